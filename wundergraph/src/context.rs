@@ -1,8 +1,106 @@
+use crate::instrumentation::{Instrumentation, DEBUG_LOG};
+use crate::query_builder::mutations::MutationHooks;
 use diesel::{r2d2, Connection};
+use std::any::Any;
+
+mod read_write;
+#[cfg(feature = "postgres")]
+mod tenant;
+
+pub use self::read_write::ReadWriteConnections;
+#[cfg(feature = "postgres")]
+pub use self::tenant::TenantConnection;
 
 pub trait WundergraphContext {
     type Connection: Connection + 'static;
+
+    /// Return `BigInt` values as string instead of as number
+    ///
+    /// Javascript clients could not represent all `i64` values as number
+    /// without losing precision. As the schema is generic over the
+    /// context type this applies to all `i64` fields of a schema.
+    /// `BigInt` inputs are accepted as number or string regardless of
+    /// this setting.
+    const BIGINT_AS_STRING: bool = false;
+
+    /// Implement the relay `Node` interface for all entities
+    ///
+    /// The `id` field of each entity returns a global node id, a field
+    /// named `id` is returned as `raw_id` instead. The query object gets
+    /// a `node` and a `nodes` field.
+    /// See the [`node`](../query_builder/selection/node/index.html) module
+    /// for details
+    const NODE_INTERFACE: bool = false;
+
     fn get_connection(&self) -> &Self::Connection;
+
+    /// The connection used to load data
+    ///
+    /// Defaults to [`get_connection`](#tymethod.get_connection), which
+    /// is always used to execute mutations. Implementations that route
+    /// reads to a replica need to return the primary connection while a
+    /// mutation is running, because mutations load the changed entities
+    /// inside of their transaction.
+    /// See [`ReadWriteConnections`](struct.ReadWriteConnections.html)
+    fn get_read_connection(&self) -> &Self::Connection {
+        self.get_connection()
+    }
+
+    /// The value passed to the [`KeyCodec`](../query_builder/types/trait.KeyCodec.html)s
+    /// of encoded primary keys
+    ///
+    /// Return for example the secret used by an encrypting codec. All
+    /// codecs of a schema that depend on the context need to use the type
+    /// of the returned value as `KeyCodec::Context`. Defaults to `()`
+    fn key_codec_context(&self) -> &dyn Any {
+        &()
+    }
+
+    /// Called before wundergraph starts to execute a mutation
+    fn mutation_started(&self) {}
+
+    /// Called after a mutation finished, regardless of whether it
+    /// succeeded or not
+    fn mutation_finished(&self) {}
+
+    /// Hooks called around the mutations of all entities
+    ///
+    /// Defaults to no hooks. See
+    /// [`MutationHooks`](../query_builder/mutations/trait.MutationHooks.html)
+    fn mutation_hooks(&self) -> Option<&dyn MutationHooks> {
+        None
+    }
+
+    /// Receives an event for each sql statement executed for this context
+    ///
+    /// Defaults to logging all statements using
+    /// [`LogInstrumentation`](../instrumentation/struct.LogInstrumentation.html)
+    /// if the `debug` feature is enabled and to no instrumentation otherwise.
+    /// See the [`instrumentation`](../instrumentation/index.html) module
+    /// for the provided implementations
+    fn instrumentation(&self) -> Option<&dyn Instrumentation> {
+        if cfg!(feature = "debug") {
+            Some(&DEBUG_LOG)
+        } else {
+            None
+        }
+    }
+}
+
+/// Calls `mutation_started` on creation and `mutation_finished` on drop
+pub(crate) struct MutationGuard<'a, Ctx: WundergraphContext>(&'a Ctx);
+
+impl<'a, Ctx: WundergraphContext> MutationGuard<'a, Ctx> {
+    pub(crate) fn new(ctx: &'a Ctx) -> Self {
+        ctx.mutation_started();
+        MutationGuard(ctx)
+    }
+}
+
+impl<'a, Ctx: WundergraphContext> Drop for MutationGuard<'a, Ctx> {
+    fn drop(&mut self) {
+        self.0.mutation_finished();
+    }
 }
 
 impl<Conn> WundergraphContext for r2d2::PooledConnection<r2d2::ConnectionManager<Conn>>
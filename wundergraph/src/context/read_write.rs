@@ -0,0 +1,107 @@
+use super::WundergraphContext;
+use diesel::Connection;
+use std::cell::Cell;
+
+/// A context that routes read only queries to a replica while all
+/// mutations are executed using the primary database
+///
+/// Data loaded as part of a mutation (for example the returned entity)
+/// is always read from the primary. If `read_your_writes` is enabled, all
+/// queries following the first mutation are sent to the primary as well,
+/// so the rest of the request sees its own changes even if the replica
+/// lags behind.
+///
+/// `ReadWriteConnections` could be used directly as context, or as part of
+/// a custom context by forwarding the methods of
+/// [`WundergraphContext`](trait.WundergraphContext.html) to it.
+///
+/// ```rust
+/// # use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
+/// # use diesel::Connection;
+/// # use wundergraph::context::ReadWriteConnections;
+/// fn request_context<C: Connection + 'static>(
+///     primary: &Pool<ConnectionManager<C>>,
+///     replica: &Pool<ConnectionManager<C>>,
+/// ) -> Result<ReadWriteConnections<PooledConnection<ConnectionManager<C>>>, Box<dyn std::error::Error>>
+/// {
+///     Ok(ReadWriteConnections::new(primary.get()?, replica.get()?).read_your_writes(true))
+/// }
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct ReadWriteConnections<Conn> {
+    primary: Conn,
+    replica: Conn,
+    read_your_writes: bool,
+    running_mutations: Cell<usize>,
+    has_written: Cell<bool>,
+}
+
+impl<Conn> ReadWriteConnections<Conn> {
+    /// Create a new context using `primary` for mutations and `replica`
+    /// for everything else
+    pub fn new(primary: Conn, replica: Conn) -> Self {
+        Self {
+            primary,
+            replica,
+            read_your_writes: false,
+            running_mutations: Cell::new(0),
+            has_written: Cell::new(false),
+        }
+    }
+
+    /// Send all queries to the primary once the first mutation was executed
+    pub fn read_your_writes(mut self, read_your_writes: bool) -> Self {
+        self.read_your_writes = read_your_writes;
+        self
+    }
+
+    /// The connection to the primary database
+    pub fn primary(&self) -> &Conn {
+        &self.primary
+    }
+
+    /// The connection that should be used for read only queries
+    pub fn reader(&self) -> &Conn {
+        if self.running_mutations.get() > 0 || (self.read_your_writes && self.has_written.get()) {
+            &self.primary
+        } else {
+            &self.replica
+        }
+    }
+
+    /// Mark the start of a mutation
+    pub fn mutation_started(&self) {
+        self.running_mutations.set(self.running_mutations.get() + 1);
+        self.has_written.set(true);
+    }
+
+    /// Mark the end of a mutation
+    pub fn mutation_finished(&self) {
+        self.running_mutations
+            .set(self.running_mutations.get().saturating_sub(1));
+    }
+}
+
+impl<Conn> WundergraphContext for ReadWriteConnections<Conn>
+where
+    Conn: Connection + 'static,
+{
+    type Connection = Conn;
+
+    fn get_connection(&self) -> &Conn {
+        self.primary()
+    }
+
+    fn get_read_connection(&self) -> &Conn {
+        self.reader()
+    }
+
+    fn mutation_started(&self) {
+        ReadWriteConnections::mutation_started(self)
+    }
+
+    fn mutation_finished(&self) {
+        ReadWriteConnections::mutation_finished(self)
+    }
+}
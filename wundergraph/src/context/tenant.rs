@@ -0,0 +1,168 @@
+use super::WundergraphContext;
+use diesel::connection::{AnsiTransactionManager, SimpleConnection};
+use diesel::deserialize::{Queryable, QueryableByName};
+use diesel::dsl::sql;
+use diesel::pg::Pg;
+use diesel::query_builder::{AsQuery, QueryFragment, QueryId};
+use diesel::sql_types::{HasSqlType, Text};
+use diesel::{Connection, ConnectionError, ConnectionResult, QueryResult, RunQueryDsl};
+
+/// A postgres connection restricted to the tables of a single tenant
+///
+/// Each tenant is expected to live in its own postgres schema containing
+/// the same set of tables. Creating a `TenantConnection` points the
+/// `search_path` of the wrapped connection to the schema of the given
+/// tenant, so every query, mutation and batch executed through it only
+/// sees the tables of this tenant. The previous `search_path` is restored
+/// once the `TenantConnection` is dropped, so a wrapped r2d2 connection
+/// is returned to the pool without the tenant.
+///
+/// If the wrapped connection is inside of a transaction, the tenant is
+/// bound to this transaction. Rolling it back reverts the `search_path`
+/// as well, therefore a failed restore could not leak the tenant either.
+///
+/// `TenantConnection` could be used directly as context or as connection
+/// type of a custom context.
+///
+/// ```rust,no_run
+/// # use diesel::r2d2::{ConnectionManager, Pool};
+/// # use diesel::PgConnection;
+/// # use wundergraph::context::TenantConnection;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = Pool::new(ConnectionManager::<PgConnection>::new("postgres://localhost/app"))?;
+/// // The tenant is usually resolved from the request, for example from a header
+/// let ctx = TenantConnection::new(pool.get()?, "customer_42")?;
+/// // execute the request using `ctx`
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager>,
+{
+    conn: Conn,
+    tenant: String,
+    outer_search_path: String,
+}
+
+impl<Conn> TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager>,
+{
+    /// Restrict the given connection to the schema named like `tenant`
+    pub fn new(conn: Conn, tenant: impl Into<String>) -> QueryResult<Self> {
+        let tenant = tenant.into();
+        let outer_search_path =
+            diesel::select(sql::<Text>("current_setting('search_path')")).get_result(&conn)?;
+        set_search_path(&conn, &format!("\"{}\"", tenant.replace('"', "\"\"")))?;
+        Ok(Self {
+            conn,
+            tenant,
+            outer_search_path,
+        })
+    }
+
+    /// The tenant this connection is restricted to
+    pub fn tenant(&self) -> &str {
+        &self.tenant
+    }
+}
+
+fn set_search_path<Conn>(conn: &Conn, search_path: &str) -> QueryResult<()>
+where
+    Conn: Connection<Backend = Pg>,
+{
+    diesel::select(
+        sql::<Text>("set_config('search_path', ")
+            .bind::<Text, _>(search_path)
+            .sql(", false)"),
+    )
+    .execute(conn)?;
+    Ok(())
+}
+
+impl<Conn> Drop for TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager>,
+{
+    fn drop(&mut self) {
+        // This only fails if the connection is broken or inside of an
+        // aborted transaction, which could only be rolled back, reverting
+        // the `search_path` as well
+        if let Err(e) = set_search_path(&self.conn, &self.outer_search_path) {
+            log::error!(
+                "Failed to restore the search_path after using tenant {}: {}",
+                self.tenant,
+                e
+            );
+        }
+    }
+}
+
+impl<Conn> WundergraphContext for TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager> + 'static,
+{
+    type Connection = Self;
+
+    fn get_connection(&self) -> &Self {
+        self
+    }
+}
+
+impl<Conn> SimpleConnection for TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager>,
+{
+    fn batch_execute(&self, query: &str) -> QueryResult<()> {
+        self.conn.batch_execute(query)
+    }
+}
+
+impl<Conn> Connection for TenantConnection<Conn>
+where
+    Conn: Connection<Backend = Pg, TransactionManager = AnsiTransactionManager>,
+{
+    type Backend = Pg;
+    type TransactionManager = AnsiTransactionManager;
+
+    fn establish(_database_url: &str) -> ConnectionResult<Self> {
+        Err(ConnectionError::BadConnection(String::from(
+            "A tenant connection could only be created by `TenantConnection::new`",
+        )))
+    }
+
+    fn execute(&self, query: &str) -> QueryResult<usize> {
+        self.conn.execute(query)
+    }
+
+    fn query_by_index<T, U>(&self, source: T) -> QueryResult<Vec<U>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg> + QueryId,
+        Pg: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Pg>,
+    {
+        self.conn.query_by_index(source)
+    }
+
+    fn query_by_name<T, U>(&self, source: &T) -> QueryResult<Vec<U>>
+    where
+        T: QueryFragment<Pg> + QueryId,
+        U: QueryableByName<Pg>,
+    {
+        self.conn.query_by_name(source)
+    }
+
+    fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Pg> + QueryId,
+    {
+        self.conn.execute_returning_count(source)
+    }
+
+    fn transaction_manager(&self) -> &AnsiTransactionManager {
+        self.conn.transaction_manager()
+    }
+}
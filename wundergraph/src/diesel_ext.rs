@@ -3,7 +3,7 @@ use diesel::backend::Backend;
 use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
 use diesel::query_builder::{AstPass, QueryFragment, QueryId};
 use diesel::result::QueryResult;
-use diesel::sql_types::IntoNullable;
+use diesel::sql_types::{Bool, Double, IntoNullable, Nullable};
 
 pub trait BoxableFilter<QS, DB>
 where
@@ -25,6 +25,14 @@ where
 {
 }
 
+impl<'a, QS, DB, ST> QueryId for dyn BoxableFilter<QS, DB, SqlType = ST> + 'a
+where
+    DB: Backend,
+{
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
 #[derive(Debug)]
 pub enum MaybeNull<T> {
     Expr(T),
@@ -66,3 +74,234 @@ impl<T> NonAggregate for MaybeNull<T> {}
 impl<T, QS> AppearsOnTable<QS> for MaybeNull<T> where Self: Expression {}
 
 impl<T, ST> SelectableExpression<T> for MaybeNull<ST> where Self: Expression {}
+
+/// Matches a text column against a plain text search query
+///
+/// On postgres this is `to_tsvector(column) @@ plainto_tsquery(query)`.
+/// On sqlite the query is matched against the column of the same name of a
+/// fts5 table called `<table>_fts`, that shares the rowid with the table of
+/// the column.
+#[derive(Debug, Clone)]
+pub struct FullTextMatch<C> {
+    column: C,
+    query: String,
+}
+
+impl<C> FullTextMatch<C> {
+    /// Match `column` against the search terms in `query`
+    pub fn new(column: C, query: String) -> Self {
+        Self { column, query }
+    }
+}
+
+impl<C> Expression for FullTextMatch<C>
+where
+    C: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<C> NonAggregate for FullTextMatch<C> {}
+
+impl<C, QS> AppearsOnTable<QS> for FullTextMatch<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<C, QS> SelectableExpression<QS> for FullTextMatch<C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<C> QueryId for FullTextMatch<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<diesel::pg::Pg> for FullTextMatch<C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        pass.push_sql("to_tsvector(");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(") @@ plainto_tsquery(");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&self.query)?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C> QueryFragment<diesel::sqlite::Sqlite> for FullTextMatch<C>
+where
+    C: diesel::Column,
+    C::Table: diesel::associations::HasTable<Table = C::Table>,
+    <C::Table as diesel::QuerySource>::FromClause: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        let fts_table = fts::table_name::<C::Table>()?;
+        fts::push_rowid::<C::Table>(pass.reborrow())?;
+        pass.push_sql(" IN (SELECT rowid FROM ");
+        pass.push_identifier(&fts_table)?;
+        pass.push_sql(" WHERE ");
+        fts::push_match::<C>(&fts_table, &self.query, pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The relevance of a text column for a plain text search query
+///
+/// Higher values indicate a more relevant row on all backends, so ordering
+/// by this expression descending returns the best matches first.
+/// On postgres this is `ts_rank(to_tsvector(column), plainto_tsquery(query))`,
+/// on sqlite the negated `bm25` rank of the matching row in the fts5 table
+/// used by [`FullTextMatch`](struct.FullTextMatch.html).
+#[derive(Debug, Clone)]
+pub struct FullTextRank<C> {
+    column: C,
+    query: String,
+}
+
+impl<C> FullTextRank<C> {
+    /// The relevance of `column` for the search terms in `query`
+    pub fn new(column: C, query: String) -> Self {
+        Self { column, query }
+    }
+}
+
+impl<C> Expression for FullTextRank<C>
+where
+    C: Expression,
+{
+    type SqlType = Nullable<Double>;
+}
+
+impl<C> NonAggregate for FullTextRank<C> {}
+
+impl<C, QS> AppearsOnTable<QS> for FullTextRank<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<C, QS> SelectableExpression<QS> for FullTextRank<C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<C> QueryId for FullTextRank<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<diesel::pg::Pg> for FullTextRank<C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        pass.push_sql("ts_rank(to_tsvector(");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql("), plainto_tsquery(");
+        pass.push_bind_param::<diesel::sql_types::Text, _>(&self.query)?;
+        pass.push_sql("))");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C> QueryFragment<diesel::sqlite::Sqlite> for FullTextRank<C>
+where
+    C: diesel::Column,
+    C::Table: diesel::associations::HasTable<Table = C::Table>,
+    <C::Table as diesel::QuerySource>::FromClause: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        let fts_table = fts::table_name::<C::Table>()?;
+        pass.push_sql("(SELECT -bm25(");
+        pass.push_identifier(&fts_table)?;
+        pass.push_sql(") FROM ");
+        pass.push_identifier(&fts_table)?;
+        pass.push_sql(" WHERE ");
+        fts::push_match::<C>(&fts_table, &self.query, pass.reborrow())?;
+        pass.push_sql(" AND ");
+        pass.push_identifier(&fts_table)?;
+        pass.push_sql(".rowid = ");
+        fts::push_rowid::<C::Table>(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod fts {
+    use diesel::associations::HasTable;
+    use diesel::query_builder::{AstPass, QueryBuilder, QueryFragment};
+    use diesel::result::QueryResult;
+    use diesel::sql_types::Text;
+    use diesel::sqlite::{Sqlite, SqliteQueryBuilder};
+    use diesel::{Column, Table};
+
+    /// The name of the fts5 table belonging to `T`
+    pub(super) fn table_name<T>() -> QueryResult<String>
+    where
+        T: HasTable<Table = T> + Table,
+        T::FromClause: QueryFragment<Sqlite>,
+    {
+        let mut query_builder = SqliteQueryBuilder::new();
+        T::table().from_clause().to_sql(&mut query_builder)?;
+        Ok(format!("{}_fts", query_builder.finish().trim_matches('`')))
+    }
+
+    pub(super) fn push_rowid<T>(mut pass: AstPass<'_, Sqlite>) -> QueryResult<()>
+    where
+        T: HasTable<Table = T> + Table,
+        T::FromClause: QueryFragment<Sqlite>,
+    {
+        T::table().from_clause().walk_ast(pass.reborrow())?;
+        pass.push_sql(".rowid");
+        Ok(())
+    }
+
+    pub(super) fn push_match<C>(
+        fts_table: &str,
+        query: &str,
+        mut pass: AstPass<'_, Sqlite>,
+    ) -> QueryResult<()>
+    where
+        C: Column,
+    {
+        pass.push_identifier(fts_table)?;
+        pass.push_sql(".");
+        pass.push_identifier(C::NAME)?;
+        pass.push_sql(" MATCH ");
+        pass.push_bind_param::<Text, _>(&plain_query(query))
+    }
+
+    /// Quote each search term, so the query is interpreted like the plain
+    /// text query of `plainto_tsquery` instead of the fts5 query syntax
+    ///
+    /// A query without any term becomes an empty phrase, that matches
+    /// nothing instead of failing with a syntax error.
+    fn plain_query(query: &str) -> String {
+        let terms = query
+            .split_whitespace()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>();
+        if terms.is_empty() {
+            String::from("\"\"")
+        } else {
+            terms.join(" ")
+        }
+    }
+}
@@ -7,8 +7,18 @@ use crate::scalar::WundergraphScalarValue;
 pub enum WundergraphError {
     /// Indicates that it was not possible to build a filter from the given
     /// graphql arguments
-    #[fail(display = "Could not build filter from arguments")]
-    CouldNotBuildFilterArgument,
+    #[fail(
+        display = "Could not build filter from argument `{}`: {}",
+        path, reason
+    )]
+    CouldNotBuildFilterArgument {
+        /// The path to the invalid part of the argument, starting at the
+        /// graphql field the argument belongs to, for example
+        /// `Heros.filter.and[1].id.eq`
+        path: String,
+        /// A description of why the given value was rejected
+        reason: String,
+    },
     /// Indicates that a unknown database field name was passed into
     /// wundergraph
     #[fail(display = "Requested unkown field {}", name)]
@@ -18,6 +28,60 @@ pub enum WundergraphError {
     },
     #[fail(display = "Could not build primary key filter from arguments")]
     NoPrimaryKeyArgumentFound,
+    /// Indicates that a node id passed to the `node` or `nodes` field
+    /// could not be decoded
+    #[fail(display = "Invalid node id `{}`", id)]
+    InvalidNodeId {
+        /// The invalid id
+        id: String,
+    },
+    /// Indicates that the primary key of an entity could not be encoded
+    /// as node id
+    #[fail(
+        display = "Could not encode the primary key of `{}` as node id",
+        entity
+    )]
+    UnsupportedNodeKey {
+        /// The graphql name of the entity
+        entity: String,
+    },
+    /// Indicates that a relation mutation references rows that do not
+    /// exist or that are not visible under the current context
+    #[fail(
+        display = "Could not execute `{}`: `{}` references a {} that does not exist or is not visible",
+        mutation, argument, entity
+    )]
+    RelationTargetNotFound {
+        /// The graphql name of the relation mutation
+        mutation: String,
+        /// The argument referencing the rows
+        argument: String,
+        /// The graphql name of the referenced entity
+        entity: String,
+    },
+    /// Indicates that a key encoded by a
+    /// [`KeyCodec`](../query_builder/types/trait.KeyCodec.html) was
+    /// encoded or decoded while no codec context was available
+    #[fail(
+        display = "Keys encoded by `{}` could only be encoded or decoded while a query or mutation is executed",
+        codec
+    )]
+    MissingKeyCodecContext {
+        /// The name of the codec
+        codec: String,
+    },
+    /// Indicates that the key codec context returned by the context does
+    /// not have the type required by a key codec
+    #[fail(
+        display = "The key codec context of the current context is not a `{}` as required by the key codec `{}`",
+        expected, codec
+    )]
+    InvalidKeyCodecContext {
+        /// The name of the codec
+        codec: String,
+        /// The type name of the context required by the codec
+        expected: String,
+    },
     #[fail(display = "Failed to build a return value")]
     JuniperError {
         inner: juniper::FieldError<WundergraphScalarValue>,
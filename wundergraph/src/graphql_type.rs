@@ -1,3 +1,5 @@
+use crate::context::WundergraphContext;
+use crate::query_builder::selection::node::{self, Node};
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::LoadingHandler;
 use crate::scalar::WundergraphScalarValue;
@@ -18,6 +20,7 @@ where
     T: LoadingHandler<DB, Ctx>,
     T::FieldList: WundergraphGraphqlHelper<T, DB, Ctx>,
     DB::QueryBuilder: Default,
+    Ctx: WundergraphContext,
 {
     type Context = ();
     type TypeInfo = ();
@@ -35,6 +38,7 @@ where
     {
         <T::FieldList as WundergraphGraphqlHelper<T, DB, Ctx>>::object_meta::<Self>(
             T::FIELD_NAMES,
+            Ctx::NODE_INTERFACE,
             registry,
         )
     }
@@ -59,8 +63,14 @@ where
 }
 
 pub trait WundergraphGraphqlHelper<L, DB, Ctx> {
+    /// Build the object type of an entity
+    ///
+    /// If `node_interface` is set the object implements the
+    /// [`Node`](../query_builder/selection/node/struct.Node.html) interface,
+    /// a field named `id` is registered as `raw_id` then
     fn object_meta<'r, T>(
         names: &[&str],
+        node_interface: bool,
         registry: &mut Registry<'r, WundergraphScalarValue>,
     ) -> meta::MetaType<'r, WundergraphScalarValue>
     where
@@ -84,11 +94,12 @@ macro_rules! wundergraph_graphql_helper_impl {
             {
                 fn object_meta<'r, Type>(
                     names: &[&str],
+                    node_interface: bool,
                     registry: &mut Registry<'r, WundergraphScalarValue>,
                 ) -> meta::MetaType<'r, WundergraphScalarValue>
                     where Type: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
                 {
-                    let fields  = [
+                    let mut fields = vec![
                         $({
                             let mut field = registry.field::<<$T as WundergraphGraphqlMapper<Back, Ctx>>::GraphQLType>(names[$idx], &());
                             field = <$T as WundergraphGraphqlMapper<Back, Ctx>>::register_arguments(registry, field);
@@ -101,6 +112,15 @@ macro_rules! wundergraph_graphql_helper_impl {
                             field
                         },)*
                     ];
+                    if node_interface {
+                        if let Some(field) = fields.iter_mut().find(|f| f.name == node::NODE_ID_FIELD) {
+                            field.name = node::RAW_ID_FIELD.to_owned();
+                        }
+                        fields.push(
+                            registry.field::<juniper::ID>(node::NODE_ID_FIELD, &())
+                                .description("A globally unique identifier of this object")
+                        );
+                    }
                     let mut ty = registry.build_object_type::<Type>(
                         &(),
                         &fields,
@@ -108,6 +128,9 @@ macro_rules! wundergraph_graphql_helper_impl {
                     if let Some(doc) = Loading::type_description() {
                         ty = ty.description(doc);
                     }
+                    if node_interface {
+                        ty = ty.interfaces(&[registry.get_type::<Node>(&())]);
+                    }
                     meta::MetaType::Object(ty)
                 }
             }
@@ -1,8 +1,17 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::helper::tuple::TupleIndex;
 use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::types::placeholder::PlaceHolderMarker;
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::dsl::EqAny;
+use diesel::expression::array_comparison::AsInExpression;
 use diesel::query_builder::nodes::Identifier;
-use diesel::{Column, Identifiable, QuerySource, Table};
+use diesel::sql_types::Bool;
+use diesel::{
+    BoolExpressionMethods, Column, EqAll, ExpressionMethods, Identifiable, QuerySource, Table,
+};
 use indexmap::IndexMap;
 use juniper::meta::{Argument, MetaType};
 use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
@@ -84,6 +93,30 @@ pub trait PrimaryKeyInputObject<V, I> {
     fn to_input_value(values: &V) -> InputValue<WundergraphScalarValue>;
 }
 
+/// Extract the primary key of each loaded row as input object
+///
+/// Used by `#[derive(WundergraphEntity)]` to implement
+/// [`LoadingHandler::primary_keys`](../../query_builder/selection/trait.LoadingHandler.html#method.primary_keys)
+#[doc(hidden)]
+pub fn primary_key_input_values<P, Key, T>(
+    placeholder: &[P],
+) -> Vec<Option<InputValue<WundergraphScalarValue>>>
+where
+    P: TupleIndex<Key>,
+    P::Value: PlaceHolderMarker,
+    T: Table,
+    T::PrimaryKey: PrimaryKeyInputObject<<P::Value as PlaceHolderMarker>::InnerType, ()>,
+{
+    placeholder
+        .iter()
+        .map(|row| {
+            row.get()
+                .into_inner()
+                .map(|key| T::PrimaryKey::to_input_value(&key))
+        })
+        .collect()
+}
+
 impl<A, V1, I> PrimaryKeyInputObject<V1, I> for A
 where
     A: Column,
@@ -174,9 +207,12 @@ macro_rules! primary_key_input_object_impl {
                 }
 
                 fn to_input_value(values: &($($ST, )+)) -> InputValue<WundergraphScalarValue> {
+                   // Each column returns an object containing only its own value
                    let mut map = IndexMap::with_capacity($Tuple);
                    $(
-                       map.insert($T::NAME, $T::to_input_value(&values.$idx));
+                       if let InputValue::Object(fields) = $T::to_input_value(&values.$idx) {
+                           map.extend(fields.into_iter().map(|(k, v)| (k.item, v.item)));
+                       }
                    )+
                    InputValue::object(map)
                 }
@@ -187,6 +223,75 @@ macro_rules! primary_key_input_object_impl {
 
 __diesel_for_each_tuple!(primary_key_input_object_impl);
 
+/// Build a filter matching the rows with one of the given primary keys
+///
+/// Single column keys are matched with one `IN` expression. Composite
+/// keys are matched with a balanced tree of `OR`ed comparisons, so the
+/// depth of the expression only grows logarithmically with the number
+/// of keys. Returns `None` if no key is given.
+pub trait PrimaryKeysFilter<T, DB, V> {
+    fn keys_filter(self, keys: Vec<V>) -> Option<Box<dyn BoxableFilter<T, DB, SqlType = Bool>>>;
+}
+
+impl<A, T, DB, V> PrimaryKeysFilter<T, DB, V> for A
+where
+    A: Column + ExpressionMethods,
+    DB: Backend,
+    V: FromLookAheadValue,
+    Vec<V>: AsInExpression<A::SqlType>,
+    EqAny<A, Vec<V>>: BoxableFilter<T, DB, SqlType = Bool> + 'static,
+{
+    fn keys_filter(self, keys: Vec<V>) -> Option<Box<dyn BoxableFilter<T, DB, SqlType = Bool>>> {
+        if keys.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.eq_any(keys)))
+        }
+    }
+}
+
+macro_rules! primary_keys_filter_impl {
+    ($(
+        $Tuple:tt {
+            $(($idx: tt) -> $T:ident, $ST: ident, $TT: ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T,)+ $($ST,)+ __T, __DB> PrimaryKeysFilter<__T, __DB, ($($ST,)+)> for ($($T,)+)
+            where
+                $($T: Column,)+
+                Self: EqAll<($($ST,)+)> + Copy,
+                <Self as EqAll<($($ST,)+)>>::Output: BoxableFilter<__T, __DB, SqlType = Bool> + 'static,
+                __DB: Backend + 'static,
+                __T: 'static,
+            {
+                fn keys_filter(self, keys: Vec<($($ST,)+)>)
+                    -> Option<Box<dyn BoxableFilter<__T, __DB, SqlType = Bool>>>
+                {
+                    let mut filters = keys
+                        .into_iter()
+                        .map(|key| Box::new(self.eq_all(key)) as Box<dyn BoxableFilter<__T, __DB, SqlType = Bool>>)
+                        .collect::<Vec<_>>();
+                    while filters.len() > 1 {
+                        let mut pairs = filters.into_iter();
+                        let mut next = Vec::new();
+                        while let Some(lhs) = pairs.next() {
+                            next.push(match pairs.next() {
+                                Some(rhs) => Box::new(lhs.or(rhs)) as Box<_>,
+                                None => lhs,
+                            });
+                        }
+                        filters = next;
+                    }
+                    filters.pop()
+                }
+            }
+        )+
+    }
+}
+
+__diesel_for_each_tuple!(primary_keys_filter_impl);
+
 #[derive(Debug)]
 pub struct PrimaryKeyInfo<T>(String, PhantomData<T>);
 
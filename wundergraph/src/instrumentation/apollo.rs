@@ -0,0 +1,133 @@
+use super::{Instrumentation, Statement, StatementOutcome};
+use crate::scalar::WundergraphScalarValue;
+use juniper::{Object, Value};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// Collects all statements of a request and renders them in the style of
+/// the apollo tracing format
+///
+/// Create a new instance for each request and put the value returned by
+/// [`extensions`](#method.extensions) into the `extensions` field of the
+/// graphql response. All durations and offsets are given in nanoseconds,
+/// offsets are relative to the creation of the `ApolloTracing`.
+///
+/// ```json
+/// {
+///   "tracing": {
+///     "version": 1,
+///     "duration": 1250000,
+///     "execution": {
+///       "statements": [
+///         {
+///           "path": ["Heros", "species"],
+///           "typeName": "Species",
+///           "sql": "SELECT ...",
+///           "bindCount": 2,
+///           "rows": 2,
+///           "startOffset": 970000,
+///           "duration": 120000
+///         }
+///       ]
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ApolloTracing {
+    start: Instant,
+    statements: RefCell<Vec<TracedStatement>>,
+}
+
+#[derive(Debug)]
+struct TracedStatement {
+    path: Vec<String>,
+    type_name: &'static str,
+    sql: String,
+    bind_count: usize,
+    rows: Option<usize>,
+    start_offset: Duration,
+    duration: Duration,
+}
+
+impl ApolloTracing {
+    /// Start tracing a new request
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            statements: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// The `extensions` block containing all statements traced until now
+    pub fn extensions(&self) -> Value<WundergraphScalarValue> {
+        let statements = self
+            .statements
+            .borrow()
+            .iter()
+            .map(TracedStatement::to_value)
+            .collect();
+        let mut execution = Object::with_capacity(1);
+        execution.add_field("statements", Value::list(statements));
+
+        let mut tracing = Object::with_capacity(3);
+        tracing.add_field("version", Value::scalar(1));
+        tracing.add_field("duration", nanos(self.start.elapsed()));
+        tracing.add_field("execution", Value::object(execution));
+
+        let mut extensions = Object::with_capacity(1);
+        extensions.add_field("tracing", Value::object(tracing));
+        Value::object(extensions)
+    }
+}
+
+impl Default for ApolloTracing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TracedStatement {
+    fn to_value(&self) -> Value<WundergraphScalarValue> {
+        let mut statement = Object::with_capacity(7);
+        statement.add_field(
+            "path",
+            Value::list(self.path.iter().map(|f| Value::scalar(f.clone())).collect()),
+        );
+        statement.add_field("typeName", Value::scalar(self.type_name));
+        statement.add_field("sql", Value::scalar(self.sql.clone()));
+        statement.add_field("bindCount", Value::scalar(self.bind_count as i32));
+        statement.add_field(
+            "rows",
+            self.rows
+                .map(|rows| Value::scalar(rows as i32))
+                .unwrap_or(Value::Null),
+        );
+        statement.add_field("startOffset", nanos(self.start_offset));
+        statement.add_field("duration", nanos(self.duration));
+        Value::object(statement)
+    }
+}
+
+fn nanos(duration: Duration) -> Value<WundergraphScalarValue> {
+    Value::scalar(duration.as_nanos() as i64)
+}
+
+impl Instrumentation for ApolloTracing {
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome) {
+        let start_offset = self
+            .start
+            .elapsed()
+            .checked_sub(outcome.duration)
+            .unwrap_or_default();
+        self.statements.borrow_mut().push(TracedStatement {
+            path: statement.path.to_vec(),
+            type_name: statement.type_name,
+            sql: statement.sql.to_owned(),
+            bind_count: statement.bind_count,
+            rows: outcome.rows,
+            start_offset,
+            duration: outcome.duration,
+        });
+    }
+}
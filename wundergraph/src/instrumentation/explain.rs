@@ -0,0 +1,133 @@
+use diesel::backend::Backend;
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::{Connection, QueryResult, RunQueryDsl};
+use std::marker::PhantomData;
+
+/// A backend able to describe how a statement is executed
+///
+/// Used to attach the number of bind parameters and the query plan to
+/// the events reported to an
+/// [`Instrumentation`](trait.Instrumentation.html).
+/// All backends supported by wundergraph implement this trait.
+pub trait Explain: Backend {
+    /// Return the number of bind parameters of the given statement
+    fn bind_count<Q>(query: &Q) -> QueryResult<usize>
+    where
+        Q: QueryFragment<Self>;
+
+    /// Return the query plan of the given statement, one entry per line
+    /// or node
+    ///
+    /// The statement itself is not executed.
+    fn explain<Conn, Q>(conn: &Conn, query: &Q) -> QueryResult<Vec<String>>
+    where
+        Conn: Connection<Backend = Self>,
+        Q: QueryFragment<Self>;
+}
+
+#[cfg(feature = "postgres")]
+impl Explain for diesel::pg::Pg {
+    fn bind_count<Q>(query: &Q) -> QueryResult<usize>
+    where
+        Q: QueryFragment<Self>,
+    {
+        use diesel::pg::PgQueryBuilder;
+        use diesel::query_builder::QueryBuilder;
+
+        // Collecting the binds of a postgres statement requires a metadata
+        // lookup backed by a connection, so this uses the placeholders
+        // instead, which are numbered by the query builder starting at `$1`
+        let mut query_builder = PgQueryBuilder::default();
+        query.to_sql(&mut query_builder)?;
+        let sql = query_builder.finish();
+        Ok(sql
+            .split('$')
+            .skip(1)
+            .filter_map(|s| {
+                let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or_else(|| s.len());
+                s[..end].parse::<usize>().ok()
+            })
+            .max()
+            .unwrap_or(0))
+    }
+
+    fn explain<Conn, Q>(conn: &Conn, query: &Q) -> QueryResult<Vec<String>>
+    where
+        Conn: Connection<Backend = Self>,
+        Q: QueryFragment<Self>,
+    {
+        use diesel::sql_types::Text;
+
+        ExplainStatement::<_, Text>::new("EXPLAIN ", query).load::<String>(conn)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Explain for diesel::sqlite::Sqlite {
+    fn bind_count<Q>(query: &Q) -> QueryResult<usize>
+    where
+        Q: QueryFragment<Self>,
+    {
+        use diesel::query_builder::bind_collector::RawBytesBindCollector;
+
+        let mut collector = RawBytesBindCollector::<Self>::new();
+        query.collect_binds(&mut collector, &())?;
+        Ok(collector.binds.len())
+    }
+
+    fn explain<Conn, Q>(conn: &Conn, query: &Q) -> QueryResult<Vec<String>>
+    where
+        Conn: Connection<Backend = Self>,
+        Q: QueryFragment<Self>,
+    {
+        use diesel::sql_types::{Integer, Text};
+
+        // Each row is (id, parent, notused, detail)
+        let plan = ExplainStatement::<_, (Integer, Integer, Integer, Text)>::new(
+            "EXPLAIN QUERY PLAN ",
+            query,
+        )
+        .load::<(i32, i32, i32, String)>(conn)?;
+        Ok(plan.into_iter().map(|(_, _, _, detail)| detail).collect())
+    }
+}
+
+/// Prefixes a statement with the backend specific explain keyword
+#[derive(Debug)]
+struct ExplainStatement<'a, Q, ST> {
+    prefix: &'static str,
+    query: &'a Q,
+    sql_type: PhantomData<ST>,
+}
+
+impl<'a, Q, ST> ExplainStatement<'a, Q, ST> {
+    fn new(prefix: &'static str, query: &'a Q) -> Self {
+        Self {
+            prefix,
+            query,
+            sql_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, Q, ST> QueryId for ExplainStatement<'a, Q, ST> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<'a, Q, ST> Query for ExplainStatement<'a, Q, ST> {
+    type SqlType = ST;
+}
+
+impl<'a, Q, ST, DB> QueryFragment<DB> for ExplainStatement<'a, Q, ST>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        pass.push_sql(self.prefix);
+        self.query.walk_ast(pass.reborrow())
+    }
+}
+
+impl<'a, Q, ST, Conn> RunQueryDsl<Conn> for ExplainStatement<'a, Q, ST> {}
@@ -0,0 +1,55 @@
+use super::{Instrumentation, Statement, StatementOutcome};
+use log::Level;
+
+/// Writes each executed statement to the `log` crate
+///
+/// Statements are logged with the target `wundergraph::sql` at the
+/// configured level, failed statements are always logged as warning.
+#[derive(Debug, Clone, Copy)]
+pub struct LogInstrumentation {
+    level: Level,
+}
+
+/// Used by contexts that do not provide their own instrumentation if the
+/// `debug` feature is enabled
+pub(crate) static DEBUG_LOG: LogInstrumentation = LogInstrumentation { level: Level::Debug };
+
+impl LogInstrumentation {
+    /// Log all statements using the given level
+    pub fn new(level: Level) -> Self {
+        Self { level }
+    }
+}
+
+impl Default for LogInstrumentation {
+    fn default() -> Self {
+        Self::new(Level::Debug)
+    }
+}
+
+impl Instrumentation for LogInstrumentation {
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome) {
+        match outcome.rows {
+            Some(rows) => log::log!(
+                target: "wundergraph::sql",
+                self.level,
+                "{} ({}) took {:?} and returned {} rows: {} -- {} binds",
+                statement.path.join("."),
+                statement.type_name,
+                outcome.duration,
+                rows,
+                statement.sql,
+                statement.bind_count
+            ),
+            None => log::warn!(
+                target: "wundergraph::sql",
+                "{} ({}) failed after {:?}: {} -- {} binds",
+                statement.path.join("."),
+                statement.type_name,
+                outcome.duration,
+                statement.sql,
+                statement.bind_count
+            ),
+        }
+    }
+}
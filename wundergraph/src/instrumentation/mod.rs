@@ -0,0 +1,254 @@
+//! Instrumentation of the sql statements executed by wundergraph
+//!
+//! Return an [`Instrumentation`](trait.Instrumentation.html) from
+//! [`WundergraphContext::instrumentation`](../context/trait.WundergraphContext.html#method.instrumentation)
+//! to get notified about each sql statement executed while resolving a
+//! request. Each event contains the sql text, the number of bind parameters,
+//! the graphql field path that caused the statement and the `TYPE_NAME` of
+//! the loaded or changed entity. Once the statement finished, the duration
+//! and the number of returned or affected rows are reported as well.
+//!
+//! Wundergraph ships the following implementations:
+//!
+//! * [`LogInstrumentation`](struct.LogInstrumentation.html) writes each
+//!   statement to the `log` crate
+//! * [`TracingInstrumentation`](struct.TracingInstrumentation.html) opens a
+//!   `tracing` span for each statement (requires the `tracing` feature)
+//! * [`ApolloTracing`](struct.ApolloTracing.html) collects all statements
+//!   of a request and renders them as apollo tracing like `extensions`
+//!   block for the response
+//! * [`SqlDebug`](struct.SqlDebug.html) collects the sql and optionally the
+//!   query plan of all statements of a request for privileged clients
+//!
+//! Multiple implementations could be combined using a tuple, optional
+//! ones by wrapping them into an `Option`.
+//!
+//! ```rust
+//! # use diesel::Connection;
+//! # use wundergraph::context::WundergraphContext;
+//! # use wundergraph::instrumentation::{ApolloTracing, Instrumentation};
+//! struct MyContext<Conn> {
+//!     conn: Conn,
+//!     tracing: ApolloTracing,
+//! }
+//!
+//! impl<Conn> WundergraphContext for MyContext<Conn>
+//! where
+//!     Conn: Connection + 'static,
+//! {
+//!     type Connection = Conn;
+//!
+//!     fn get_connection(&self) -> &Conn {
+//!         &self.conn
+//!     }
+//!
+//!     fn instrumentation(&self) -> Option<&dyn Instrumentation> {
+//!         Some(&self.tracing)
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The extensions of the `ApolloTracing` are available via
+//! [`ApolloTracing::extensions`](struct.ApolloTracing.html#method.extensions)
+//! once the request was executed.
+
+use crate::context::WundergraphContext;
+use diesel::backend::Backend;
+use diesel::query_builder::{QueryBuilder, QueryFragment};
+use diesel::{Connection, QueryResult};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+mod apollo;
+mod explain;
+mod logger;
+#[cfg(feature = "tracing")]
+mod spans;
+mod sql_debug;
+
+pub use self::apollo::ApolloTracing;
+pub use self::explain::Explain;
+pub(crate) use self::logger::DEBUG_LOG;
+pub use self::logger::LogInstrumentation;
+#[cfg(feature = "tracing")]
+pub use self::spans::TracingInstrumentation;
+pub use self::sql_debug::SqlDebug;
+
+/// A sql statement executed by wundergraph
+#[derive(Debug, Clone, Copy)]
+pub struct Statement<'a> {
+    /// The sql text of the statement, containing placeholders for all
+    /// bind parameters
+    pub sql: &'a str,
+    /// The number of bind parameters
+    pub bind_count: usize,
+    /// The path of graphql fields whose resolution caused this statement
+    pub path: &'a [String],
+    /// The `TYPE_NAME` of the entity loaded or changed by this statement
+    pub type_name: &'static str,
+    /// The query plan of the statement, if requested by
+    /// [`Instrumentation::explain`](trait.Instrumentation.html#method.explain)
+    pub plan: Option<&'a [String]>,
+}
+
+/// The outcome of an executed sql statement
+#[derive(Debug, Clone, Copy)]
+pub struct StatementOutcome {
+    /// Time spent executing the statement
+    pub duration: Duration,
+    /// The number of returned or affected rows, `None` if the statement
+    /// failed
+    pub rows: Option<usize>,
+}
+
+/// Receives an event for each sql statement executed by wundergraph
+///
+/// Both methods are called on the thread executing the statement, directly
+/// before and after the statement is sent to the database.
+pub trait Instrumentation {
+    /// Whether the query plan of each statement should be reported
+    ///
+    /// Explaining a statement requires an additional roundtrip to the
+    /// database, so this defaults to `false`.
+    fn explain(&self) -> bool {
+        false
+    }
+
+    /// Called before the statement is executed
+    fn statement_started(&self, _statement: &Statement<'_>) {}
+
+    /// Called after the statement was executed
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome);
+}
+
+impl<T> Instrumentation for Option<T>
+where
+    T: Instrumentation,
+{
+    fn explain(&self) -> bool {
+        self.as_ref().map_or(false, Instrumentation::explain)
+    }
+
+    fn statement_started(&self, statement: &Statement<'_>) {
+        if let Some(instrumentation) = self {
+            instrumentation.statement_started(statement);
+        }
+    }
+
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome) {
+        if let Some(instrumentation) = self {
+            instrumentation.statement_finished(statement, outcome);
+        }
+    }
+}
+
+impl<A, B> Instrumentation for (A, B)
+where
+    A: Instrumentation,
+    B: Instrumentation,
+{
+    fn explain(&self) -> bool {
+        self.0.explain() || self.1.explain()
+    }
+
+    fn statement_started(&self, statement: &Statement<'_>) {
+        self.0.statement_started(statement);
+        self.1.statement_started(statement);
+    }
+
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome) {
+        self.0.statement_finished(statement, outcome);
+        self.1.statement_finished(statement, outcome);
+    }
+}
+
+thread_local! {
+    static FIELD_PATH: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Removes the field added by [`enter_field`](fn.enter_field.html) from the
+/// current path once dropped
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct FieldScope {
+    // Prevent construction outside of `enter_field`
+    _priv: (),
+}
+
+impl Drop for FieldScope {
+    fn drop(&mut self) {
+        FIELD_PATH.with(|path| {
+            path.borrow_mut().pop();
+        });
+    }
+}
+
+/// Add a graphql field to the path reported for all statements executed
+/// until the returned scope is dropped
+#[doc(hidden)]
+pub fn enter_field(name: &str) -> FieldScope {
+    FIELD_PATH.with(|path| path.borrow_mut().push(name.to_owned()));
+    FieldScope { _priv: () }
+}
+
+/// Execute a statement while reporting it to the instrumentation of the
+/// given context
+///
+/// `execute` runs the statement, `count_rows` extracts the number of
+/// returned or affected rows from a successful result.
+#[doc(hidden)]
+pub fn instrument<Ctx, Conn, Q, R, E, C>(
+    ctx: &Ctx,
+    conn: &Conn,
+    type_name: &'static str,
+    query: Q,
+    execute: E,
+    count_rows: C,
+) -> QueryResult<R>
+where
+    Ctx: WundergraphContext + ?Sized,
+    Conn: Connection,
+    Conn::Backend: Explain,
+    <Conn::Backend as Backend>::QueryBuilder: Default,
+    Q: QueryFragment<Conn::Backend>,
+    E: FnOnce(Q, &Conn) -> QueryResult<R>,
+    C: FnOnce(&R) -> usize,
+{
+    let instrumentation = match ctx.instrumentation() {
+        Some(instrumentation) => instrumentation,
+        None => return execute(query, conn),
+    };
+    let mut query_builder = <Conn::Backend as Backend>::QueryBuilder::default();
+    query.to_sql(&mut query_builder)?;
+    let sql = query_builder.finish();
+    let bind_count = <Conn::Backend as Explain>::bind_count(&query)?;
+    let path = FIELD_PATH.with(|path| path.borrow().clone());
+    let plan = if instrumentation.explain() {
+        Some(
+            // A failing statement aborts a surrounding postgres transaction,
+            // so the statement is explained inside of its own savepoint
+            conn.transaction(|| <Conn::Backend as Explain>::explain(conn, &query))
+                .unwrap_or_else(|e| vec![format!("Failed to explain the statement: {}", e)]),
+        )
+    } else {
+        None
+    };
+    let statement = Statement {
+        sql: &sql,
+        bind_count,
+        path: &path,
+        type_name,
+        plan: plan.as_ref().map(Vec::as_slice),
+    };
+
+    instrumentation.statement_started(&statement);
+    let start = Instant::now();
+    let result = execute(query, conn);
+    let outcome = StatementOutcome {
+        duration: start.elapsed(),
+        rows: result.as_ref().ok().map(count_rows),
+    };
+    instrumentation.statement_finished(&statement, &outcome);
+    result
+}
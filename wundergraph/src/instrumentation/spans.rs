@@ -0,0 +1,50 @@
+use super::{Instrumentation, Statement, StatementOutcome};
+use std::cell::RefCell;
+use tracing_internal::Span;
+
+/// Opens a `tracing` span named `wundergraph.sql` around each statement
+///
+/// The span records the sql text, the number of bind parameters, the
+/// graphql field path and the entity as fields. It is created as child of
+/// the span that is active while the request is resolved and closed once
+/// the statement finished. The number of returned rows and the duration
+/// in microseconds are reported by an event inside of the span.
+#[derive(Debug, Default)]
+pub struct TracingInstrumentation {
+    spans: RefCell<Vec<Span>>,
+}
+
+impl TracingInstrumentation {
+    /// Create a new instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Instrumentation for TracingInstrumentation {
+    fn statement_started(&self, statement: &Statement<'_>) {
+        let span = tracing_internal::debug_span!(
+            "wundergraph.sql",
+            sql = statement.sql,
+            bind_count = statement.bind_count as u64,
+            path = %statement.path.join("."),
+            type_name = statement.type_name,
+        );
+        self.spans.borrow_mut().push(span);
+    }
+
+    fn statement_finished(&self, _statement: &Statement<'_>, outcome: &StatementOutcome) {
+        if let Some(span) = self.spans.borrow_mut().pop() {
+            let _entered = span.enter();
+            let duration_us = outcome.duration.as_micros() as u64;
+            match outcome.rows {
+                Some(rows) => tracing_internal::debug!(
+                    rows = rows as u64,
+                    duration_us,
+                    "statement finished"
+                ),
+                None => tracing_internal::warn!(duration_us, "statement failed"),
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Instrumentation, Statement, StatementOutcome};
+use crate::scalar::WundergraphScalarValue;
+use indexmap::IndexMap;
+use juniper::{Object, Value};
+use std::cell::RefCell;
+
+/// Collects the sql of all statements executed for a request, so it could
+/// be returned as part of the response
+///
+/// The statements are grouped by the graphql field path that caused them.
+/// Optionally the query plan of each statement is included, using
+/// `EXPLAIN` on postgres and `EXPLAIN QUERY PLAN` on sqlite.
+///
+/// ```json
+/// {
+///   "wundergraph": {
+///     "sql": {
+///       "Heros.species": [
+///         {
+///           "sql": "SELECT ...",
+///           "bindCount": 2,
+///           "rows": 2,
+///           "duration": 120000,
+///           "explain": ["Seq Scan on species  (cost=0.00..25.88 rows=6 width=36)"]
+///         }
+///       ]
+///     }
+///   }
+/// }
+/// ```
+///
+/// The generated sql exposes details about the database schema, therefore
+/// a `SqlDebug` could only be created for privileged requests.
+///
+/// ```rust
+/// # use wundergraph::instrumentation::{LogInstrumentation, SqlDebug};
+/// # let is_admin = true;
+/// // Use this as instrumentation of the context
+/// let instrumentation = (
+///     LogInstrumentation::default(),
+///     SqlDebug::for_request(is_admin).map(|debug| debug.with_explain(true)),
+/// );
+/// // Execute the request and merge `instrumentation.1.extensions()` into
+/// // the extensions of the response
+/// ```
+#[derive(Debug)]
+pub struct SqlDebug {
+    explain: bool,
+    statements: RefCell<IndexMap<String, Vec<DebugStatement>>>,
+}
+
+#[derive(Debug)]
+struct DebugStatement {
+    sql: String,
+    bind_count: usize,
+    rows: Option<usize>,
+    duration: i64,
+    plan: Option<Vec<String>>,
+}
+
+impl SqlDebug {
+    /// Collect the statements of a request if the request is privileged
+    ///
+    /// Returns `None` otherwise, so the debug output is never exposed to
+    /// regular clients.
+    pub fn for_request(privileged: bool) -> Option<Self> {
+        if privileged {
+            Some(Self {
+                explain: false,
+                statements: RefCell::new(IndexMap::new()),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Include the query plan of each statement
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// The `extensions` block containing all statements collected until now
+    pub fn extensions(&self) -> Value<WundergraphScalarValue> {
+        let statements = self.statements.borrow();
+        let mut sql = Object::with_capacity(statements.len());
+        for (path, statements) in statements.iter() {
+            sql.add_field(
+                path.as_str(),
+                Value::list(statements.iter().map(DebugStatement::to_value).collect()),
+            );
+        }
+
+        let mut wundergraph = Object::with_capacity(1);
+        wundergraph.add_field("sql", Value::object(sql));
+
+        let mut extensions = Object::with_capacity(1);
+        extensions.add_field("wundergraph", Value::object(wundergraph));
+        Value::object(extensions)
+    }
+}
+
+impl DebugStatement {
+    fn to_value(&self) -> Value<WundergraphScalarValue> {
+        let mut statement = Object::with_capacity(5);
+        statement.add_field("sql", Value::scalar(self.sql.clone()));
+        statement.add_field("bindCount", Value::scalar(self.bind_count as i32));
+        statement.add_field(
+            "rows",
+            self.rows
+                .map(|rows| Value::scalar(rows as i32))
+                .unwrap_or(Value::Null),
+        );
+        statement.add_field("duration", Value::scalar(self.duration));
+        if let Some(ref plan) = self.plan {
+            statement.add_field(
+                "explain",
+                Value::list(plan.iter().map(|l| Value::scalar(l.clone())).collect()),
+            );
+        }
+        Value::object(statement)
+    }
+}
+
+impl Instrumentation for SqlDebug {
+    fn explain(&self) -> bool {
+        self.explain
+    }
+
+    fn statement_finished(&self, statement: &Statement<'_>, outcome: &StatementOutcome) {
+        self.statements
+            .borrow_mut()
+            .entry(statement.path.join("."))
+            .or_insert_with(Vec::new)
+            .push(DebugStatement {
+                sql: statement.sql.to_owned(),
+                bind_count: statement.bind_count,
+                rows: outcome.rows,
+                duration: outcome.duration.as_nanos() as i64,
+                plan: statement.plan.map(<[String]>::to_vec),
+            });
+    }
+}
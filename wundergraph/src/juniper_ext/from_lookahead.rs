@@ -1,4 +1,4 @@
-use crate::scalar::WundergraphScalarValue;
+use crate::scalar::{bigint_from_str, WundergraphScalarValue};
 use juniper::{LookAheadValue, ID};
 
 /// A helper trait marking how to convert a `LookAheadValue` into a specific type
@@ -35,6 +35,7 @@ impl FromLookAheadValue for i64 {
             LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => Some(Self::from(*i)),
             LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => Some(Self::from(*i)),
             LookAheadValue::Scalar(WundergraphScalarValue::BigInt(ref i)) => Some(*i),
+            LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => bigint_from_str(s),
             _ => None,
         }
     }
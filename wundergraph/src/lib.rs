@@ -46,11 +46,15 @@ pub extern crate paste;
 #[macro_use]
 extern crate wundergraph_derive;
 #[doc(hidden)]
-pub use wundergraph_derive::{WundergraphEntity, WundergraphFilter, WundergraphValue};
+pub use wundergraph_derive::{
+    ExplicitNulls, ValidateInput, WundergraphEntity, WundergraphFilter, WundergraphScalar,
+    WundergraphValue,
+};
 
 pub mod diesel_ext;
 mod error;
 pub mod helper;
+pub mod instrumentation;
 pub mod juniper_ext;
 pub mod scalar;
 #[macro_use]
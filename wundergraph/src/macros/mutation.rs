@@ -26,6 +26,15 @@ macro_rules! __expand_register_delete {
             $info
         ).argument(delete);
         $fields.push(delete);
+        let delete_returning = $registry.arg::<$($delete)*>(
+            concat!("Delete", stringify!($entity_name)),
+            &std::default::Default::default(),
+        );
+        let delete_returning = $registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
+            concat!("Delete", stringify!($entity_name), "Returning"),
+            $info
+        ).argument(delete_returning);
+        $fields.push(delete_returning);
     }}
 }
 
@@ -58,10 +67,190 @@ macro_rules! __expand_resolve_delete {
        $($delete)*,
        $entity_name,
        Self::Context,
-               >($executor, $arguments, concat!("Delete", stringify!($entity_name)))
+       _,
+               >(
+           $executor,
+           $arguments,
+           concat!("Delete", stringify!($entity_name)),
+           $crate::__entity_hooks!($entity_name, ()),
+       )
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_resolve_delete_returning {
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, ) => {
+        $crate::__expand_resolve_delete_returning!($entity_name, $selection, $executor, $arguments, true)
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, true) => {
+        $crate::__expand_resolve_delete_returning!(
+            $entity_name, $selection, $executor, $arguments,
+            $crate::helper::primary_keys::PrimaryKeyArgument<
+                'static,
+                 <$entity_name as $crate::diesel::associations::HasTable>::Table,
+                 Ctx,
+                 <&'static $entity_name as $crate::diesel::Identifiable>::Id
+            >
+        )
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, false) => {
+        Err($crate::juniper::FieldError::new(
+            "Unknown field:",
+            $crate::juniper::Value::scalar(concat!("Delete", stringify!($entity_name), "Returning")),
+        ))
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, $($delete:tt)*) => {
+       $crate::query_builder::mutations::handle_delete_returning::<
+           DB,
+       $($delete)*,
+       $entity_name,
+       Self::Context,
+       _,
+               >(
+           $selection,
+           $executor,
+           $arguments,
+           concat!("Delete", stringify!($entity_name)),
+           $crate::__entity_hooks!($entity_name, ()),
+       )
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_register_restore {
+    ($entity_name: ident, $registry: ident, $fields: ident, $info: ident,) => {
+        $crate::__expand_register_restore!($entity_name, $registry, $fields, $info, true)
+    };
+    ($entity_name: ident, $registry: ident, $fields: ident, $info: ident, true) => {
+        $crate::__expand_register_restore!(
+            $entity_name, $registry, $fields, $info,
+            $crate::helper::primary_keys::PrimaryKeyArgument<
+                'static,
+                 <$entity_name as $crate::diesel::associations::HasTable>::Table,
+                 Ctx,
+                 <&'static $entity_name as $crate::diesel::Identifiable>::Id
+            >
+        )
+    };
+    ($entity_name: ident, $registry: ident, $fields: ident, $info: ident, false) => {};
+    ($entity_name: ident, $registry: ident, $fields: ident, $info: ident, $($delete:tt)*) => {
+        if <$entity_name as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::SOFT_DELETE {
+            let restore = $registry.arg::<$($delete)*>(
+                concat!("Restore", stringify!($entity_name)),
+                &std::default::Default::default(),
+            );
+            let restore = $registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
+                concat!("Restore", stringify!($entity_name)),
+                $info
+            ).argument(restore);
+            $fields.push(restore);
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_resolve_restore {
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, ) => {
+        $crate::__expand_resolve_restore!($entity_name, $selection, $executor, $arguments, true)
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, true) => {
+        $crate::__expand_resolve_restore!(
+            $entity_name, $selection, $executor, $arguments,
+            $crate::helper::primary_keys::PrimaryKeyArgument<
+                'static,
+                 <$entity_name as $crate::diesel::associations::HasTable>::Table,
+                 Ctx,
+                 <&'static $entity_name as $crate::diesel::Identifiable>::Id
+            >
+        )
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, false) => {
+        Err($crate::juniper::FieldError::new(
+            "Unknown field:",
+            $crate::juniper::Value::scalar(concat!("Restore", stringify!($entity_name))),
+        ))
+    };
+    ($entity_name: ident, $selection: expr, $executor: ident, $arguments: ident, $($delete:tt)*) => {
+       $crate::query_builder::mutations::handle_restore::<
+           DB,
+       $($delete)*,
+       $entity_name,
+       Self::Context,
+       _,
+               >(
+           $selection,
+           $executor,
+           $arguments,
+           concat!("Restore", stringify!($entity_name)),
+           $crate::__entity_hooks!($entity_name, ()),
+       )
     }
 }
 
+/// Select the validator of an input type
+///
+/// Uses the `ValidateInput` implementation if there is one, otherwise the
+/// input is not validated. The method is called on a `&&ValidatorFor<T>`,
+/// so method resolution tries the impl for `&ValidatorFor<T>` before it
+/// auto-derefs to the one for `ValidatorFor<T>`. This only works for
+/// concrete types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validator {
+    ($input: ty) => {{
+        #[allow(unused_imports)]
+        use $crate::query_builder::mutations::validate::{WithValidation, WithoutValidation};
+        (&&$crate::query_builder::mutations::validate::ValidatorFor::<$input>::new()).validator()
+    }};
+}
+
+/// Select the `ExplicitNulls` implementation of a changeset
+///
+/// Works like `__validator!`, changesets without an implementation ignore
+/// explicit nulls.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __null_setter {
+    ($input: ty) => {{
+        #[allow(unused_imports)]
+        use $crate::query_builder::mutations::{WithExplicitNulls, WithoutExplicitNulls};
+        (&&$crate::query_builder::mutations::NullSetterFor::<$input>::new()).null_setter()
+    }};
+}
+
+/// Select the `EntityHooks` implementation of an entity for an input type
+///
+/// Works like `__validator!`, entities without an implementation use the
+/// no-op hooks of `()`. Backend and context are inferred from the handler
+/// the hooks are passed to.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __entity_hooks {
+    ($entity: ty, $input: ty) => {{
+        #[allow(unused_imports)]
+        use $crate::query_builder::mutations::{WithEntityHooks, WithoutEntityHooks};
+        (&&$crate::query_builder::mutations::EntityHooksFor::<$entity, $input>::new()).entity_hooks()
+    }};
+}
+
+/// Select the type providing the relation mutations of an entity
+///
+/// `relations = true` uses the entity itself, `relations = false` uses
+/// `()`, which has no relation mutations.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __relation_mutations {
+    ($entity_name: ident, true) => {
+        $entity_name
+    };
+    ($entity_name: ident, false) => {
+        ()
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __build_mutation_trait_bounds {
@@ -70,6 +259,7 @@ macro_rules! __build_mutation_trait_bounds {
         structs = [$($entity_name: ident(
             $(insert = $insert: ident,)?
             $(update = $update: ident,)?
+            $(relations = $relations: tt,)?
             $(delete = $($delete:tt)*)?
         ),)*],
         $(lt = $lt: tt,)?
@@ -87,6 +277,7 @@ macro_rules! __build_mutation_trait_bounds {
                     structs = [$($entity_name(
                         $(insert = $insert,)?
                             $(update = $update,)?
+                            $(relations = $relations,)?
                     ),)*],
                     $(lt = $lt,)?
                         body = {
@@ -172,6 +363,12 @@ macro_rules! __build_mutation_trait_bounds {
                 {
                     $($table)*: $crate::query_builder::mutations::HandleDelete<$entity_name, $($delete)*, DB, Ctx>
                 },
+                {
+                    $($table)*: $crate::query_builder::mutations::HandleDeleteReturning<$entity_name, $($delete)*, DB, Ctx>
+                },
+                {
+                    $($table)*: $crate::query_builder::mutations::HandleRestore<$entity_name, $($delete)*, DB, Ctx>
+                },
             ],
         }
     };
@@ -184,6 +381,7 @@ macro_rules! __build_mutation_trait_bounds {
                 $($entity_name: ident(
                    $(insert = $insert: ident,)?
                    $(update = $update: ident,)?
+                   $(relations = $relations: tt,)?
                 ),)*
            ],
            $(lt = $lt: tt,)?
@@ -196,7 +394,7 @@ macro_rules! __build_mutation_trait_bounds {
         $crate::__impl_graphql_obj_for_mutation! {
             mutation_name = {$($mutation_name)*},
             structs = [
-                $($entity_name($(insert = $insert,)? $(update = $update,)?),)*
+                $($entity_name($(insert = $insert,)? $(update = $update,)? $(relations = $relations,)?),)*
             ],
             additional_bound = [$({$($bounds)*},)*],
             $(lt = $lt,)?
@@ -216,6 +414,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
         structs = [$($entity_name: ident(
             $(insert = $insert: ident,)?
             $(update = $update: ident,)?
+            $(relations = $relations: tt,)?
             $(delete = ($($delete:tt)*))?
         ),)*],
         $(lt = $lt: tt,)?
@@ -226,7 +425,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
         $crate::__build_mutation_trait_bounds! {
             mutation_name = {$($mutation_name)*},
             structs = [
-                $($entity_name($(insert = $insert,)? $(update = $update,)? $(delete = $($delete)*)?),)*
+                $($entity_name($(insert = $insert,)? $(update = $update,)? $(relations = $relations,)? $(delete = $($delete)*)?),)*
             ],
             $(lt = $lt,)?
             body = {
@@ -239,6 +438,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
         structs = [$($entity_name: ident(
             $(insert = $insert: ident,)?
             $(update = $update: ident,)?
+            $(relations = $relations: tt,)?
         ),)*],
         additional_bound = [$({$($bounds:tt)*},)*],
         $(lt = $lt: tt,)?
@@ -250,7 +450,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
             impl<$($lt,)? Ctx, DB, $([<$entity_name _table>],)* $([<$entity_name _id>],)*> $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>
                 for $($mutation_name)*<$($lt,)? Ctx>
             where Ctx: $crate::context::WundergraphContext,
-                  DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + 'static,
+                  DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + $crate::instrumentation::Explain + 'static,
                   DB::QueryBuilder: std::default::Default,
                   Ctx::Connection: $crate::diesel::Connection<Backend = DB>,
                   $($entity_name: $crate::query_builder::selection::LoadingHandler<DB, Ctx> + $crate::diesel::associations::HasTable<Table = [<$entity_name _table>]>,)*
@@ -286,6 +486,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
                   $($([<$entity_name _table>]: $crate::query_builder::mutations::HandleInsert<$entity_name, $insert, DB, Ctx>,)*)*
                   $($([<$entity_name _table>]: $crate::query_builder::mutations::HandleBatchInsert<$entity_name, $insert, DB, Ctx>,)*)*
                   $($([<$entity_name _table>]: $crate::query_builder::mutations::HandleUpdate<$entity_name, $update, DB, Ctx>,)*)*
+                  $($($crate::__relation_mutations!($entity_name, $relations): $crate::query_builder::mutations::RelationMutations<DB, Ctx>,)?)*
                   $($($bounds)*,)*
 
             {
@@ -303,6 +504,7 @@ macro_rules! mutation_object {
             $($entity_name: ident (
                 $(insert = $insert: ident,)?
                 $(update = $update: ident,)?
+                $(relations = $relations: tt,)?
                 $(delete = $($delete: tt)*)?
                 $(,)?
             ),)*
@@ -326,6 +528,7 @@ macro_rules! mutation_object {
                 structs = [$($entity_name(
                     $(insert = $insert,)?
                     $(update = $update,)?
+                    $(relations = $relations,)?
                     $(delete = ($($delete)*))?
                 ),)*],
                 body = {
@@ -354,12 +557,16 @@ macro_rules! mutation_object {
                         arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
                         executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
                     ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                        let _field = $crate::instrumentation::enter_field(field_name);
                         let wrapper = [<$mutation_name _wrapper>](
                             ::std::marker::PhantomData,
                             field_name,
                             arguments,
                         );
-                        executor.resolve(info, &wrapper)
+                        $crate::query_builder::types::with_key_codec_context(
+                            $crate::context::WundergraphContext::key_codec_context(executor.context()),
+                            || executor.resolve(info, &wrapper),
+                        )
                     }
                 }
             }
@@ -379,6 +586,7 @@ macro_rules! mutation_object {
                 structs = [$($entity_name(
                     $(insert = $insert,)?
                     $(update = $update,)?
+                    $(relations = $relations,)?
                     $(delete = ($($delete)*))?
                 ),)*],
                 lt = 'a,
@@ -444,6 +652,7 @@ macro_rules! mutation_object {
                 structs = [$($entity_name(
                     $(insert = $insert,)?
                     $(update = $update,)?
+                    $(relations = $relations,)?
                     $(delete = ($($delete)*))?
                 ),)*],
                 lt = 'a,
@@ -492,6 +701,16 @@ macro_rules! mutation_object {
                             )*
                             $(
                                 $crate::__expand_register_delete!($entity_name, registry, fields, info, $($($delete)*)?);
+                                $crate::__expand_register_restore!($entity_name, registry, fields, info, $($($delete)*)?);
+                            )*
+                            $(
+                                $(
+                                    let relations = <$crate::__relation_mutations!($entity_name, $relations) as $crate::query_builder::mutations::RelationMutations<DB, Ctx>>::RELATION_MUTATIONS;
+                                    for (idx, name) in relations.iter().enumerate() {
+                                        let field = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(name, info);
+                                        fields.push(<$crate::__relation_mutations!($entity_name, $relations) as $crate::query_builder::mutations::RelationMutations<DB, Ctx>>::register_relation_mutation_arguments(idx, registry, field));
+                                    }
+                                )?
                             )*
                             let mut mutation = registry.build_object_type::<Self>(info, &fields);
                         mutation = mutation.description(concat!($($glob_doc, "\n",)* ""));
@@ -513,12 +732,15 @@ macro_rules! mutation_object {
                                             DB,
                                         $insert,
                                         $entity_name,
-                                        Self::Context>
+                                        Self::Context,
+                                        _>
                                             (
                                                 self.1,
                                                 executor,
                                                 arguments,
-                                                concat!("New", stringify!($entity_name))
+                                                concat!("New", stringify!($entity_name)),
+                                                $crate::__validator!($insert),
+                                                $crate::__entity_hooks!($entity_name, $insert)
                                             )
                                     }
                                     concat!("Create", stringify!($entity_name), "s") => {
@@ -526,12 +748,15 @@ macro_rules! mutation_object {
                                             DB,
                                         $insert,
                                         $entity_name,
-                                        Self::Context>
+                                        Self::Context,
+                                        _>
                                             (
                                                 self.1,
                                                 executor,
                                                 arguments,
-                                                concat!("New", stringify!($entity_name), "s")
+                                                concat!("New", stringify!($entity_name), "s"),
+                                                $crate::__validator!($insert),
+                                                $crate::__entity_hooks!($entity_name, $insert)
                                             )
                                     }
                                 )*
@@ -543,12 +768,16 @@ macro_rules! mutation_object {
                                                 DB,
                                             $update,
                                             $entity_name,
-                                            Self::Context
+                                            Self::Context,
+                                            _
                                                 >(
                                                     self.1,
                                                     executor,
                                                     arguments,
-                                                    concat!("Update", stringify!($entity_name))
+                                                    concat!("Update", stringify!($entity_name)),
+                                                    $crate::__validator!($update),
+                                                    $crate::__null_setter!($update),
+                                                    $crate::__entity_hooks!($entity_name, $update)
                                                 )
                                         }
                                     )*
@@ -557,11 +786,27 @@ macro_rules! mutation_object {
                                     concat!("Delete", stringify!($entity_name)) => {
                                         $crate::__expand_resolve_delete!($entity_name, executor, arguments, $($($delete)*)?)
                                     }
+                                    concat!("Delete", stringify!($entity_name), "Returning") => {
+                                        $crate::__expand_resolve_delete_returning!($entity_name, self.1, executor, arguments, $($($delete)*)?)
+                                    }
+                                    concat!("Restore", stringify!($entity_name)) => {
+                                        $crate::__expand_resolve_restore!($entity_name, self.1, executor, arguments, $($($delete)*)?)
+                                    }
                                  )*
-                                e => Err($crate::juniper::FieldError::new(
-                                    "Unknown field:",
-                                    $crate::juniper::Value::scalar(e),
-                                )),
+                                e => {
+                                    $(
+                                        $(
+                                            let relations = <$crate::__relation_mutations!($entity_name, $relations) as $crate::query_builder::mutations::RelationMutations<DB, Ctx>>::RELATION_MUTATIONS;
+                                            if let Some(idx) = relations.iter().position(|n| *n == e) {
+                                                return <$crate::__relation_mutations!($entity_name, $relations) as $crate::query_builder::mutations::RelationMutations<DB, Ctx>>::handle_relation_mutation(idx, self.1, executor);
+                                            }
+                                        )?
+                                    )*
+                                    Err($crate::juniper::FieldError::new(
+                                        "Unknown field:",
+                                        $crate::juniper::Value::scalar(e),
+                                    ))
+                                }
                         }
                     }
                 }
@@ -103,7 +103,6 @@ macro_rules! __expand_name {
     };
 }
 
-
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_graphql_obj_for_query {
@@ -119,7 +118,7 @@ macro_rules! __impl_graphql_obj_for_query {
             impl<$($lt,)? Ctx, DB, $([<$graphql_struct _table>], [<$graphql_struct _id>],)*> $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>
                 for $($query_name)*<$($lt,)? Ctx>
             where Ctx: $crate::context::WundergraphContext,
-                  DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + 'static,
+                  DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + $crate::instrumentation::Explain + 'static,
                   DB::QueryBuilder: std::default::Default,
                   Ctx::Connection: $crate::diesel::Connection<Backend = DB>,
             $([<$graphql_struct _table>]: $crate::diesel::Table + $crate::diesel::query_dsl::methods::BoxedDsl<
@@ -172,6 +171,12 @@ macro_rules! __impl_graphql_obj_for_query {
                   >,)*
                 $([<$graphql_struct _table>]::PrimaryKey: $crate::diesel::EqAll<<[<$graphql_struct _id>] as $crate::helper::primary_keys::UnRef<'static>>::UnRefed>,)*
                 $(<[<$graphql_struct _table>]::PrimaryKey as $crate::diesel::EqAll<<[<$graphql_struct _id>] as $crate::helper::primary_keys::UnRef<'static>>::UnRefed>>::Output: $crate::diesel::AppearsOnTable<[<$graphql_struct _table>]> + $crate::diesel::query_builder::QueryFragment<DB> + $crate::diesel::expression::NonAggregate,)*
+                $(<[<$graphql_struct _id>] as $crate::helper::primary_keys::UnRef<'static>>::UnRefed: std::hash::Hash + std::cmp::Eq,)*
+                $([<$graphql_struct _table>]::PrimaryKey: $crate::helper::primary_keys::PrimaryKeysFilter<
+                  [<$graphql_struct _table>],
+                  DB,
+                  <[<$graphql_struct _id>] as $crate::helper::primary_keys::UnRef<'static>>::UnRefed
+                  >,)*
                 $(<<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::Filter as $crate::query_builder::selection::filter::build_filter::BuildFilter<DB>>::Ret: $crate::diesel::AppearsOnTable<[<$graphql_struct _table>]>,)*
                 $(<<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList as $crate::query_builder::selection::fields::FieldListExtractor>::Out:
                   $crate::graphql_type::WundergraphGraphqlHelper<$graphql_struct, DB, Ctx> +
@@ -244,12 +249,16 @@ macro_rules! query_object {
                         arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
                         executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
                     ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                        let _field = $crate::instrumentation::enter_field(field_name);
                         let wrapper = [<$query_name _wrapper>](
                             ::std::marker::PhantomData,
                             field_name,
                             arguments,
                         );
-                        executor.resolve(info, &wrapper)
+                        $crate::query_builder::types::with_key_codec_context(
+                            $crate::context::WundergraphContext::key_codec_context(executor.context()),
+                            || executor.resolve(info, &wrapper),
+                        )
                     }
                 }
             }
@@ -343,7 +352,11 @@ macro_rules! query_object {
                     ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
                     where $crate::scalar::WundergraphScalarValue: 'r
                     {
-                        let fields = &[
+                        let node_interface = <Ctx as $crate::context::WundergraphContext>::NODE_INTERFACE;
+                        if node_interface {
+                            registry.get_type::<$crate::query_builder::selection::node::Node>(&());
+                        }
+                        let mut fields = vec![
                             $(
                                 {
                                     let mut field = registry.field::<Vec<$crate::graphql_type::GraphqlWrapper<
@@ -368,7 +381,7 @@ macro_rules! query_object {
                                         field,
                                         <Ctx as $crate::context::WundergraphContext>::Connection,
                                         $graphql_struct $($(, $order)?)?);
-                                    field
+                                    $crate::query_builder::selection::soft_delete::register_soft_delete_arguments::<$graphql_struct, DB, Ctx>(registry, field)
                                 },
                                 {
                                     let key_info = $crate::helper::primary_keys::PrimaryKeyInfo::default();
@@ -380,16 +393,45 @@ macro_rules! query_object {
                                     <&'static $graphql_struct as $crate::diesel::Identifiable>::Id
                                         >
                                         >("primaryKey", &key_info);
-                                    registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, <<Ctx as $crate::context::WundergraphContext>::Connection as $crate::diesel::Connection>::Backend, Ctx>>>(
+                                    let field = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, <<Ctx as $crate::context::WundergraphContext>::Connection as $crate::diesel::Connection>::Backend, Ctx>>>(
                                         stringify!($graphql_struct),
                                         info
-                                    ).argument(key)
-                                }
-                                ,
+                                    ).argument(key);
+                                    $crate::query_builder::selection::soft_delete::register_soft_delete_arguments::<$graphql_struct, DB, Ctx>(registry, field)
+                                },
+                                {
+                                    let key_info = $crate::helper::primary_keys::PrimaryKeyInfo::default();
+                                    let ids = registry.arg::<Vec<
+                                        $crate::helper::primary_keys::PrimaryKeyArgument<
+                                        'static,
+                                    <$graphql_struct as $crate::diesel::associations::HasTable>::Table,
+                                    Ctx,
+                                    <&'static $graphql_struct as $crate::diesel::Identifiable>::Id
+                                        >
+                                        >>("ids", &key_info);
+                                    let field = registry.field::<Vec<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, DB, Ctx>>>>(
+                                        concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "ByIds"),
+                                        info
+                                    ).argument(ids);
+                                    $crate::query_builder::selection::soft_delete::register_soft_delete_arguments::<$graphql_struct, DB, Ctx>(registry, field)
+                                },
 
                             )*
                         ];
-                        let mut obj = registry.build_object_type::<Self>(info, fields);
+                        $(
+                            let type_name = <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::TYPE_NAME;
+                            let lookups = <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::UNIQUE_LOOKUPS;
+                            for (idx, suffix) in lookups.iter().enumerate() {
+                                let name = format!("{}{}", type_name, suffix);
+                                let field = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, DB, Ctx>>>(&name, info);
+                                let field = <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::register_unique_lookup_arguments(idx, registry, field);
+                                fields.push($crate::query_builder::selection::soft_delete::register_soft_delete_arguments::<$graphql_struct, DB, Ctx>(registry, field));
+                            }
+                        )*
+                        if node_interface {
+                            fields.extend($crate::query_builder::selection::node::register_node_fields(registry));
+                        }
+                        let mut obj = registry.build_object_type::<Self>(info, &fields);
                         obj = obj.description(concat!($($glob_doc, "\n", )* ""));
                         obj.into_meta()
                     }
@@ -404,6 +446,43 @@ macro_rules! query_object {
                         use $crate::query_builder::selection::LoadingHandler;
                         use $crate::context::WundergraphContext;
                         match field_name {
+                            "node" | "nodes" if Ctx::NODE_INTERFACE => {
+                                use $crate::helper::primary_keys::PrimaryKeyInputObject;
+                                use $crate::query_builder::selection::node;
+                                let look_ahead = executor.look_ahead();
+                                let ids = node::node_id_arguments(&look_ahead)?;
+                                let mut items = vec![$crate::juniper::Value::Null; ids.len()];
+                                $(
+                                    let type_name = <$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME;
+                                    let (positions, keys): (Vec<_>, Vec<_>) = ids
+                                        .iter()
+                                        .enumerate()
+                                        .filter(|(_, (t, _))| t == type_name)
+                                        .filter_map(|(pos, (_, key))| {
+                                            <[<$graphql_struct _table>]::PrimaryKey as PrimaryKeyInputObject<
+                                                <[<$graphql_struct _id>] as $crate::helper::primary_keys::UnRef<'static>>::UnRefed, ()
+                                            >>::from_input_value(key).map(|key| (pos, key))
+                                        })
+                                        .unzip();
+                                    if !keys.is_empty() {
+                                        // Resolve the fields of the concrete type instead of the `Node` interface
+                                        let executor = executor.type_sub_executor(Some(type_name), self.1);
+                                        let q = $graphql_struct::build_query(&look_ahead)?;
+                                        let loaded = $graphql_struct::load_by_keys(&look_ahead, self.1, &executor, q, keys)?;
+                                        for (pos, item) in positions.into_iter().zip(loaded) {
+                                            if let Some(mut item) = item {
+                                                node::add_typename(type_name, &look_ahead, self.1, &mut item);
+                                                items[pos] = item;
+                                            }
+                                        }
+                                    }
+                                )*
+                                if field_name == "node" {
+                                    Ok(items.pop().unwrap_or($crate::juniper::Value::Null))
+                                } else {
+                                    Ok($crate::juniper::Value::List(items))
+                                }
+                            }
                             $(
                                 $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*) => {
                                     let look_ahead = executor.look_ahead();
@@ -417,11 +496,37 @@ macro_rules! query_object {
                                     let item = $graphql_struct::load_by_primary_key(&look_ahead, self.1, executor, q)?;
                                     Ok(item.unwrap_or($crate::juniper::Value::Null))
                                 }
+                                concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "ByIds") => {
+                                    let look_ahead = executor.look_ahead();
+                                    let q = $graphql_struct::build_query(&look_ahead)?;
+                                    let items = $graphql_struct::load_by_primary_keys(&look_ahead, self.1, executor, q)?;
+                                    Ok($crate::juniper::Value::List(
+                                        items.into_iter().map(|i| i.unwrap_or($crate::juniper::Value::Null)).collect()
+                                    ))
+                                }
                             )*
-                                e => Err($crate::juniper::FieldError::new(
-                                    "Unknown field:",
-                                    $crate::juniper::Value::scalar(e),
-                                )),
+                                e => {
+                                    $(
+                                        let type_name = <$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME;
+                                        let lookups = <$graphql_struct as LoadingHandler<DB, Ctx>>::UNIQUE_LOOKUPS;
+                                        let lookup = if e.starts_with(type_name) {
+                                            lookups.iter().position(|suffix| *suffix == &e[type_name.len()..])
+                                        } else {
+                                            None
+                                        };
+                                        if let Some(idx) = lookup {
+                                            let look_ahead = executor.look_ahead();
+                                            let q = $graphql_struct::build_query(&look_ahead)?;
+                                            let q = $graphql_struct::apply_unique_lookup(idx, q, &look_ahead)?;
+                                            let item = $graphql_struct::load(&look_ahead, self.1, executor, q)?;
+                                            return Ok(item.into_iter().next().unwrap_or($crate::juniper::Value::Null));
+                                        }
+                                    )*
+                                    Err($crate::juniper::FieldError::new(
+                                        "Unknown field:",
+                                        $crate::juniper::Value::scalar(e),
+                                    ))
+                                }
                         }
                     }
 
@@ -0,0 +1,662 @@
+//! Changeset field types applying atomic operators to a column
+//!
+//! Incrementing a counter through a plain changeset requires the client
+//! to read the current value, change it and write it back, which loses
+//! concurrent updates. Using one of the types of this module as field
+//! type of a changeset instead lets the database compute the new value,
+//! for example `SET view_count = view_count + $1`, as part of the
+//! `UPDATE` statement executed by the update mutation.
+//!
+//! In graphql each of those types is an input object with one optional
+//! field per operator. Exactly one operator needs to be given, otherwise
+//! the update fails with an error naming the column and the reason.
+//! `{set: null}` sets a nullable column to `NULL`, like an explicit `null`
+//! for a plain changeset field implementing
+//! [`ExplicitNulls`](../trait.ExplicitNulls.html). For columns that are not
+//! nullable this is rejected as well.
+//!
+//! * [`NumericUpdate`](type.NumericUpdate.html): `set`, `inc`, `dec` and `mul`
+//! * [`TextUpdate`](type.TextUpdate.html): `set`, `append` and `prepend`
+//! * [`ArrayUpdate`](type.ArrayUpdate.html) (postgres only): `set`,
+//!   `append` and `remove`
+//! * [`TimestampUpdate`](type.TimestampUpdate.html) (with the `chrono`
+//!   feature): `set` and `now`
+//!
+//! Operators applied to a `NULL` value result in `NULL`, as usual in sql.
+//! The only exception is appending to an array, as postgres treats a
+//! `NULL` array like an empty one there.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate diesel;
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # extern crate wundergraph;
+//! use wundergraph::query_builder::mutations::{NumericUpdate, TextUpdate};
+//! use wundergraph::scalar::WundergraphScalarValue;
+//!
+//! # table! {
+//! #    heros {
+//! #        id -> Integer,
+//! #        name -> Text,
+//! #        view_count -> Integer,
+//! #    }
+//! # }
+//! #[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
+//! #[graphql(scalar = "WundergraphScalarValue")]
+//! #[table_name = "heros"]
+//! pub struct HeroChangeset {
+//!     id: i32,
+//!     name: Option<TextUpdate<heros::name>>,
+//!     view_count: Option<NumericUpdate<heros::view_count, i32>>,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! A client then increments the counter by sending
+//! `{id: 1, view_count: {inc: 1}}`.
+
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::Error as DieselError;
+use diesel::result::QueryResult;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{HasSqlType, IntoNullable};
+use diesel::Column;
+use indexmap::IndexMap;
+use juniper::meta::{Argument, MetaType};
+use juniper::{FromInputValue, GraphQLType, InputValue, Registry, ToInputValue};
+use std::any::TypeId;
+
+/// A changeset field applying an atomic operator to the column `C`
+///
+/// Use one of the type aliases [`NumericUpdate`](type.NumericUpdate.html),
+/// [`TextUpdate`](type.TextUpdate.html), [`ArrayUpdate`](type.ArrayUpdate.html)
+/// or [`TimestampUpdate`](type.TimestampUpdate.html) as field type.
+#[derive(Debug, Clone)]
+pub struct AtomicUpdate<C, O> {
+    column: C,
+    op: AtomicOperation<O>,
+}
+
+#[derive(Debug, Clone)]
+enum AtomicOperation<O> {
+    Apply(O),
+    SetNull,
+    // Reported when the update is executed, as juniper expects the
+    // conversion of schema conforming input objects to succeed
+    Invalid(String),
+}
+
+impl<C, O> AtomicUpdate<C, O>
+where
+    C: Default,
+{
+    /// Apply the given operation to the column
+    pub fn new(op: O) -> Self {
+        Self::with_operation(AtomicOperation::Apply(op))
+    }
+
+    /// Set the column to `NULL`
+    pub fn null() -> Self {
+        Self::with_operation(AtomicOperation::SetNull)
+    }
+
+    fn with_operation(op: AtomicOperation<O>) -> Self {
+        Self {
+            column: C::default(),
+            op,
+        }
+    }
+}
+
+impl<C, O> AtomicUpdate<C, O> {
+    /// The operation applied to the column
+    ///
+    /// `None` if the column is set to `NULL` or if the input was invalid
+    pub fn operation(&self) -> Option<&O> {
+        match self.op {
+            AtomicOperation::Apply(ref op) => Some(op),
+            AtomicOperation::SetNull | AtomicOperation::Invalid(_) => None,
+        }
+    }
+}
+
+impl<C, O> AtomicUpdate<C, O>
+where
+    C: Column,
+    C::SqlType: IntoNullable + 'static,
+    <C::SqlType as IntoNullable>::Nullable: 'static,
+{
+    /// Walk the given operation, `NULL` or fail for invalid input
+    fn walk_operation<'a, DB, F>(&'a self, mut pass: AstPass<'_, DB>, walk: F) -> QueryResult<()>
+    where
+        DB: Backend,
+        F: FnOnce(&'a O, AstPass<'_, DB>) -> QueryResult<()>,
+    {
+        match self.op {
+            AtomicOperation::Apply(ref op) => walk(op, pass),
+            AtomicOperation::SetNull if Self::is_nullable() => {
+                pass.push_sql("NULL");
+                Ok(())
+            }
+            AtomicOperation::SetNull => Err(Self::invalid("the column is not nullable")),
+            AtomicOperation::Invalid(ref reason) => Err(Self::invalid(reason)),
+        }
+    }
+
+    fn is_nullable() -> bool {
+        TypeId::of::<C::SqlType>() == TypeId::of::<<C::SqlType as IntoNullable>::Nullable>()
+    }
+
+    fn invalid(reason: &str) -> DieselError {
+        DieselError::QueryBuilderError(
+            format!("Invalid update of column `{}`: {}", C::NAME, reason).into(),
+        )
+    }
+}
+
+/// An operation of an [`AtomicUpdate`](struct.AtomicUpdate.html)
+///
+/// Describes the graphql input object for a given operation type.
+pub trait UpdateOperation: Sized {
+    /// The graphql name of the input object
+    const NAME: &'static str;
+
+    /// Register one field per operator
+    fn register_fields<'r>(
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>>;
+
+    /// Parse the value given for the operator `name`
+    fn from_input_field(name: &str, value: &InputValue<WundergraphScalarValue>) -> Option<Self>;
+
+    /// The operator and the value of this operation
+    fn to_input_field(&self) -> (&'static str, InputValue<WundergraphScalarValue>);
+}
+
+/// An operation on a numeric column
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumericOp<T> {
+    /// `SET column = value`
+    Set(T),
+    /// `SET column = column + value`
+    Inc(T),
+    /// `SET column = column - value`
+    Dec(T),
+    /// `SET column = column * value`
+    Mul(T),
+}
+
+/// An operation on a text column
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextOp {
+    /// `SET column = value`
+    Set(String),
+    /// `SET column = column || value`
+    Append(String),
+    /// `SET column = value || column`
+    Prepend(String),
+}
+
+/// An operation on an array column
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayOp<T> {
+    /// `SET column = value`
+    Set(Vec<T>),
+    /// `SET column = column || value`
+    Append(Vec<T>),
+    /// Removes all occurrences of the given elements using `array_remove`
+    Remove(Vec<T>),
+}
+
+/// An operation on a timestamp column
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimestampOp<T> {
+    /// `SET column = value`
+    Set(T),
+    /// `SET column = CURRENT_TIMESTAMP`
+    Now,
+}
+
+/// Update a numeric column using `set`, `inc`, `dec` or `mul`
+pub type NumericUpdate<C, T> = AtomicUpdate<C, NumericOp<T>>;
+/// Update a text column using `set`, `append` or `prepend`
+pub type TextUpdate<C> = AtomicUpdate<C, TextOp>;
+/// Update an array column using `set`, `append` or `remove`
+///
+/// Only supported on postgres
+pub type ArrayUpdate<C, T> = AtomicUpdate<C, ArrayOp<T>>;
+/// Update a timestamp column using `set` or `now`
+///
+/// `now: true` sets the column to the current timestamp of the database
+pub type TimestampUpdate<C, T> = AtomicUpdate<C, TimestampOp<T>>;
+
+macro_rules! numeric_operations {
+    ($($ty:ty => $name:expr,)*) => {$(
+        impl UpdateOperation for NumericOp<$ty> {
+            const NAME: &'static str = $name;
+
+            fn register_fields<'r>(
+                registry: &mut Registry<'r, WundergraphScalarValue>,
+            ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+                vec![
+                    registry
+                        .arg::<Option<$ty>>("set", &())
+                        .description("Set the column to the given value"),
+                    registry
+                        .arg::<Option<$ty>>("inc", &())
+                        .description("Add the given value to the column"),
+                    registry
+                        .arg::<Option<$ty>>("dec", &())
+                        .description("Subtract the given value from the column"),
+                    registry
+                        .arg::<Option<$ty>>("mul", &())
+                        .description("Multiply the column with the given value"),
+                ]
+            }
+
+            fn from_input_field(
+                name: &str,
+                value: &InputValue<WundergraphScalarValue>,
+            ) -> Option<Self> {
+                let value = <$ty>::from_input_value(value)?;
+                match name {
+                    "set" => Some(NumericOp::Set(value)),
+                    "inc" => Some(NumericOp::Inc(value)),
+                    "dec" => Some(NumericOp::Dec(value)),
+                    "mul" => Some(NumericOp::Mul(value)),
+                    _ => None,
+                }
+            }
+
+            fn to_input_field(&self) -> (&'static str, InputValue<WundergraphScalarValue>) {
+                match *self {
+                    NumericOp::Set(ref v) => ("set", v.to_input_value()),
+                    NumericOp::Inc(ref v) => ("inc", v.to_input_value()),
+                    NumericOp::Dec(ref v) => ("dec", v.to_input_value()),
+                    NumericOp::Mul(ref v) => ("mul", v.to_input_value()),
+                }
+            }
+        }
+    )*};
+}
+
+numeric_operations! {
+    i16 => "SmallIntUpdate",
+    i32 => "IntUpdate",
+    i64 => "BigIntUpdate",
+    f32 => "SmallFloatUpdate",
+    f64 => "FloatUpdate",
+}
+
+#[cfg(feature = "bigdecimal")]
+numeric_operations! {
+    bigdecimal_internal::BigDecimal => "DecimalUpdate",
+}
+
+impl UpdateOperation for TextOp {
+    const NAME: &'static str = "StringUpdate";
+
+    fn register_fields<'r>(
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        vec![
+            registry
+                .arg::<Option<String>>("set", &())
+                .description("Set the column to the given value"),
+            registry
+                .arg::<Option<String>>("append", &())
+                .description("Append the given value to the column"),
+            registry
+                .arg::<Option<String>>("prepend", &())
+                .description("Prepend the given value to the column"),
+        ]
+    }
+
+    fn from_input_field(name: &str, value: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        let value = String::from_input_value(value)?;
+        match name {
+            "set" => Some(TextOp::Set(value)),
+            "append" => Some(TextOp::Append(value)),
+            "prepend" => Some(TextOp::Prepend(value)),
+            _ => None,
+        }
+    }
+
+    fn to_input_field(&self) -> (&'static str, InputValue<WundergraphScalarValue>) {
+        match *self {
+            TextOp::Set(ref v) => ("set", v.to_input_value()),
+            TextOp::Append(ref v) => ("append", v.to_input_value()),
+            TextOp::Prepend(ref v) => ("prepend", v.to_input_value()),
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "postgres"), allow(unused_macros))]
+macro_rules! array_operations {
+    ($($ty:ty => $name:expr,)*) => {$(
+        impl UpdateOperation for ArrayOp<$ty> {
+            const NAME: &'static str = $name;
+
+            fn register_fields<'r>(
+                registry: &mut Registry<'r, WundergraphScalarValue>,
+            ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+                vec![
+                    registry
+                        .arg::<Option<Vec<$ty>>>("set", &())
+                        .description("Set the column to the given elements"),
+                    registry
+                        .arg::<Option<Vec<$ty>>>("append", &())
+                        .description("Append the given elements to the column"),
+                    registry
+                        .arg::<Option<Vec<$ty>>>("remove", &())
+                        .description("Remove all occurrences of the given elements"),
+                ]
+            }
+
+            fn from_input_field(
+                name: &str,
+                value: &InputValue<WundergraphScalarValue>,
+            ) -> Option<Self> {
+                let value = Vec::<$ty>::from_input_value(value)?;
+                match name {
+                    "set" => Some(ArrayOp::Set(value)),
+                    "append" => Some(ArrayOp::Append(value)),
+                    "remove" => Some(ArrayOp::Remove(value)),
+                    _ => None,
+                }
+            }
+
+            fn to_input_field(&self) -> (&'static str, InputValue<WundergraphScalarValue>) {
+                match *self {
+                    ArrayOp::Set(ref v) => ("set", v.to_input_value()),
+                    ArrayOp::Append(ref v) => ("append", v.to_input_value()),
+                    ArrayOp::Remove(ref v) => ("remove", v.to_input_value()),
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "postgres")]
+array_operations! {
+    i16 => "SmallIntArrayUpdate",
+    i32 => "IntArrayUpdate",
+    i64 => "BigIntArrayUpdate",
+    f32 => "SmallFloatArrayUpdate",
+    f64 => "FloatArrayUpdate",
+    bool => "BooleanArrayUpdate",
+    String => "StringArrayUpdate",
+}
+
+#[cfg_attr(not(feature = "chrono"), allow(unused_macros))]
+macro_rules! timestamp_operations {
+    ($($ty:ty => $name:expr,)*) => {$(
+        impl UpdateOperation for TimestampOp<$ty> {
+            const NAME: &'static str = $name;
+
+            fn register_fields<'r>(
+                registry: &mut Registry<'r, WundergraphScalarValue>,
+            ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+                vec![
+                    registry
+                        .arg::<Option<$ty>>("set", &())
+                        .description("Set the column to the given value"),
+                    registry
+                        .arg::<Option<bool>>("now", &())
+                        .description("Set the column to the current timestamp, needs to be `true`"),
+                ]
+            }
+
+            fn from_input_field(
+                name: &str,
+                value: &InputValue<WundergraphScalarValue>,
+            ) -> Option<Self> {
+                match name {
+                    "set" => <$ty>::from_input_value(value).map(TimestampOp::Set),
+                    "now" if bool::from_input_value(value) == Some(true) => {
+                        Some(TimestampOp::Now)
+                    }
+                    _ => None,
+                }
+            }
+
+            fn to_input_field(&self) -> (&'static str, InputValue<WundergraphScalarValue>) {
+                match *self {
+                    TimestampOp::Set(ref v) => ("set", v.to_input_value()),
+                    TimestampOp::Now => ("now", true.to_input_value()),
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "chrono")]
+timestamp_operations! {
+    chrono_internal::NaiveDateTime => "NaiveDateTimeUpdate",
+    chrono_internal::DateTime<chrono_internal::Utc> => "DateTimeUpdate",
+}
+
+impl<C, O> GraphQLType<WundergraphScalarValue> for AtomicUpdate<C, O>
+where
+    C: Default,
+    O: UpdateOperation,
+{
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some(O::NAME)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = O::register_fields(registry);
+        registry
+            .build_input_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<C, O> FromInputValue<WundergraphScalarValue> for AtomicUpdate<C, O>
+where
+    C: Default,
+    O: UpdateOperation,
+{
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        let operators = v.to_object_value()?.into_iter().collect::<Vec<_>>();
+        let op = match *operators.as_slice() {
+            [] => AtomicOperation::Invalid(String::from("expected one operator, got none")),
+            [("set", value)] if value.is_null() => AtomicOperation::SetNull,
+            [(name, value)] if value.is_null() => {
+                AtomicOperation::Invalid(format!("`{}` must not be null", name))
+            }
+            [(name, value)] => match O::from_input_field(name, value) {
+                Some(op) => AtomicOperation::Apply(op),
+                None => AtomicOperation::Invalid(format!("invalid value for `{}`", name)),
+            },
+            _ => {
+                let names = operators
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect::<Vec<_>>();
+                AtomicOperation::Invalid(format!("expected one operator, got {}", names.join(", ")))
+            }
+        };
+        Some(Self::with_operation(op))
+    }
+}
+
+impl<C, O> ToInputValue<WundergraphScalarValue> for AtomicUpdate<C, O>
+where
+    O: UpdateOperation,
+{
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        let mut map = IndexMap::with_capacity(1);
+        match self.op {
+            AtomicOperation::Apply(ref op) => {
+                let (name, value) = op.to_input_field();
+                map.insert(name, value);
+            }
+            AtomicOperation::SetNull => {
+                map.insert("set", InputValue::null());
+            }
+            AtomicOperation::Invalid(_) => {}
+        }
+        InputValue::object(map)
+    }
+}
+
+impl<C, O> Expression for AtomicUpdate<C, O>
+where
+    C: Column,
+{
+    type SqlType = C::SqlType;
+}
+
+impl<C, O> NonAggregate for AtomicUpdate<C, O> {}
+
+impl<C, O, QS> AppearsOnTable<QS> for AtomicUpdate<C, O>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<C, O, QS> SelectableExpression<QS> for AtomicUpdate<C, O>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<C, O> QueryId for AtomicUpdate<C, O> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C, T, DB> QueryFragment<DB> for AtomicUpdate<C, NumericOp<T>>
+where
+    DB: Backend + HasSqlType<C::SqlType>,
+    C: Column + QueryFragment<DB>,
+    C::SqlType: IntoNullable + 'static,
+    <C::SqlType as IntoNullable>::Nullable: 'static,
+    T: ToSql<C::SqlType, DB>,
+{
+    fn walk_ast(&self, pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.walk_operation(pass, |op, mut pass| {
+            let (operator, value) = match *op {
+                NumericOp::Set(ref v) => return pass.push_bind_param::<C::SqlType, _>(v),
+                NumericOp::Inc(ref v) => (" + ", v),
+                NumericOp::Dec(ref v) => (" - ", v),
+                NumericOp::Mul(ref v) => (" * ", v),
+            };
+            self.column.walk_ast(pass.reborrow())?;
+            pass.push_sql(operator);
+            pass.push_bind_param::<C::SqlType, _>(value)
+        })
+    }
+}
+
+impl<C, DB> QueryFragment<DB> for AtomicUpdate<C, TextOp>
+where
+    DB: Backend + HasSqlType<C::SqlType>,
+    C: Column + QueryFragment<DB>,
+    C::SqlType: IntoNullable + 'static,
+    <C::SqlType as IntoNullable>::Nullable: 'static,
+    String: ToSql<C::SqlType, DB>,
+{
+    fn walk_ast(&self, pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.walk_operation(pass, |op, mut pass| match *op {
+            TextOp::Set(ref v) => pass.push_bind_param::<C::SqlType, _>(v),
+            TextOp::Append(ref v) => {
+                self.column.walk_ast(pass.reborrow())?;
+                pass.push_sql(" || ");
+                pass.push_bind_param::<C::SqlType, _>(v)
+            }
+            TextOp::Prepend(ref v) => {
+                pass.push_bind_param::<C::SqlType, _>(v)?;
+                pass.push_sql(" || ");
+                self.column.walk_ast(pass)
+            }
+        })
+    }
+}
+
+/// The sql type of the elements of an array sql type
+#[cfg(feature = "postgres")]
+pub trait ArraySqlType {
+    /// The sql type of a single element
+    type Element;
+}
+
+#[cfg(feature = "postgres")]
+impl<ST> ArraySqlType for diesel::sql_types::Array<ST> {
+    type Element = ST;
+}
+
+#[cfg(feature = "postgres")]
+impl<ST> ArraySqlType for diesel::sql_types::Nullable<diesel::sql_types::Array<ST>> {
+    type Element = ST;
+}
+
+#[cfg(feature = "postgres")]
+impl<C, T> QueryFragment<diesel::pg::Pg> for AtomicUpdate<C, ArrayOp<T>>
+where
+    C: Column + QueryFragment<diesel::pg::Pg>,
+    C::SqlType: ArraySqlType + IntoNullable + 'static,
+    <C::SqlType as IntoNullable>::Nullable: 'static,
+    diesel::pg::Pg: HasSqlType<C::SqlType> + HasSqlType<<C::SqlType as ArraySqlType>::Element>,
+    Vec<T>: ToSql<C::SqlType, diesel::pg::Pg>,
+    T: ToSql<<C::SqlType as ArraySqlType>::Element, diesel::pg::Pg>,
+{
+    fn walk_ast(&self, pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        self.walk_operation(pass, |op, mut pass| match *op {
+            ArrayOp::Set(ref v) => pass.push_bind_param::<C::SqlType, _>(v),
+            ArrayOp::Append(ref v) => {
+                self.column.walk_ast(pass.reborrow())?;
+                pass.push_sql(" || ");
+                pass.push_bind_param::<C::SqlType, _>(v)
+            }
+            ArrayOp::Remove(ref v) => {
+                // array_remove only removes a single element, so nest one
+                // call per given element
+                for _ in v {
+                    pass.push_sql("array_remove(");
+                }
+                self.column.walk_ast(pass.reborrow())?;
+                for e in v {
+                    pass.push_sql(", ");
+                    pass.push_bind_param::<<C::SqlType as ArraySqlType>::Element, _>(e)?;
+                    pass.push_sql(")");
+                }
+                Ok(())
+            }
+        })
+    }
+}
+
+impl<C, T, DB> QueryFragment<DB> for AtomicUpdate<C, TimestampOp<T>>
+where
+    DB: Backend + HasSqlType<C::SqlType>,
+    C: Column,
+    C::SqlType: IntoNullable + 'static,
+    <C::SqlType as IntoNullable>::Nullable: 'static,
+    T: ToSql<C::SqlType, DB>,
+    diesel::dsl::now: QueryFragment<DB>,
+{
+    fn walk_ast(&self, pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.walk_operation(pass, |op, mut pass| match *op {
+            TimestampOp::Set(ref v) => pass.push_bind_param::<C::SqlType, _>(v),
+            TimestampOp::Now => diesel::dsl::now.walk_ast(pass),
+        })
+    }
+}
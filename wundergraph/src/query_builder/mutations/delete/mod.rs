@@ -0,0 +1,477 @@
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::diesel_ext::BoxableFilter;
+use crate::helper::primary_keys::UnRefClone;
+use crate::instrumentation::{instrument, Explain};
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::offset::ApplyOffset;
+use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::query_modifier::QueryModifier;
+use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::version::{
+    check_conflict, expected_version, with_version_check,
+};
+use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::deserialize::FromSql;
+use diesel::dsl::{sql, Filter, SqlTypeOf};
+use diesel::expression::NonAggregate;
+use diesel::query_builder::{BoxedSelectStatement, IntoUpdateTarget, QueryFragment, QueryId};
+use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, LimitDsl, SelectDsl};
+use diesel::sql_types::{Bool, HasSqlType, Integer};
+use diesel::Identifiable;
+use diesel::{AppearsOnTable, Connection, EqAll, Expression, QuerySource, RunQueryDsl, Table};
+use juniper::{
+    Arguments, ExecutionResult, Executor, FieldError, FromInputValue, GraphQLObject,
+    LookAheadValue, Selection, Value,
+};
+
+#[cfg(feature = "postgres")]
+mod pg;
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+#[derive(Debug, GraphQLObject, Clone, Copy)]
+#[graphql(scalar = WundergraphScalarValue)]
+pub struct DeletedCount {
+    pub count: i64,
+}
+
+pub fn handle_delete<DB, D, R, Ctx, H>(
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    arguments: &Arguments<'_, WundergraphScalarValue>,
+    field_name: &'static str,
+    hooks: SelectedHooks<H, DB, Ctx, ()>,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    R: LoadingHandler<DB, Ctx>,
+    R::Table: HandleDelete<R, D, DB, Ctx> + 'static,
+    DB: Backend + ApplyOffset + 'static,
+    DB::QueryBuilder: Default,
+    R::Columns: BuildOrder<R::Table, DB>
+        + BuildSelect<
+            R::Table,
+            DB,
+            SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
+        >,
+    <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    D: FromInputValue<WundergraphScalarValue>,
+    H: EntityHooks<DB, Ctx>,
+{
+    if let Some(n) = arguments.get::<D>(field_name) {
+        let look_ahead = executor.look_ahead();
+        let version = expected_version::<R, DB, Ctx>(&look_ahead, field_name)?;
+        <R::Table as HandleDelete<_, _, _, _>>::handle_delete(executor, &n, version, hooks)
+    } else {
+        let msg = format!("Missing argument {:?}", field_name);
+        Err(FieldError::new(&msg, Value::Null))
+    }
+}
+
+pub fn handle_delete_returning<DB, D, R, Ctx, H>(
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    arguments: &Arguments<'_, WundergraphScalarValue>,
+    field_name: &'static str,
+    hooks: SelectedHooks<H, DB, Ctx, ()>,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    R: LoadingHandler<DB, Ctx>,
+    R::Table: HandleDeleteReturning<R, D, DB, Ctx> + 'static,
+    DB: Backend + ApplyOffset + 'static,
+    DB::QueryBuilder: Default,
+    R::Columns: BuildOrder<R::Table, DB>
+        + BuildSelect<
+            R::Table,
+            DB,
+            SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
+        >,
+    <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    D: FromInputValue<WundergraphScalarValue>,
+    H: EntityHooks<DB, Ctx>,
+{
+    if let Some(n) = arguments.get::<D>(field_name) {
+        let look_ahead = executor.look_ahead();
+        let version = expected_version::<R, DB, Ctx>(&look_ahead, field_name)?;
+        <R::Table as HandleDeleteReturning<_, _, _, _>>::handle_delete_returning(
+            selection, executor, &n, version, hooks,
+        )
+    } else {
+        let msg = format!("Missing argument {:?}", field_name);
+        Err(FieldError::new(&msg, Value::Null))
+    }
+}
+
+pub trait HandleDelete<L, K, DB, Ctx> {
+    fn handle_delete<H: EntityHooks<DB, Ctx>>(
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue>;
+}
+
+/// Delete an entity and return it
+///
+/// Rows not returned by the [`QueryModifier`](../selection/query_modifier/trait.QueryModifier.html)
+/// of the context are not deleted, in this case `null` is returned.
+/// Postgres uses `DELETE ... RETURNING`, other backends load the entity
+/// before deleting it in the same transaction.
+pub trait HandleDeleteReturning<L, K, DB, Ctx> {
+    fn handle_delete_returning<H: EntityHooks<DB, Ctx>>(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue>;
+}
+
+#[doc(hidden)]
+/// Delete (or soft delete) a single entity including the mutation
+/// hooks and the version check, returning the number of deleted rows
+///
+/// Must be called inside of a transaction
+pub trait DeleteByKey<L, K, DB, Ctx>: Sized {
+    fn delete_by_key<H: EntityHooks<DB, Ctx>>(
+        ctx: &Ctx,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> Result<usize, FieldError<WundergraphScalarValue>>;
+
+    /// Like `delete_by_key`, but the row is deleted by `delete`
+    ///
+    /// `delete` gets the filter selecting the row, `count` returns the
+    /// number of rows deleted by it. Used to return the deleted row from
+    /// the delete statement itself.
+    fn delete_by_key_with<R, H: EntityHooks<DB, Ctx>>(
+        ctx: &Ctx,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+        delete: impl FnOnce(
+            Box<dyn BoxableFilter<Self, DB, SqlType = Bool>>,
+        ) -> Result<R, FieldError<WundergraphScalarValue>>,
+        count: impl Fn(&R) -> usize,
+    ) -> Result<R, FieldError<WundergraphScalarValue>>
+    where
+        DB: Backend;
+}
+
+impl<L, K, DB, Ctx, T> HandleDelete<L, K, DB, Ctx> for T
+where
+    T: DeleteByKey<L, K, DB, Ctx>,
+    DB: Backend,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+{
+    fn handle_delete<H: EntityHooks<DB, Ctx>>(
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        let conn = ctx.get_connection();
+        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            let count = T::delete_by_key(ctx, to_delete, expected_version, hooks)?;
+            executor.resolve_with_ctx(&(), &DeletedCount { count: count as _ })
+        })
+    }
+}
+
+// We use the 'static static lifetime here because otherwise rustc will
+// tell us that it could not find a applying lifetime (caused by broken projection
+// on higher ranked lifetime bounds)
+impl<L, K, DB, Ctx, T> DeleteByKey<L, K, DB, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + QueryId + 'static,
+    DB: Backend + ApplyOffset + Explain + 'static,
+    DB::QueryBuilder: Default,
+    T::FromClause: QueryFragment<DB>,
+    L: LoadingHandler<DB, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, DB>
+        + BuildSelect<T, DB, SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+    L::FieldList: WundergraphFieldList<DB, L::PrimaryKeyIndex, T, Ctx>,
+    K: 'static,
+    &'static K: Identifiable<Table = T>,
+    <&'static K as Identifiable>::Id: UnRefClone,
+    <<&'static K as Identifiable>::Id as UnRefClone>::UnRefed: 'static,
+    T::PrimaryKey: EqAll<<&'static K as Identifiable>::Id>,
+    T::Query: FilterDsl<Box<dyn BoxableFilter<T, DB, SqlType = Bool>>>,
+    Filter<T::Query, Box<dyn BoxableFilter<T, DB, SqlType = Bool>>>: IntoUpdateTarget<Table = T>,
+    <Filter<T::Query, Box<dyn BoxableFilter<T, DB, SqlType = Bool>>> as IntoUpdateTarget>::WhereClause:
+        QueryFragment<DB> + QueryId,
+    T: BoxedDsl<
+        'static,
+        DB,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, DB>,
+    >,
+    DB: HasSqlType<Integer>,
+    i32: FromSql<Integer, DB>,
+    <T::PrimaryKey as EqAll<<&'static K as Identifiable>::Id>>::Output: Expression<SqlType = Bool>
+        + AppearsOnTable<T>
+        + NonAggregate
+        + QueryFragment<DB>
+        + 'static,
+{
+    fn delete_by_key<H: EntityHooks<DB, Ctx>>(
+        ctx: &Ctx,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> Result<usize, FieldError<WundergraphScalarValue>> {
+        <Self as DeleteByKey<L, K, DB, Ctx>>::delete_by_key_with(
+            ctx,
+            to_delete,
+            expected_version,
+            hooks,
+            |filter| {
+                if L::SOFT_DELETE {
+                    Ok(L::set_soft_deleted(ctx, filter, true)?.unwrap_or(0))
+                } else {
+                    let d = ::diesel::delete(FilterDsl::filter(Self::table(), filter));
+                    let conn = ctx.get_connection();
+                    Ok(instrument(ctx, conn, L::TYPE_NAME, d, |d, conn| d.execute(conn), |n| *n)?)
+                }
+            },
+            |n| *n,
+        )
+    }
+
+    fn delete_by_key_with<R, H: EntityHooks<DB, Ctx>>(
+        ctx: &Ctx,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+        delete: impl FnOnce(
+            Box<dyn BoxableFilter<Self, DB, SqlType = Bool>>,
+        ) -> Result<R, FieldError<WundergraphScalarValue>>,
+        count: impl Fn(&R) -> usize,
+    ) -> Result<R, FieldError<WundergraphScalarValue>> {
+        // this is safe becuse we do not leak to_delete out of this function
+        let static_to_delete: &'static K = unsafe { &*(to_delete as *const K) };
+        let keys = [static_to_delete.id().make_owned()];
+        hooks.before_delete(ctx, L::TYPE_NAME, &keys)?;
+        let key_filter = || -> Box<dyn BoxableFilter<T, DB, SqlType = Bool>> {
+            Box::new(Self::table().primary_key().eq_all(static_to_delete.id()))
+        };
+        let filter = with_version_check::<L, DB, Ctx>(key_filter(), expected_version)?;
+        let deleted = delete(filter)?;
+        let n = count(&deleted);
+        if let (0, Some(expected)) = (n, expected_version) {
+            check_conflict::<L, DB, Ctx>(ctx, key_filter(), expected)?;
+        }
+        if n > 0 {
+            hooks.after_delete(ctx, L::TYPE_NAME, &keys)?;
+        }
+        Ok(deleted)
+    }
+}
+
+/// Load the entity to delete and delete it afterwards, inside of a
+/// single transaction
+fn select_then_delete<L, K, DB, Ctx, T, H>(
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    to_delete: &K,
+    expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+    hooks: SelectedHooks<H, DB, Ctx, ()>,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    T: Table + HasTable<Table = T> + DeleteByKey<L, K, DB, Ctx> + 'static,
+    DB: Backend + ApplyOffset + Explain + 'static,
+    DB::QueryBuilder: Default,
+    T::FromClause: QueryFragment<DB>,
+    L: LoadingHandler<DB, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, DB>
+        + BuildSelect<T, DB, SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, DB>,
+    Ctx::Connection: Connection<Backend = DB>,
+    L::FieldList: WundergraphFieldList<DB, L::PrimaryKeyIndex, T, Ctx>,
+    T: BoxedDsl<
+        'static,
+        DB,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, DB>,
+    >,
+    <L::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<T>,
+    DB: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    K: 'static,
+    &'static K: Identifiable<Table = T>,
+    T::PrimaryKey: EqAll<<&'static K as Identifiable>::Id>,
+    <T::PrimaryKey as EqAll<<&'static K as Identifiable>::Id>>::Output:
+        Expression<SqlType = Bool> + AppearsOnTable<T> + NonAggregate + QueryFragment<DB> + 'static,
+    H: EntityHooks<DB, Ctx>,
+{
+    let ctx = executor.context();
+    let _mutation = MutationGuard::new(ctx);
+    let conn = ctx.get_connection();
+    conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+        // this is safe becuse we do not leak to_delete out of this function
+        let static_to_delete: &'static K = unsafe { &*(to_delete as *const K) };
+        let look_ahead = executor.look_ahead();
+        let f = FilterDsl::filter(
+            L::build_query(&look_ahead)?,
+            T::table().primary_key().eq_all(static_to_delete.id()),
+        );
+        // We filter by primary key so there should only be one element
+        let q = LimitDsl::limit(f, 1);
+        // `load` applies the query modifier, so rows hidden by it are
+        // neither returned nor deleted
+        let items = L::load(&look_ahead, selection, executor, q)?;
+        let item = match items.into_iter().next() {
+            Some(item) => item,
+            None => return Ok(Value::Null),
+        };
+        if T::delete_by_key(ctx, to_delete, expected_version, hooks)? > 0 {
+            Ok(item)
+        } else {
+            Ok(Value::Null)
+        }
+    })
+}
+
+pub fn handle_restore<DB, K, R, Ctx, H>(
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    arguments: &Arguments<'_, WundergraphScalarValue>,
+    field_name: &'static str,
+    hooks: SelectedHooks<H, DB, Ctx, ()>,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    R: LoadingHandler<DB, Ctx>,
+    R::Table: HandleRestore<R, K, DB, Ctx> + 'static,
+    DB: Backend + ApplyOffset + 'static,
+    DB::QueryBuilder: Default,
+    R::Columns: BuildOrder<R::Table, DB>
+        + BuildSelect<
+            R::Table,
+            DB,
+            SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
+        >,
+    <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    K: FromInputValue<WundergraphScalarValue>,
+    H: EntityHooks<DB, Ctx>,
+{
+    if let Some(n) = arguments.get::<K>(field_name) {
+        <R::Table as HandleRestore<_, _, _, _>>::handle_restore(selection, executor, &n, hooks)
+    } else {
+        let msg = format!("Missing argument {:?}", field_name);
+        Err(FieldError::new(&msg, Value::Null))
+    }
+}
+
+/// Restore a soft deleted entity
+///
+/// See the [`soft_delete`](../selection/soft_delete/index.html) module
+/// for details
+pub trait HandleRestore<L, K, DB, Ctx> {
+    fn handle_restore<H: EntityHooks<DB, Ctx>>(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_restore: &K,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue>;
+}
+
+// We use the 'static static lifetime here because otherwise rustc will
+// tell us that it could not find a applying lifetime (caused by broken projection
+// on higher ranked lifetime bounds)
+impl<L, K, DB, Ctx, T> HandleRestore<L, K, DB, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + 'static,
+    DB: Backend + ApplyOffset + Explain + 'static,
+    DB::QueryBuilder: Default,
+    T::FromClause: QueryFragment<DB>,
+    L: LoadingHandler<DB, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, DB>
+        + BuildSelect<T, DB, SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, DB>,
+    Ctx::Connection: Connection<Backend = DB>,
+    L::FieldList: WundergraphFieldList<DB, L::PrimaryKeyIndex, T, Ctx>,
+    T: BoxedDsl<
+        'static,
+        DB,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, DB>,
+    >,
+    <L::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<T>,
+    DB: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    DB: HasSqlType<Integer>,
+    i32: FromSql<Integer, DB>,
+    K: 'static,
+    &'static K: Identifiable<Table = T>,
+    <&'static K as Identifiable>::Id: UnRefClone,
+    <<&'static K as Identifiable>::Id as UnRefClone>::UnRefed: 'static,
+    T::PrimaryKey: EqAll<<&'static K as Identifiable>::Id>,
+    <T::PrimaryKey as EqAll<<&'static K as Identifiable>::Id>>::Output:
+        Expression<SqlType = Bool> + AppearsOnTable<T> + NonAggregate + QueryFragment<DB> + 'static,
+{
+    fn handle_restore<H: EntityHooks<DB, Ctx>>(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_restore: &K,
+        hooks: SelectedHooks<H, DB, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        let conn = ctx.get_connection();
+        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            // this is safe becuse we do not leak to_restore out of this function
+            let static_to_restore: &'static K = unsafe { &*(to_restore as *const K) };
+            let look_ahead = executor.look_ahead();
+            // Rows hidden by the query modifier are not restored. The row
+            // is soft deleted, so `build_query` would hide it as well.
+            let visible = FilterDsl::filter(
+                <_ as SelectDsl<_>>::select(
+                    BoxedDsl::internal_into_boxed(Self::table()),
+                    L::get_select(&look_ahead)?,
+                ),
+                Self::table().primary_key().eq_all(static_to_restore.id()),
+            );
+            let visible = ctx.modify_query(&look_ahead, visible)?;
+            let visible = SelectDsl::select(visible, sql::<Integer>("1"));
+            let visible: Vec<i32> = instrument(
+                ctx,
+                conn,
+                L::TYPE_NAME,
+                visible,
+                |visible, conn| visible.load(conn),
+                Vec::len,
+            )?;
+            if visible.is_empty() {
+                return Ok(Value::Null);
+            }
+
+            let keys = [static_to_restore.id().make_owned()];
+            hooks.before_restore(ctx, L::TYPE_NAME, &keys)?;
+            let filter: Box<dyn BoxableFilter<T, DB, SqlType = Bool>> =
+                Box::new(Self::table().primary_key().eq_all(static_to_restore.id()));
+            match L::set_soft_deleted(ctx, filter, false)? {
+                Some(0) => {}
+                Some(_) => hooks.after_restore(ctx, L::TYPE_NAME, &keys)?,
+                None => {
+                    return Err(FieldError::new(
+                        format!("{} does not support soft deletes", L::TYPE_NAME),
+                        Value::Null,
+                    ));
+                }
+            }
+            let f = FilterDsl::filter(
+                L::build_query(&look_ahead)?,
+                Self::table().primary_key().eq_all(static_to_restore.id()),
+            );
+            // We filter by primary key so there should only be one element
+            let q = LimitDsl::limit(f, 1);
+            let items = L::load(&look_ahead, selection, executor, q)?;
+            Ok(items.into_iter().next().unwrap_or(Value::Null))
+        })
+    }
+}
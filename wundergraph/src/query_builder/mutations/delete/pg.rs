@@ -0,0 +1,127 @@
+use super::{select_then_delete, DeleteByKey, HandleDeleteReturning};
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::diesel_ext::BoxableFilter;
+use crate::instrumentation::instrument;
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::query_modifier::QueryModifier;
+use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::dsl::{sql, Filter, SqlTypeOf};
+use diesel::expression::NonAggregate;
+use diesel::pg::Pg;
+use diesel::query_builder::{BoxedSelectStatement, IntoUpdateTarget, QueryFragment, QueryId};
+use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, SelectDsl};
+use diesel::sql_types::{Bool, HasSqlType, Integer};
+use diesel::{AppearsOnTable, Connection, EqAll, Expression, Identifiable, RunQueryDsl, Table};
+use juniper::{ExecutionResult, Executor, LookAheadValue, Selection, Value};
+
+type PlaceHolderOf<L, Ctx> = <<L as LoadingHandler<Pg, Ctx>>::FieldList as WundergraphFieldList<
+    Pg,
+    <L as LoadingHandler<Pg, Ctx>>::PrimaryKeyIndex,
+    <L as HasTable>::Table,
+    Ctx,
+>>::PlaceHolder;
+
+impl<L, K, Ctx, T> HandleDeleteReturning<L, K, Pg, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + DeleteByKey<L, K, Pg, Ctx> + QueryId + 'static,
+    T::FromClause: QueryFragment<Pg>,
+    L: LoadingHandler<Pg, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, Pg>
+        + BuildSelect<T, Pg, SqlTypeOfPlaceholder<L::FieldList, Pg, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, Pg>,
+    Ctx::Connection: Connection<Backend = Pg>,
+    L::FieldList: WundergraphFieldList<Pg, L::PrimaryKeyIndex, T, Ctx>,
+    T: BoxedDsl<
+        'static,
+        Pg,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Pg>,
+    >,
+    <L::Filter as BuildFilter<Pg>>::Ret: AppearsOnTable<T>,
+    Pg: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Pg, L::PrimaryKeyIndex, T, Ctx>>,
+    K: 'static,
+    &'static K: Identifiable<Table = T>,
+    T::PrimaryKey: EqAll<<&'static K as Identifiable>::Id>,
+    T::Query: FilterDsl<Box<dyn BoxableFilter<T, Pg, SqlType = Bool>>>,
+    Filter<T::Query, Box<dyn BoxableFilter<T, Pg, SqlType = Bool>>>: IntoUpdateTarget<Table = T>,
+    <Filter<T::Query, Box<dyn BoxableFilter<T, Pg, SqlType = Bool>>> as IntoUpdateTarget>::WhereClause:
+        QueryFragment<Pg> + QueryId,
+    <T::PrimaryKey as EqAll<<&'static K as Identifiable>::Id>>::Output: Expression<SqlType = Bool>
+        + AppearsOnTable<T>
+        + NonAggregate
+        + QueryFragment<Pg>
+        + 'static,
+{
+    fn handle_delete_returning<H: EntityHooks<Pg, Ctx>>(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, Pg, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        if L::SOFT_DELETE {
+            // A soft delete is an update, so there is nothing to return
+            // from a `DELETE` statement
+            return select_then_delete::<L, K, Pg, Ctx, T, H>(
+                selection,
+                executor,
+                to_delete,
+                expected_version,
+                hooks,
+            );
+        }
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        let conn = ctx.get_connection();
+        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            // this is safe becuse we do not leak to_delete out of this function
+            let static_to_delete: &'static K = unsafe { &*(to_delete as *const K) };
+            let look_ahead = executor.look_ahead();
+            // Rows hidden by the query modifier are not deleted
+            let visible = FilterDsl::filter(
+                L::build_query(&look_ahead)?,
+                Self::table().primary_key().eq_all(static_to_delete.id()),
+            );
+            let visible = ctx.modify_query(&look_ahead, visible)?;
+            let visible = SelectDsl::select(visible, sql::<Integer>("1"));
+            let visible: Vec<i32> = instrument(
+                ctx,
+                conn,
+                L::TYPE_NAME,
+                visible,
+                |visible, conn| visible.load(conn),
+                Vec::len,
+            )?;
+            if visible.is_empty() {
+                return Ok(Value::Null);
+            }
+
+            let deleted: Vec<PlaceHolderOf<L, Ctx>> = T::delete_by_key_with(
+                ctx,
+                to_delete,
+                expected_version,
+                hooks,
+                |filter| {
+                    let d = ::diesel::delete(FilterDsl::filter(Self::table(), filter))
+                        .returning(L::get_select(&look_ahead)?);
+                    Ok(instrument(
+                        ctx,
+                        conn,
+                        L::TYPE_NAME,
+                        d,
+                        |d, conn| d.load(conn),
+                        Vec::len,
+                    )?)
+                },
+                Vec::len,
+            )?;
+            let (_, items) = L::resolve_loaded(deleted, &look_ahead, selection, executor)?;
+            Ok(items.into_iter().next().unwrap_or(Value::Null))
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use super::{select_then_delete, DeleteByKey, HandleDeleteReturning};
+use crate::context::WundergraphContext;
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::query_modifier::QueryModifier;
+use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::dsl::SqlTypeOf;
+use diesel::expression::NonAggregate;
+use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
+use diesel::query_dsl::methods::BoxedDsl;
+use diesel::sql_types::{Bool, HasSqlType};
+use diesel::sqlite::Sqlite;
+use diesel::{AppearsOnTable, Connection, EqAll, Expression, Identifiable, Table};
+use juniper::{ExecutionResult, Executor, LookAheadValue, Selection};
+
+// Sqlite does not support `DELETE ... RETURNING`, so we load the entity
+// before deleting it
+impl<L, K, Ctx, T> HandleDeleteReturning<L, K, Sqlite, Ctx> for T
+where
+    T: Table + HasTable<Table = T> + DeleteByKey<L, K, Sqlite, Ctx> + 'static,
+    T::FromClause: QueryFragment<Sqlite>,
+    L: LoadingHandler<Sqlite, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, Sqlite>
+        + BuildSelect<
+            T,
+            Sqlite,
+            SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>,
+        >,
+    Ctx: WundergraphContext + QueryModifier<L, Sqlite>,
+    Ctx::Connection: Connection<Backend = Sqlite>,
+    L::FieldList: WundergraphFieldList<Sqlite, L::PrimaryKeyIndex, T, Ctx>,
+    T: BoxedDsl<
+        'static,
+        Sqlite,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Sqlite>,
+    >,
+    <L::Filter as BuildFilter<Sqlite>>::Ret: AppearsOnTable<T>,
+    Sqlite: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>>,
+    K: 'static,
+    &'static K: Identifiable<Table = T>,
+    T::PrimaryKey: EqAll<<&'static K as Identifiable>::Id>,
+    <T::PrimaryKey as EqAll<<&'static K as Identifiable>::Id>>::Output: Expression<SqlType = Bool>
+        + AppearsOnTable<T>
+        + NonAggregate
+        + QueryFragment<Sqlite>
+        + 'static,
+{
+    fn handle_delete_returning<H: EntityHooks<Sqlite, Ctx>>(
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        to_delete: &K,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, Sqlite, Ctx, ()>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        select_then_delete::<L, K, Sqlite, Ctx, T, H>(
+            selection,
+            executor,
+            to_delete,
+            expected_version,
+            hooks,
+        )
+    }
+}
@@ -0,0 +1,389 @@
+use crate::context::WundergraphContext;
+use failure::Error;
+use std::any::Any;
+use std::marker::PhantomData;
+
+/// A trait to hook into the mutations of all entities of a context
+///
+/// Returned by
+/// [`WundergraphContext::mutation_hooks`](../../context/trait.WundergraphContext.html#method.mutation_hooks),
+/// which returns no hooks by default. Use this for behaviour shared by all
+/// entities (for example writing audit rows or stamping data of the current
+/// user), and [`EntityHooks`](trait.EntityHooks.html) for behaviour of a
+/// single entity.
+///
+/// All methods are called inside of the transaction used by the mutation,
+/// so queries executed on the connection returned by
+/// [`get_connection`](../../context/trait.WundergraphContext.html#tymethod.get_connection)
+/// are part of that transaction. Returning an error from any method aborts
+/// the mutation and rolls back the transaction. Context hooks are called
+/// before the hooks of the entity.
+///
+/// `entity` is the graphql name of the changed entity. The input is the
+/// insertable type for inserts, the changeset type for updates and a
+/// [`RelationChange`](relation/struct.RelationChange.html) for relation
+/// mutations. Inputs and primary keys are passed as
+/// [`Any`](https://doc.rust-lang.org/std/any/trait.Any.html), so they
+/// could be accessed by downcasting them to the concrete type. Primary
+/// keys are owned values, so `i32` for a single integer key or a tuple for
+/// composite keys.
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate diesel;
+/// # use diesel::Connection;
+/// # use std::any::Any;
+/// # use wundergraph::context::WundergraphContext;
+/// # use wundergraph::query_builder::mutations::MutationHooks;
+/// # table! {
+/// #    heros {
+/// #        id -> Integer,
+/// #        name -> Text,
+/// #        created_by -> Text,
+/// #    }
+/// # }
+/// #[derive(Insertable)]
+/// #[table_name = "heros"]
+/// pub struct NewHero {
+///     name: String,
+///     created_by: String,
+/// }
+///
+/// pub struct MyContext<Conn: Connection + 'static> {
+///     conn: Conn,
+///     user: String,
+/// }
+///
+/// impl<Conn: Connection + 'static> WundergraphContext for MyContext<Conn> {
+///     type Connection = Conn;
+///
+///     fn get_connection(&self) -> &Conn {
+///         &self.conn
+///     }
+///
+///     fn mutation_hooks(&self) -> Option<&dyn MutationHooks> {
+///         Some(self)
+///     }
+/// }
+///
+/// impl<Conn: Connection + 'static> MutationHooks for MyContext<Conn> {
+///     fn before_insert(
+///         &self,
+///         _entity: &'static str,
+///         insertable: &mut dyn Any,
+///     ) -> Result<(), failure::Error> {
+///         if let Some(hero) = insertable.downcast_mut::<NewHero>() {
+///             hero.created_by = self.user.clone();
+///         }
+///         Ok(())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub trait MutationHooks {
+    /// Called for each insertable before it is validated and inserted
+    fn before_insert(&self, _entity: &'static str, _insertable: &mut dyn Any) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all inserted rows
+    fn after_insert(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the changeset before it is validated and the update
+    /// is executed
+    fn before_update(&self, _entity: &'static str, _change_set: &mut dyn Any) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all updated rows
+    fn after_update(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all rows that should be deleted
+    fn before_delete(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all rows that should have been deleted
+    ///
+    /// For soft deleted entities this is called after the rows are marked as
+    /// deleted
+    fn after_delete(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all soft deleted rows that should be
+    /// restored
+    fn before_restore(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all restored rows
+    fn after_restore(&self, _entity: &'static str, _keys: &[&dyn Any]) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A trait to hook into the mutations of a single entity
+///
+/// Implemented by the entity type, `I` is the input type of the mutation,
+/// so the insertable type for inserts and the changeset type for updates.
+/// Delete and restore mutations use `()`, relation mutations a
+/// [`RelationChange`](relation/struct.RelationChange.html). Entities
+/// without an implementation for an input type have no hooks for the
+/// corresponding mutations.
+///
+/// The hooks are called after the ones returned by
+/// [`WundergraphContext::mutation_hooks`](../../context/trait.WundergraphContext.html#method.mutation_hooks)
+/// inside of the transaction used by the mutation, see
+/// [`MutationHooks`](trait.MutationHooks.html) for details. As
+/// `mutation_object!` is generic over the backend and the context, an
+/// implementation needs to be generic over both of them as well.
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate diesel;
+/// # use wundergraph::query_builder::mutations::EntityHooks;
+/// # table! {
+/// #    heros {
+/// #        id -> Integer,
+/// #        name -> Text,
+/// #    }
+/// # }
+/// # #[derive(Identifiable)]
+/// # #[table_name = "heros"]
+/// pub struct Hero {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Insertable)]
+/// #[table_name = "heros"]
+/// pub struct NewHero {
+///     name: String,
+/// }
+///
+/// impl<DB, Ctx> EntityHooks<DB, Ctx, NewHero> for Hero {
+///     fn before_insert(_ctx: &Ctx, insertable: &mut NewHero) -> Result<(), failure::Error> {
+///         insertable.name = insertable.name.trim().to_owned();
+///         Ok(())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub trait EntityHooks<DB, Ctx, I = ()> {
+    /// Called for each insertable before it is validated and inserted
+    fn before_insert(_ctx: &Ctx, _insertable: &mut I) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all inserted rows
+    fn after_insert<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the changeset before it is validated and the update
+    /// is executed
+    fn before_update(_ctx: &Ctx, _change_set: &mut I) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all updated rows
+    fn after_update<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all rows that should be deleted
+    fn before_delete<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all rows that should have been deleted
+    ///
+    /// For soft deleted entities this is called after the rows are marked as
+    /// deleted
+    fn after_delete<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all soft deleted rows that should be
+    /// restored
+    fn before_restore<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with the primary keys of all restored rows
+    fn after_restore<K: Any>(_ctx: &Ctx, _keys: &[K]) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Used for entities without hooks
+impl<DB, Ctx, I> EntityHooks<DB, Ctx, I> for () {}
+
+/// The entity hooks selected by `mutation_object!`
+///
+/// `H` is the entity if it implements
+/// [`EntityHooks`](trait.EntityHooks.html) for the input type `I`,
+/// otherwise `()`. Calls the hooks of the context before the ones of `H`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SelectedHooks<H, DB, Ctx, I>(PhantomData<(H, DB, Ctx, I)>);
+
+impl<H, DB, Ctx, I> Clone for SelectedHooks<H, DB, Ctx, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H, DB, Ctx, I> Copy for SelectedHooks<H, DB, Ctx, I> {}
+
+fn any_keys<K: Any>(keys: &[K]) -> Vec<&dyn Any> {
+    keys.iter().map(|k| k as &dyn Any).collect()
+}
+
+impl<H, DB, Ctx, I> SelectedHooks<H, DB, Ctx, I>
+where
+    H: EntityHooks<DB, Ctx, I>,
+    Ctx: WundergraphContext,
+    I: 'static,
+{
+    pub(crate) fn before_insert(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        insertable: &mut I,
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.before_insert(entity, insertable)?;
+        }
+        H::before_insert(ctx, insertable)
+    }
+
+    pub(crate) fn after_insert<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.after_insert(entity, &any_keys(keys))?;
+        }
+        H::after_insert(ctx, keys)
+    }
+
+    pub(crate) fn before_update(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        change_set: &mut I,
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.before_update(entity, change_set)?;
+        }
+        H::before_update(ctx, change_set)
+    }
+
+    pub(crate) fn after_update<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.after_update(entity, &any_keys(keys))?;
+        }
+        H::after_update(ctx, keys)
+    }
+
+    pub(crate) fn before_delete<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.before_delete(entity, &any_keys(keys))?;
+        }
+        H::before_delete(ctx, keys)
+    }
+
+    pub(crate) fn after_delete<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.after_delete(entity, &any_keys(keys))?;
+        }
+        H::after_delete(ctx, keys)
+    }
+
+    pub(crate) fn before_restore<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.before_restore(entity, &any_keys(keys))?;
+        }
+        H::before_restore(ctx, keys)
+    }
+
+    pub(crate) fn after_restore<K: Any>(
+        self,
+        ctx: &Ctx,
+        entity: &'static str,
+        keys: &[K],
+    ) -> Result<(), Error> {
+        if let Some(hooks) = ctx.mutation_hooks() {
+            hooks.after_restore(entity, &any_keys(keys))?;
+        }
+        H::after_restore(ctx, keys)
+    }
+}
+
+/// Helper used by `mutation_object!` to select the entity hooks of an
+/// entity and input type
+///
+/// See the `__entity_hooks!` macro for the actual selection.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct EntityHooksFor<L, I>(PhantomData<(L, I)>);
+
+impl<L, I> EntityHooksFor<L, I> {
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        EntityHooksFor(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithEntityHooks<L, DB, Ctx, I> {
+    fn entity_hooks(&self) -> SelectedHooks<L, DB, Ctx, I>;
+}
+
+impl<L, DB, Ctx, I> WithEntityHooks<L, DB, Ctx, I> for &EntityHooksFor<L, I>
+where
+    L: EntityHooks<DB, Ctx, I>,
+{
+    fn entity_hooks(&self) -> SelectedHooks<L, DB, Ctx, I> {
+        SelectedHooks(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithoutEntityHooks<DB, Ctx, I> {
+    fn entity_hooks(&self) -> SelectedHooks<(), DB, Ctx, I>;
+}
+
+impl<L, DB, Ctx, I> WithoutEntityHooks<DB, Ctx, I> for EntityHooksFor<L, I> {
+    fn entity_hooks(&self) -> SelectedHooks<(), DB, Ctx, I> {
+        SelectedHooks(PhantomData)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::query_builder::mutations::validate::{ValidationErrors, Validator};
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::LoadingHandler;
 use crate::query_builder::selection::order::BuildOrder;
@@ -6,7 +9,7 @@ use crate::query_builder::selection::select::BuildSelect;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::query_builder::QueryFragment;
-use diesel::QuerySource;
+use diesel::{Connection, QuerySource};
 use juniper::{Arguments, ExecutionResult, Executor, FieldError, FromInputValue, Selection, Value};
 
 #[cfg(feature = "postgres")]
@@ -15,11 +18,13 @@ mod pg;
 #[cfg(feature = "sqlite")]
 mod sqlite;
 
-pub fn handle_insert<DB, I, R, Ctx>(
+pub fn handle_insert<DB, I, R, Ctx, H>(
     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
     executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     arguments: &Arguments<'_, WundergraphScalarValue>,
     field_name: &'static str,
+    validator: Validator<I>,
+    hooks: SelectedHooks<H, DB, Ctx, I>,
 ) -> ExecutionResult<WundergraphScalarValue>
 where
     R: LoadingHandler<DB, Ctx>,
@@ -33,21 +38,34 @@ where
             SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
         >,
     <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
-    I: FromInputValue<WundergraphScalarValue>,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+    I: FromInputValue<WundergraphScalarValue> + 'static,
+    H: EntityHooks<DB, Ctx, I>,
 {
-    if let Some(n) = arguments.get::<I>(field_name) {
-        <R::Table as HandleInsert<_, _, _, _>>::handle_insert(selection, executor, n)
+    if let Some(mut n) = arguments.get::<I>(field_name) {
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        ctx.get_connection()
+            .transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            // Hooks may normalize the input, so validate the result of them
+            hooks.before_insert(ctx, R::TYPE_NAME, &mut n)?;
+            ValidationErrors::check_with(&n, validator)?;
+            <R::Table as HandleInsert<_, _, _, _>>::handle_insert(selection, executor, n, hooks)
+        })
     } else {
         let msg = format!("Missing argument {}", field_name);
         Err(FieldError::new(&msg, Value::Null))
     }
 }
 
-pub fn handle_batch_insert<DB, I, R, Ctx>(
+pub fn handle_batch_insert<DB, I, R, Ctx, H>(
     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
     executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     arguments: &Arguments<'_, WundergraphScalarValue>,
     field_name: &'static str,
+    validator: Validator<I>,
+    hooks: SelectedHooks<H, DB, Ctx, I>,
 ) -> ExecutionResult<WundergraphScalarValue>
 where
     R: LoadingHandler<DB, Ctx>,
@@ -61,10 +79,24 @@ where
             SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
         >,
     <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
-    I: FromInputValue<WundergraphScalarValue>,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+    I: FromInputValue<WundergraphScalarValue> + 'static,
+    H: EntityHooks<DB, Ctx, I>,
 {
-    if let Some(n) = arguments.get::<Vec<I>>(field_name) {
-        <R::Table as HandleBatchInsert<_, _, _, _>>::handle_batch_insert(selection, executor, n)
+    if let Some(mut batch) = arguments.get::<Vec<I>>(field_name) {
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        ctx.get_connection()
+            .transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            for insertable in &mut batch {
+                hooks.before_insert(ctx, R::TYPE_NAME, insertable)?;
+            }
+            ValidationErrors::check_batch_with(&batch, validator)?;
+            <R::Table as HandleBatchInsert<_, _, _, _>>::handle_batch_insert(
+                selection, executor, batch, hooks,
+            )
+        })
     } else {
         let msg = format!("Missing argument {}", field_name);
         Err(FieldError::new(&msg, Value::Null))
@@ -72,17 +104,19 @@ where
 }
 
 pub trait HandleInsert<L, I, DB, Ctx> {
-    fn handle_insert(
+    fn handle_insert<H: EntityHooks<DB, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         insertable: I,
+        hooks: SelectedHooks<H, DB, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue>;
 }
 
 pub trait HandleBatchInsert<L, I, DB, Ctx> {
-    fn handle_batch_insert(
+    fn handle_batch_insert<H: EntityHooks<DB, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         insertable: Vec<I>,
+        hooks: SelectedHooks<H, DB, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue>;
 }
@@ -1,6 +1,8 @@
 use super::{HandleBatchInsert, HandleInsert};
-use crate::context::WundergraphContext;
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::context::{MutationGuard, WundergraphContext};
 use crate::helper::primary_keys::UnRef;
+use crate::instrumentation::instrument;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::order::BuildOrder;
@@ -34,7 +36,7 @@ where
     Ctx: WundergraphContext + QueryModifier<L, Pg>,
     Ctx::Connection: Connection<Backend = Pg>,
     L::FieldList: WundergraphFieldList<Pg, L::PrimaryKeyIndex, T, Ctx>,
-    I: Insertable<T>,
+    I: Insertable<T> + 'static,
     I::Values: QueryFragment<Pg> + CanInsertInSingleQuery<Pg>,
     T::PrimaryKey: QueryFragment<Pg>,
     T: BoxedDsl<
@@ -49,26 +51,33 @@ where
     T::PrimaryKey: EqAll<Id>,
     &'static L: Identifiable,
     <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
-    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Pg>,
+    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Pg> + 'static,
     <T::PrimaryKey as EqAll<Id>>::Output:
         SelectableExpression<T> + NonAggregate + QueryFragment<Pg> + 'static,
 {
-    fn handle_insert(
+    fn handle_insert<H: EntityHooks<Pg, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         insertable: I,
+        hooks: SelectedHooks<H, Pg, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
         let conn = ctx.get_connection();
         conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
             let look_ahead = executor.look_ahead();
             let inserted = insertable
                 .insert_into(Self::table())
                 .returning(Self::table().primary_key());
-            if cfg!(feature = "debug") {
-                println!("{}", ::diesel::debug_query(&inserted));
-            }
-            let inserted: Id = inserted.get_result(conn)?;
+            let inserted: Id = instrument(
+                ctx,
+                conn,
+                L::TYPE_NAME,
+                inserted,
+                |inserted, conn| inserted.get_result(conn),
+                |_| 1,
+            )?;
+            hooks.after_insert(ctx, L::TYPE_NAME, std::slice::from_ref(&inserted))?;
             let q = L::build_query(&look_ahead)?;
             let q = FilterDsl::filter(q, Self::table().primary_key().eq_all(inserted));
             let items = L::load(&look_ahead, selection, executor, q)?;
@@ -91,6 +100,7 @@ where
     Ctx: WundergraphContext + QueryModifier<L, Pg>,
     Ctx::Connection: Connection<Backend = Pg>,
     L::FieldList: WundergraphFieldList<Pg, L::PrimaryKeyIndex, T, Ctx>,
+    I: 'static,
     Vec<I>: Insertable<T>,
     <Vec<I> as Insertable<T>>::Values: QueryFragment<Pg> + CanInsertInSingleQuery<Pg>,
     T::PrimaryKey: QueryFragment<Pg>,
@@ -106,26 +116,33 @@ where
     T::PrimaryKey: EqAll<Id>,
     &'static L: Identifiable,
     <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
-    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Pg>,
+    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Pg> + 'static,
     <T::PrimaryKey as EqAll<Id>>::Output:
         SelectableExpression<T> + NonAggregate + QueryFragment<Pg> + 'static,
 {
-    fn handle_batch_insert(
+    fn handle_batch_insert<H: EntityHooks<Pg, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         batch: Vec<I>,
+        hooks: SelectedHooks<H, Pg, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
         let conn = ctx.get_connection();
         conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
             let look_ahead = executor.look_ahead();
             let inserted = batch
                 .insert_into(Self::table())
                 .returning(Self::table().primary_key());
-            if cfg!(feature = "debug") {
-                println!("{}", ::diesel::debug_query(&inserted));
-            }
-            let inserted: Vec<Id> = inserted.get_results(conn)?;
+            let inserted: Vec<Id> = instrument(
+                ctx,
+                conn,
+                L::TYPE_NAME,
+                inserted,
+                |inserted, conn| inserted.get_results(conn),
+                Vec::len,
+            )?;
+            hooks.after_insert(ctx, L::TYPE_NAME, &inserted)?;
             let mut q = L::build_query(&look_ahead)?;
             for i in inserted {
                 q = OrFilterDsl::or_filter(q, Self::table().primary_key().eq_all(i));
@@ -1,5 +1,8 @@
 use super::{HandleBatchInsert, HandleInsert};
-use crate::context::WundergraphContext;
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::helper::primary_keys::UnRef;
+use crate::instrumentation::instrument;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::order::BuildOrder;
@@ -10,18 +13,21 @@ use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
 use diesel::dsl::SqlTypeOf;
 use diesel::expression::dsl::sql;
+use diesel::expression::{Expression, SelectableExpression};
 use diesel::query_builder::{BoxedSelectStatement, InsertStatement, QueryFragment};
-use diesel::query_dsl::methods::{BoxedDsl, ExecuteDsl, LimitDsl, OrderDsl};
+use diesel::query_dsl::methods::{BoxedDsl, ExecuteDsl, LimitDsl, OrderDsl, SelectDsl};
 use diesel::sql_types::{Bool, HasSqlType};
 use diesel::sqlite::Sqlite;
-use diesel::{AppearsOnTable, Connection, Insertable, RunQueryDsl, Table};
+use diesel::{
+    AppearsOnTable, Connection, Identifiable, Insertable, QueryDsl, Queryable, RunQueryDsl, Table,
+};
 use juniper::{ExecutionResult, Executor, Selection, Value};
 
-impl<I, Ctx, L, T> HandleInsert<L, I, Sqlite, Ctx> for T
+impl<I, Ctx, L, T, Id> HandleInsert<L, I, Sqlite, Ctx> for T
 where
     T: Table + HasTable<Table = T> + 'static,
     T::FromClause: QueryFragment<Sqlite>,
-    L: LoadingHandler<Sqlite, Ctx, Table = T>,
+    L: LoadingHandler<Sqlite, Ctx, Table = T> + 'static,
     L::Columns: BuildOrder<T, Sqlite>
         + BuildSelect<
             T,
@@ -31,27 +37,43 @@ where
     Ctx: WundergraphContext + QueryModifier<L, Sqlite>,
     Ctx::Connection: Connection<Backend = Sqlite>,
     L::FieldList: WundergraphFieldList<Sqlite, L::PrimaryKeyIndex, T, Ctx>,
-    I: Insertable<T>,
+    I: Insertable<T> + 'static,
     I::Values: QueryFragment<Sqlite>,
-    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection>,
+    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection> + QueryFragment<Sqlite>,
     T: BoxedDsl<
         'static,
         Sqlite,
         Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Sqlite>,
     >,
     <L::Filter as BuildFilter<Sqlite>>::Ret: AppearsOnTable<T>,
-    Sqlite: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>>,
+    Sqlite: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>>
+        + HasSqlType<SqlTypeOf<T::PrimaryKey>>,
+    T::PrimaryKey: SelectableExpression<T> + QueryFragment<Sqlite> + 'static,
+    &'static L: Identifiable,
+    <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
+    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Sqlite> + 'static,
 {
-    fn handle_insert(
+    fn handle_insert<H: EntityHooks<Sqlite, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         insertable: I,
+        hooks: SelectedHooks<H, Sqlite, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
         let conn = ctx.get_connection();
         conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
             let look_ahead = executor.look_ahead();
-            insertable.insert_into(T::table()).execute(conn)?;
+            instrument(
+                ctx,
+                conn,
+                L::TYPE_NAME,
+                insertable.insert_into(T::table()),
+                |statement, conn| statement.execute(conn),
+                |n| *n,
+            )?;
+            let inserted: Vec<Id> = last_inserted_keys::<T, _, _>(ctx, L::TYPE_NAME, 1)?;
+            hooks.after_insert(ctx, L::TYPE_NAME, &inserted)?;
             let q = OrderDsl::order(L::build_query(&look_ahead)?, sql::<Bool>("rowid DESC"));
             let q = LimitDsl::limit(q, 1);
             let items = L::load(&look_ahead, selection, executor, q)?;
@@ -61,11 +83,11 @@ where
     }
 }
 
-impl<I, Ctx, L, T> HandleBatchInsert<L, I, Sqlite, Ctx> for T
+impl<I, Ctx, L, T, Id> HandleBatchInsert<L, I, Sqlite, Ctx> for T
 where
     T: Table + HasTable<Table = T> + 'static,
     T::FromClause: QueryFragment<Sqlite>,
-    L: LoadingHandler<Sqlite, Ctx, Table = T>,
+    L: LoadingHandler<Sqlite, Ctx, Table = T> + 'static,
     L::Columns: BuildOrder<T, Sqlite>
         + BuildSelect<
             T,
@@ -75,32 +97,51 @@ where
     Ctx: WundergraphContext + QueryModifier<L, Sqlite>,
     Ctx::Connection: Connection<Backend = Sqlite>,
     L::FieldList: WundergraphFieldList<Sqlite, L::PrimaryKeyIndex, T, Ctx>,
-    I: Insertable<T>,
+    I: Insertable<T> + 'static,
     I::Values: QueryFragment<Sqlite>,
-    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection>,
+    InsertStatement<T, I::Values>: ExecuteDsl<Ctx::Connection> + QueryFragment<Sqlite>,
     T: BoxedDsl<
         'static,
         Sqlite,
         Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Sqlite>,
     >,
     <L::Filter as BuildFilter<Sqlite>>::Ret: AppearsOnTable<T>,
-    Sqlite: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>>,
+    Sqlite: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, Sqlite, L::PrimaryKeyIndex, T, Ctx>>
+        + HasSqlType<SqlTypeOf<T::PrimaryKey>>,
+    T::PrimaryKey: SelectableExpression<T> + QueryFragment<Sqlite> + 'static,
+    &'static L: Identifiable,
+    <&'static L as Identifiable>::Id: UnRef<'static, UnRefed = Id>,
+    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Sqlite> + 'static,
 {
-    fn handle_batch_insert(
+    fn handle_batch_insert<H: EntityHooks<Sqlite, Ctx, I>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         batch: Vec<I>,
+        hooks: SelectedHooks<H, Sqlite, Ctx, I>,
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
         let conn = ctx.get_connection();
         conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
             let look_ahead = executor.look_ahead();
             let n: usize = batch
                 .into_iter()
-                .map(|i| i.insert_into(T::table()).execute(conn))
+                .map(|i| {
+                    instrument(
+                        ctx,
+                        conn,
+                        L::TYPE_NAME,
+                        i.insert_into(T::table()),
+                        |statement, conn| statement.execute(conn),
+                        |n| *n,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()?
                 .into_iter()
                 .sum();
+            let mut inserted: Vec<Id> = last_inserted_keys::<T, _, _>(ctx, L::TYPE_NAME, n as i64)?;
+            inserted.reverse();
+            hooks.after_insert(ctx, L::TYPE_NAME, &inserted)?;
             let q = OrderDsl::order(L::build_query(&look_ahead)?, sql::<Bool>("rowid DESC"));
             let q = LimitDsl::limit(q, n as i64);
             let items = L::load(&look_ahead, selection, executor, q)?;
@@ -108,3 +149,39 @@ where
         })
     }
 }
+
+/// Load the primary keys of the last `n` inserted rows of a table
+///
+/// Sqlite does not support `RETURNING`, so we rely on the `rowid` here
+fn last_inserted_keys<T, Id, Ctx>(
+    ctx: &Ctx,
+    type_name: &'static str,
+    n: i64,
+) -> Result<Vec<Id>, diesel::result::Error>
+where
+    T: Table
+        + HasTable<Table = T>
+        + BoxedDsl<
+            'static,
+            Sqlite,
+            Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, Sqlite>,
+        >,
+    T::FromClause: QueryFragment<Sqlite>,
+    T::PrimaryKey: SelectableExpression<T> + QueryFragment<Sqlite> + 'static,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = Sqlite>,
+    Sqlite: HasSqlType<SqlTypeOf<T::PrimaryKey>>,
+    Id: Queryable<<T::PrimaryKey as Expression>::SqlType, Sqlite>,
+{
+    let q = SelectDsl::select(QueryDsl::into_boxed(T::table()), T::table().primary_key());
+    let q = OrderDsl::order(q, sql::<Bool>("rowid DESC"));
+    let q = LimitDsl::limit(q, n);
+    instrument(
+        ctx,
+        ctx.get_connection(),
+        type_name,
+        q,
+        |q, conn| q.load(conn),
+        Vec::len,
+    )
+}
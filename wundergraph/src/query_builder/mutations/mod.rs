@@ -66,21 +66,132 @@
 //! }
 //! # fn main() {}
 //! ```
+//!
+//! Fields set to `None` are not changed. To allow clients to clear a
+//! nullable column use `Option<Option<T>>` as field type and derive
+//! [`ExplicitNulls`](trait.ExplicitNulls.html): an omitted field leaves
+//! the column unchanged, while a field explicitly set to `null` sets the
+//! column to `NULL`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate diesel;
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # #[macro_use]
+//! # extern crate wundergraph;
+//! # table! {
+//! #    heros {
+//! #        id -> Integer,
+//! #        name -> Text,
+//! #        hair_color -> Nullable<Text>,
+//! #    }
+//! # }
+//!
+//! #[derive(AsChangeset, GraphQLInputObject, Identifiable, ExplicitNulls, Debug)]
+//! #[table_name = "heros"]
+//! pub struct HeroChangeset {
+//!     id: i32,
+//!     name: Option<String>,
+//!     hair_color: Option<Option<String>>,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! # Atomic updates
+//!
+//! To change a column relative to its current value, for example to
+//! increment a counter without reading it first, use one of the types of
+//! the [`atomic`](atomic/index.html) module as field type of the changeset.
+//! Those are translated into `SET column = column + $1` and similar
+//! assignments of the update statement.
+//!
+//! # Hooks
+//!
+//! To run custom code as part of a mutation (for example to set audit columns
+//! or to write additional rows) implement
+//! [`EntityHooks`](trait.EntityHooks.html) for an entity type, or return
+//! [`MutationHooks`](trait.MutationHooks.html) shared by all entities from
+//! the context. Both are optional, entities and contexts without hooks
+//! just skip them. `before_insert` and `before_update` are called before
+//! the input is validated, so they could be used to normalize the input.
+//!
+//! # Delete
+//!
+//! `Delete<Entity>` returns the number of deleted rows. Additionally
+//! `Delete<Entity>Returning` returns the deleted entity itself, using the
+//! normal selection set. On postgres this uses `DELETE ... RETURNING`, on
+//! sqlite the entity is loaded before it is deleted in the same transaction.
+//! Rows filtered out by the [`QueryModifier`](../selection/query_modifier/trait.QueryModifier.html)
+//! of the context are not deleted, in this case `null` is returned.
+//! See [`HandleDeleteReturning`](trait.HandleDeleteReturning.html) for details.
+//!
+//! # Soft delete
+//!
+//! Entities marked with `#[wundergraph(soft_delete = "deleted_at")]` are not
+//! removed by `Delete<Entity>`. Instead the given column is set to the current
+//! timestamp. For those entities an additional `Restore<Entity>` mutation is
+//! provided, that resets the column and returns the restored entity.
+//! See the [`soft_delete`](../selection/soft_delete/index.html) module for details.
+//!
+//! # Optimistic concurrency control
+//!
+//! Entities marked with `#[wundergraph(version_column = "version")]` only
+//! accept updates and deletes whose input object contains the current
+//! version of the row. Otherwise the mutation fails with a
+//! [`Conflict`](../selection/version/struct.Conflict.html) error.
+//! See the [`version`](../selection/version/index.html) module for details.
+//!
+//! # Relations
+//!
+//! Fields of an entity marked with `#[wundergraph(relation_mutations)]`
+//! get mutations to connect and disconnect related rows, for example
+//! `ConnectHeroSpecies` for a `HasOne` field `species` of the entity
+//! `Hero`. They are exposed for entities marked with `relations = true`
+//! in `mutation_object!`, which need to implement
+//! [`RelationMutations`](relation/trait.RelationMutations.html) as done
+//! by `#[derive(WundergraphEntity)]`. `HasMany` fields need a nullable
+//! foreign key, relations through a join table are not supported.
+//! See the [`relation`](relation/index.html) module for details.
+//!
+//! # Validation
+//!
+//! Insertable and changeset types implementing
+//! [`ValidateInput`](validate/trait.ValidateInput.html) are checked before any
+//! sql is sent to the database. Use `#[derive(ValidateInput)]` and
+//! `#[wundergraph(validate(...))]` annotations to declare the rules for each
+//! field. See the [`validate`](validate/index.html) module for details.
 
+pub mod atomic;
 mod delete;
+mod hooks;
 mod insert;
+pub mod relation;
 mod update;
+pub mod validate;
 
 #[doc(inline)]
-pub use self::delete::{DeletedCount, HandleDelete};
+pub use self::atomic::{ArrayUpdate, NumericUpdate, TextUpdate, TimestampUpdate};
+#[doc(inline)]
+pub use self::delete::{DeletedCount, HandleDelete, HandleDeleteReturning, HandleRestore};
+#[doc(inline)]
+pub use self::hooks::{EntityHooks, MutationHooks};
 #[doc(inline)]
 pub use self::insert::{HandleBatchInsert, HandleInsert};
 #[doc(inline)]
-pub use self::update::HandleUpdate;
+pub use self::relation::{RelationChange, RelationMutations, RelationOperation};
+#[doc(inline)]
+pub use self::update::{ExplicitNulls, HandleUpdate};
+#[doc(inline)]
+pub use self::validate::{ValidateInput, ValidationErrors};
 
 #[doc(hidden)]
-pub use self::delete::handle_delete;
+pub use self::delete::{handle_delete, handle_delete_returning, handle_restore};
+#[doc(hidden)]
+pub use self::hooks::{EntityHooksFor, SelectedHooks, WithEntityHooks, WithoutEntityHooks};
 #[doc(hidden)]
 pub use self::insert::{handle_insert, handle_batch_insert};
 #[doc(hidden)]
-pub use self::update::handle_update;
+pub use self::update::{
+    handle_update, NullSetter, NullSetterFor, WithExplicitNulls, WithoutExplicitNulls,
+};
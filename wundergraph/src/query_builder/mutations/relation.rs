@@ -0,0 +1,440 @@
+//! Helper types and functions for fields marked with
+//! `#[wundergraph(relation_mutations)]`
+//!
+//! Marking a `HasOne` or `HasMany` field of an entity deriving
+//! `WundergraphEntity` with this attribute adds mutations changing the
+//! corresponding relation, without the need to write a changeset type.
+//! All of them take the primary key of the entity as argument named like
+//! the primary key field and return the changed entity, or `null` if
+//! there is no such entity visible under the current context.
+//! The mutations are only exposed for entities marked with
+//! `relations = true` in `mutation_object!`, for example
+//! `Hero(insert = NewHero, update = HeroChangeset, relations = true,)`.
+//!
+//! For a `HasOne` field `species` of an entity `Hero` those are
+//!
+//! * `ConnectHeroSpecies(id, to)` pointing the foreign key to the row
+//!   with the primary key `to`
+//! * `DisconnectHeroSpecies(id)` setting the foreign key to `NULL`,
+//!   only for optional fields
+//!
+//! For a `HasMany` field `heros` of an entity `Species` those are
+//!
+//! * `ConnectSpeciesHeros(id, ids)` pointing the foreign key of the
+//!   listed children to the entity
+//! * `DisconnectSpeciesHeros(id, ids)` setting the foreign key of the
+//!   listed children to `NULL`, if they currently belong to the entity
+//! * `SetSpeciesHeros(id, ids)` connecting the listed children and
+//!   disconnecting all other children of the entity
+//!
+//! Only `HasMany` fields whose foreign key is a nullable column of the
+//! child table are supported, using the attribute on a field with a not
+//! nullable foreign key fails to compile (see
+//! [`NullableForeignKey`](trait.NullableForeignKey.html)). Children of
+//! such relations are moved to another entity by the `Connect` mutation
+//! of the corresponding `HasOne` field of the child entity.
+//!
+//! Many-to-many relations through a join table (like the `appears_in`
+//! field of a hero) are not supported. They are changed by inserting and
+//! deleting rows of the join table entity instead.
+//!
+//! All referenced rows need to exist and to be visible under the
+//! [`QueryModifier`](../../selection/query_modifier/trait.QueryModifier.html)
+//! of the context, otherwise the mutation fails before anything is
+//! written. Children not visible to the context are never changed.
+//!
+//! Each mutation runs inside of a transaction and calls the update hooks
+//! of the context and the [`EntityHooks`](../trait.EntityHooks.html) of
+//! the entity whose rows are changed, so of the entity itself for `HasOne`
+//! fields and of the child entity for `HasMany` fields. `before_update` is
+//! called with
+//! a [`RelationChange`](struct.RelationChange.html) describing the
+//! mutation before it is validated, `after_update` with the primary
+//! keys of all changed rows.
+//!
+//! Relation mutations are only supported for entities with a single
+//! column primary key and could not be used for `HasOne` fields of
+//! versioned entities, as they would bypass the version check.
+
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::diesel_ext::BoxableFilter;
+use crate::error::WundergraphError;
+use crate::instrumentation::{instrument, Explain};
+use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::mutations::validate::{ValidateInput, ValidationErrors};
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
+use crate::query_builder::selection::fields::WundergraphFieldList;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::invalid_argument;
+use crate::query_builder::selection::offset::ApplyOffset;
+use crate::query_builder::selection::order::BuildOrder;
+use crate::query_builder::selection::query_modifier::QueryModifier;
+use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::{BoxedQuery, LoadingHandler, SqlTypeOfPlaceholder};
+use crate::scalar::WundergraphScalarValue;
+use diesel::associations::HasTable;
+use diesel::backend::Backend;
+use diesel::dsl::{count_star, SqlTypeOf};
+use diesel::expression::Expression;
+use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
+use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, LimitDsl, SelectDsl};
+use diesel::sql_types::{BigInt, Bool, HasSqlType, NotNull, Nullable};
+use diesel::{AppearsOnTable, Connection, Queryable, RunQueryDsl, Table};
+use failure::Error;
+use juniper::meta::Field;
+use juniper::{
+    ExecutionResult, Executor, FieldError, LookAheadMethods, LookAheadSelection, Registry,
+    Selection, Value,
+};
+use std::any::Any;
+
+/// The relation mutations of an entity
+///
+/// `#[derive(WundergraphEntity)]` implements this trait for entities with
+/// fields marked with `#[wundergraph(relation_mutations)]`. It is only
+/// required for entities marked with `relations = true` in
+/// `mutation_object!`.
+pub trait RelationMutations<DB, Ctx> {
+    /// The graphql names of the relation mutations of this entity
+    const RELATION_MUTATIONS: &'static [&'static str] = &[];
+
+    /// Register the arguments of the relation mutation with the given
+    /// index in `RELATION_MUTATIONS`
+    fn register_relation_mutation_arguments<'r>(
+        _idx: usize,
+        _registry: &mut Registry<'r, WundergraphScalarValue>,
+        field: Field<'r, WundergraphScalarValue>,
+    ) -> Field<'r, WundergraphScalarValue> {
+        field
+    }
+
+    /// Execute the relation mutation with the given index in
+    /// `RELATION_MUTATIONS`
+    fn handle_relation_mutation(
+        idx: usize,
+        _selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        _executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        Err(FieldError::new(
+            format!("Unknown relation mutation {}", idx),
+            Value::Null,
+        ))
+    }
+}
+
+// Used by `mutation_object!` for entities without relation mutations
+impl<DB, Ctx> RelationMutations<DB, Ctx> for () {}
+
+/// The kind of change done by a relation mutation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationOperation {
+    /// Connect the referenced rows
+    Connect,
+    /// Disconnect the referenced rows
+    Disconnect,
+    /// Connect the referenced rows and disconnect all others
+    Set,
+}
+
+/// The input of a relation mutation
+///
+/// Passed to the `before_update` hooks of the context and of the entity
+/// whose rows are changed. Hooks may change the referenced
+/// rows, the result is validated afterwards: `HasOne` connections need
+/// exactly one target, `HasMany` mutations reject duplicated targets and
+/// all of them except `Set` need at least one target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationChange<K, T> {
+    mutation: &'static str,
+    operation: RelationOperation,
+    argument: &'static str,
+    key: K,
+    targets: Vec<T>,
+}
+
+impl<K, T> RelationChange<K, T> {
+    #[doc(hidden)]
+    pub fn new(
+        mutation: &'static str,
+        operation: RelationOperation,
+        argument: &'static str,
+        key: K,
+        targets: Vec<T>,
+    ) -> Self {
+        Self {
+            mutation,
+            operation,
+            argument,
+            key,
+            targets,
+        }
+    }
+
+    /// The graphql name of the mutation, for example `ConnectHeroSpecies`
+    pub fn mutation(&self) -> &'static str {
+        self.mutation
+    }
+
+    /// The kind of change
+    pub fn operation(&self) -> RelationOperation {
+        self.operation
+    }
+
+    /// The name of the argument referencing the targets, `to` for
+    /// `HasOne` fields and `ids` for `HasMany` fields
+    pub fn argument(&self) -> &'static str {
+        self.argument
+    }
+
+    /// The primary key of the entity owning the relation
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// The primary keys of the referenced rows
+    ///
+    /// Empty when disconnecting a `HasOne` field
+    pub fn targets(&self) -> &[T] {
+        &self.targets
+    }
+
+    /// Mutable access to the primary keys of the referenced rows
+    pub fn targets_mut(&mut self) -> &mut Vec<T> {
+        &mut self.targets
+    }
+}
+
+impl<K, T> ValidateInput for RelationChange<K, T>
+where
+    T: PartialEq,
+{
+    fn validate(&self, errors: &mut ValidationErrors) {
+        let has_one = self.argument == "to";
+        match self.operation {
+            RelationOperation::Connect if has_one && self.targets.len() != 1 => {
+                errors.add(self.argument, "must reference exactly one row");
+            }
+            RelationOperation::Connect | RelationOperation::Disconnect
+                if !has_one && self.targets.is_empty() =>
+            {
+                errors.add(self.argument, "must not be empty");
+            }
+            _ => {}
+        }
+        let distinct = self
+            .targets
+            .iter()
+            .enumerate()
+            .filter(|&(idx, t)| !self.targets[..idx].contains(t))
+            .count();
+        if !has_one && distinct != self.targets.len() {
+            errors.add(self.argument, "must not contain duplicates");
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Queries on the rows of an entity visible under the current context
+///
+/// Used by the relation mutations generated by `#[derive(WundergraphEntity)]`
+pub trait RelationEntity<DB, Ctx>: LoadingHandler<DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+{
+    /// All rows of this entity visible under the query modifier of
+    /// the context
+    fn visible_rows(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        ctx: &Ctx,
+    ) -> Result<BoxedQuery<'static, Self, DB, Ctx>, Error>;
+
+    /// Count the visible rows matching `filter`
+    fn count_visible(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        ctx: &Ctx,
+        filter: Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>,
+    ) -> Result<usize, Error>;
+
+    /// Load the visible row matching `filter` using the given selection
+    fn load_visible(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        filter: Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>,
+    ) -> ExecutionResult<WundergraphScalarValue>;
+}
+
+impl<L, DB, Ctx, T> RelationEntity<DB, Ctx> for L
+where
+    T: Table + HasTable<Table = T> + 'static,
+    DB: Backend + ApplyOffset + Explain + HasSqlType<BigInt> + 'static,
+    DB::QueryBuilder: Default,
+    T::FromClause: QueryFragment<DB>,
+    L: LoadingHandler<DB, Ctx, Table = T>,
+    L::Columns: BuildOrder<T, DB>
+        + BuildSelect<T, DB, SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    Ctx: WundergraphContext + QueryModifier<L, DB>,
+    Ctx::Connection: Connection<Backend = DB>,
+    L::FieldList: WundergraphFieldList<DB, L::PrimaryKeyIndex, T, Ctx>,
+    T: BoxedDsl<
+        'static,
+        DB,
+        Output = BoxedSelectStatement<'static, SqlTypeOf<<T as Table>::AllColumns>, T, DB>,
+    >,
+    <L::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<T>,
+    DB: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    i64: Queryable<BigInt, DB>,
+{
+    fn visible_rows(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        ctx: &Ctx,
+    ) -> Result<BoxedQuery<'static, Self, DB, Ctx>, Error> {
+        ctx.modify_query(select, L::build_query(select)?)
+    }
+
+    fn count_visible(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        ctx: &Ctx,
+        filter: Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>,
+    ) -> Result<usize, Error> {
+        let conn = ctx.get_connection();
+        let q = FilterDsl::filter(Self::visible_rows(select, ctx)?, filter);
+        let q = SelectDsl::select(q, count_star());
+        let count: i64 = instrument(
+            ctx,
+            conn,
+            L::TYPE_NAME,
+            q,
+            |q, conn| q.get_result(conn),
+            |_| 1,
+        )?;
+        Ok(count as usize)
+    }
+
+    fn load_visible(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        filter: Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        let q = FilterDsl::filter(L::build_query(select)?, filter);
+        // We filter by primary key so there should only be one element
+        let q = LimitDsl::limit(q, 1);
+        let items = L::load(select, selection, executor, q)?;
+        Ok(items.into_iter().next().unwrap_or(Value::Null))
+    }
+}
+
+#[doc(hidden)]
+/// Extract the argument `name` passed to a relation mutation
+pub fn argument<T>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+) -> Result<T, Error>
+where
+    T: FromLookAheadValue,
+{
+    select
+        .argument(name)
+        .and_then(|arg| T::from_look_ahead(arg.value()))
+        .ok_or_else(|| invalid_argument(name, "expected a primary key"))
+}
+
+/// Implemented for the sql types of nullable columns
+///
+/// Relation mutations of `HasMany` fields require the foreign key column
+/// of the child table to be nullable, as disconnected children do not
+/// belong to any row. Marking a `HasMany` field with a not nullable
+/// foreign key with `#[wundergraph(relation_mutations)]` fails to compile
+/// with an error mentioning this trait.
+pub trait NullableForeignKey {}
+
+impl<ST> NullableForeignKey for Nullable<ST> where ST: NotNull {}
+
+#[doc(hidden)]
+/// Fail to compile if the foreign key `FK` of a `HasMany` field is not
+/// nullable
+pub fn assert_nullable_foreign_key<FK>()
+where
+    FK: Expression,
+    FK::SqlType: NullableForeignKey,
+{
+}
+
+#[doc(hidden)]
+/// Fail if not all `expected` rows of `R` matching `filter` are visible
+/// under the current context
+///
+/// `mutation` is the graphql name of the executed mutation, `argument`
+/// the name of the argument the rows are referenced by
+pub fn check_visible<R, DB, Ctx>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ctx: &Ctx,
+    mutation: &str,
+    argument: &str,
+    filter: Box<dyn BoxableFilter<R::Table, DB, SqlType = Bool>>,
+    expected: usize,
+) -> Result<(), Error>
+where
+    R: RelationEntity<DB, Ctx>,
+    DB: Backend + ApplyOffset + 'static,
+{
+    if R::count_visible(select, ctx, filter)? == expected {
+        Ok(())
+    } else {
+        Err(WundergraphError::RelationTargetNotFound {
+            mutation: mutation.to_owned(),
+            argument: argument.to_owned(),
+            entity: R::TYPE_NAME.to_owned(),
+        }
+        .into())
+    }
+}
+
+#[doc(hidden)]
+/// Execute a relation mutation of the entity matching `entity`
+///
+/// Returns `null` if the entity is not visible under the current context.
+/// Otherwise the hooks of the context and of `R` are called around
+/// `mutate`, which returns the primary keys of all changed rows, and the
+/// changed entity is returned.
+pub fn handle_relation_mutation<L, R, DB, Ctx, K, T, A, F, H>(
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    entity: &dyn Fn() -> Box<dyn BoxableFilter<L::Table, DB, SqlType = Bool>>,
+    mut change: RelationChange<K, T>,
+    hooks: SelectedHooks<H, DB, Ctx, RelationChange<K, T>>,
+    mutate: F,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    L: RelationEntity<DB, Ctx>,
+    R: LoadingHandler<DB, Ctx>,
+    DB: Backend + ApplyOffset + 'static,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+    K: 'static,
+    T: PartialEq + 'static,
+    H: EntityHooks<DB, Ctx, RelationChange<K, T>>,
+    A: Any,
+    F: FnOnce(
+        &Ctx,
+        &LookAheadSelection<'_, WundergraphScalarValue>,
+        &RelationChange<K, T>,
+    ) -> Result<Vec<A>, Error>,
+{
+    let ctx = executor.context();
+    let _mutation = MutationGuard::new(ctx);
+    let conn = ctx.get_connection();
+    conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+        // Hooks may normalize the input, so validate the result of them
+        hooks.before_update(ctx, R::TYPE_NAME, &mut change)?;
+        ValidationErrors::check(&change)?;
+        let look_ahead = executor.look_ahead();
+        if L::count_visible(&look_ahead, ctx, entity())? == 0 {
+            return Ok(Value::Null);
+        }
+        let keys = mutate(ctx, &look_ahead, &change)?;
+        hooks.after_update(ctx, R::TYPE_NAME, &keys)?;
+        L::load_visible(&look_ahead, selection, executor, entity())
+    })
+}
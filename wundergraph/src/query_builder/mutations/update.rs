@@ -1,28 +1,125 @@
-use crate::context::WundergraphContext;
+use crate::context::{MutationGuard, WundergraphContext};
+use crate::diesel_ext::BoxableFilter;
+use crate::helper::primary_keys::UnRefClone;
+use crate::instrumentation::{instrument, Explain};
+use crate::query_builder::mutations::validate::{ValidationErrors, Validator};
+use crate::query_builder::mutations::hooks::{EntityHooks, SelectedHooks};
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::order::BuildOrder;
 use crate::query_builder::selection::query_modifier::QueryModifier;
 use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::version::{
+    check_conflict, expected_version, with_version_check,
+};
 use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::{HasTable, Identifiable};
 use diesel::backend::Backend;
-use diesel::dsl::{Find, SqlTypeOf};
+use diesel::deserialize::FromSql;
+use diesel::dsl::{Filter, Find, SqlTypeOf};
 use diesel::expression::NonAggregate;
 use diesel::query_builder::BoxedSelectStatement;
 use diesel::query_builder::{AsChangeset, IntoUpdateTarget, QueryFragment};
 use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, FindDsl, LimitDsl};
-use diesel::sql_types::HasSqlType;
-use diesel::{AppearsOnTable, Connection, EqAll, QuerySource, RunQueryDsl, Table};
-use juniper::{Arguments, ExecutionResult, Executor, FieldError, FromInputValue, Selection, Value};
+use diesel::sql_types::{Bool, HasSqlType, Integer};
+use diesel::{AppearsOnTable, Connection, EqAll, Expression, QuerySource, RunQueryDsl, Table};
+use juniper::{
+    Arguments, ExecutionResult, Executor, FieldError, FromInputValue, LookAheadArgument,
+    LookAheadMethods, LookAheadSelection, LookAheadValue, Selection, Value,
+};
+use std::marker::PhantomData;
 
-pub fn handle_update<DB, U, R, Ctx>(
+/// A trait to mark fields of a changeset that were explicitly set to `null`
+///
+/// Graphql input objects do not distinguish between omitted fields and
+/// fields set to `null` after they are converted into a rust type. A
+/// changeset field of type `Option<Option<T>>` is therefore `None`
+/// (leave the column unchanged) in both cases. Before executing an update
+/// each field given as `null` is passed to
+/// [`set_explicit_null`](#tymethod.set_explicit_null), which sets those
+/// fields to `Some(None)`, so that diesel emits `SET column = NULL`.
+///
+/// Normally this trait is implemented by using `#[derive(ExplicitNulls)]`.
+/// Changesets without an implementation treat `null` like an omitted field.
+pub trait ExplicitNulls {
+    /// Set the field with the given graphql name to `Some(None)`
+    ///
+    /// Returns `false` if there is no such field or if the field could
+    /// not be set to null explicitly
+    fn set_explicit_null(&mut self, field: &str) -> bool;
+}
+
+/// A function setting the field with the given graphql name to `Some(None)`
+#[doc(hidden)]
+pub type NullSetter<T> = fn(&mut T, &str) -> bool;
+
+/// Helper used by `mutation_object!` to select the
+/// [`ExplicitNulls`](trait.ExplicitNulls.html) implementation of a changeset
+///
+/// See the `__null_setter!` macro for the actual selection.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct NullSetterFor<T>(PhantomData<T>);
+
+impl<T> NullSetterFor<T> {
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        NullSetterFor(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithExplicitNulls<T> {
+    fn null_setter(&self) -> NullSetter<T>;
+}
+
+impl<'a, T> WithExplicitNulls<T> for &'a NullSetterFor<T>
+where
+    T: ExplicitNulls,
+{
+    fn null_setter(&self) -> NullSetter<T> {
+        <T as ExplicitNulls>::set_explicit_null
+    }
+}
+
+#[doc(hidden)]
+pub trait WithoutExplicitNulls<T> {
+    fn null_setter(&self) -> NullSetter<T>;
+}
+
+impl<T> WithoutExplicitNulls<T> for NullSetterFor<T> {
+    fn null_setter(&self) -> NullSetter<T> {
+        |_, _| false
+    }
+}
+
+fn apply_explicit_nulls<U>(
+    update: &mut U,
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+    argument: &str,
+    null_setter: NullSetter<U>,
+) {
+    if let Some(LookAheadValue::Object(ref fields)) =
+        look_ahead.argument(argument).map(LookAheadArgument::value)
+    {
+        for (name, value) in fields {
+            if let LookAheadValue::Null = *value {
+                null_setter(update, name);
+            }
+        }
+    }
+}
+
+pub fn handle_update<DB, U, R, Ctx, H>(
     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
     executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     arguments: &Arguments<'_, WundergraphScalarValue>,
     field_name: &'static str,
+    validator: Validator<U>,
+    null_setter: NullSetter<U>,
+    hooks: SelectedHooks<H, DB, Ctx, U>,
 ) -> ExecutionResult<WundergraphScalarValue>
 where
     R: LoadingHandler<DB, Ctx>,
@@ -36,10 +133,26 @@ where
             SqlTypeOfPlaceholder<R::FieldList, DB, R::PrimaryKeyIndex, R::Table, Ctx>,
         >,
     <R::Table as QuerySource>::FromClause: QueryFragment<DB>,
-    U: FromInputValue<WundergraphScalarValue>,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+    U: FromInputValue<WundergraphScalarValue> + 'static,
+    H: EntityHooks<DB, Ctx, U>,
 {
-    if let Some(n) = arguments.get::<U>(field_name) {
-        <R::Table as HandleUpdate<_, _, _, _>>::handle_update(selection, executor, &n)
+    if let Some(mut n) = arguments.get::<U>(field_name) {
+        let look_ahead = executor.look_ahead();
+        apply_explicit_nulls(&mut n, &look_ahead, field_name, null_setter);
+        let version = expected_version::<R, DB, Ctx>(&look_ahead, field_name)?;
+        let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
+        ctx.get_connection()
+            .transaction(|| -> ExecutionResult<WundergraphScalarValue> {
+            // Hooks may normalize the input, so validate the result of them
+            hooks.before_update(ctx, R::TYPE_NAME, &mut n)?;
+            ValidationErrors::check_with(&n, validator)?;
+            <R::Table as HandleUpdate<_, _, _, _>>::handle_update(
+                selection, executor, n, version, hooks,
+            )
+        })
     } else {
         let msg = format!("Missing argument {:?}", field_name);
         Err(FieldError::new(&msg, Value::Null))
@@ -47,10 +160,12 @@ where
 }
 
 pub trait HandleUpdate<L, U, DB, Ctx> {
-    fn handle_update(
+    fn handle_update<H: EntityHooks<DB, Ctx, U>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<Ctx, WundergraphScalarValue>,
-        update: &U,
+        update: U,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, U>,
     ) -> ExecutionResult<WundergraphScalarValue>;
 }
 
@@ -60,7 +175,7 @@ pub trait HandleUpdate<L, U, DB, Ctx> {
 impl<L, U, DB, Ctx, T> HandleUpdate<L, U, DB, Ctx> for T
 where
     T: Table + HasTable<Table = T> + FindDsl<<&'static U as Identifiable>::Id> + 'static,
-    DB: Backend + ApplyOffset + 'static,
+    DB: Backend + ApplyOffset + Explain + 'static,
     DB::QueryBuilder: Default,
     T::FromClause: QueryFragment<DB>,
     L: LoadingHandler<DB, Ctx, Table = T>,
@@ -81,32 +196,92 @@ where
     <L::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<T>,
     U: 'static,
     &'static U: AsChangeset<Target = T> + Identifiable + HasTable<Table = T>,
+    <&'static U as Identifiable>::Id: UnRefClone,
+    <<&'static U as Identifiable>::Id as UnRefClone>::UnRefed: 'static,
     Find<T, <&'static U as Identifiable>::Id>: IntoUpdateTarget<Table = T>,
     <Find<T, <&'static U as Identifiable>::Id> as IntoUpdateTarget>::WhereClause: QueryFragment<DB>,
     <&'static U as AsChangeset>::Changeset: QueryFragment<DB>,
+    T::Query: FilterDsl<Box<dyn BoxableFilter<T, DB, SqlType = Bool>>>,
+    Filter<T::Query, Box<dyn BoxableFilter<T, DB, SqlType = Bool>>>: IntoUpdateTarget<Table = T>,
+    <Filter<T::Query, Box<dyn BoxableFilter<T, DB, SqlType = Bool>>> as IntoUpdateTarget>::WhereClause:
+        QueryFragment<DB>,
     T::PrimaryKey: EqAll<<&'static U as Identifiable>::Id>,
-    DB: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>,
+    DB: HasSqlType<SqlTypeOfPlaceholder<L::FieldList, DB, L::PrimaryKeyIndex, T, Ctx>>
+        + HasSqlType<Integer>,
+    i32: FromSql<Integer, DB>,
     <T::PrimaryKey as EqAll<<&'static U as Identifiable>::Id>>::Output:
-        AppearsOnTable<T> + NonAggregate + QueryFragment<DB>,
+        Expression<SqlType = Bool> + AppearsOnTable<T> + NonAggregate + QueryFragment<DB> + 'static,
 {
-    fn handle_update(
+    fn handle_update<H: EntityHooks<DB, Ctx, U>>(
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
-        change_set: &U,
+        change_set: U,
+        expected_version: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+        hooks: SelectedHooks<H, DB, Ctx, U>,
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
+        let _mutation = MutationGuard::new(ctx);
         let conn = ctx.get_connection();
         conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
             let look_ahead = executor.look_ahead();
             // this is safe becuse we do not leak change_set out of this function
             // this is required because otherwise rustc fails to project the temporary
             // lifetime
-            let change_set: &'static U = unsafe { &*(change_set as *const U) };
-            let u = ::diesel::update(change_set).set(change_set);
-            if cfg!(feature = "debug") {
-                log::debug!("{}", ::diesel::debug_query(&u));
+            let change_set: &'static U = unsafe { &*(&change_set as *const U) };
+            let key_filter = || -> Box<dyn BoxableFilter<T, DB, SqlType = Bool>> {
+                Box::new(Self::table().primary_key().eq_all(change_set.id()))
+            };
+            let version_increment = match expected_version {
+                Some(_) => L::version_increment(),
+                None => None,
+            };
+            let updated = if let Some(version_increment) = version_increment {
+                // Apply the changeset only if the version still matches.
+                // A changeset deriving `AsChangeset` writes the expected
+                // version back, which is a no-op for the matched row.
+                let filter = with_version_check::<L, DB, Ctx>(key_filter(), expected_version)?;
+                let u = ::diesel::update(FilterDsl::filter(Self::table(), filter)).set(change_set);
+                let updated = instrument(
+                    ctx,
+                    conn,
+                    L::TYPE_NAME,
+                    u,
+                    |u, conn| u.execute(conn),
+                    |n| *n,
+                )?;
+                if updated > 0 {
+                    // The updated row stays locked until the transaction
+                    // ends, so no concurrent update could slip in between
+                    let filter = with_version_check::<L, DB, Ctx>(key_filter(), expected_version)?;
+                    let u = ::diesel::update(FilterDsl::filter(Self::table(), filter))
+                        .set(version_increment);
+                    instrument(
+                        ctx,
+                        conn,
+                        L::TYPE_NAME,
+                        u,
+                        |u, conn| u.execute(conn),
+                        |n| *n,
+                    )?;
+                }
+                updated
+            } else {
+                let u = ::diesel::update(change_set).set(change_set);
+                instrument(
+                    ctx,
+                    conn,
+                    L::TYPE_NAME,
+                    u,
+                    |u, conn| u.execute(conn),
+                    |n| *n,
+                )?
+            };
+            if let (0, Some(expected)) = (updated, expected_version) {
+                check_conflict::<L, DB, Ctx>(ctx, key_filter(), expected)?;
+            }
+            if updated > 0 {
+                hooks.after_update(ctx, L::TYPE_NAME, &[change_set.id().make_owned()])?;
             }
-            u.execute(conn)?;
             let f = FilterDsl::filter(
                 L::build_query(&look_ahead)?,
                 Self::table().primary_key().eq_all(change_set.id()),
@@ -0,0 +1,337 @@
+//! This module contains the declarative input validation used by insert and
+//! update mutations
+//!
+//! Insertable and changeset types used in `mutation_object!` are validated
+//! if they implement [`ValidateInput`](trait.ValidateInput.html), input types
+//! without an implementation are passed to diesel unchecked. Normally the
+//! trait is implemented by using `#[derive(ValidateInput)]`. Rules are
+//! declared per field using `#[wundergraph(validate(...))]`:
+//!
+//! * `length(min = 1, max = 255)` checks the number of characters of a string
+//!   or the number of elements of a list
+//! * `non_empty` rejects empty strings and lists
+//! * `range(min = "0", max = "100")` checks numeric bounds (both inclusive)
+//! * `regex = "^[a-z]+$"` matches a string against a regular expression
+//!   (requires the `regex` feature)
+//! * `custom = "path::to::function"` calls a function with the signature
+//!   `fn(&T) -> Result<(), String>`
+//!
+//! Rules on `Option` fields are only checked if a value is given.
+//! All violations are collected and returned as one error before any sql is
+//! sent to the database.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate diesel;
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # #[macro_use]
+//! # extern crate wundergraph;
+//! # use wundergraph::query_builder::mutations::ValidateInput;
+//! # table! {
+//! #    heros {
+//! #        id -> Integer,
+//! #        name -> Text,
+//! #        species -> Nullable<Integer>,
+//! #        home_world -> Nullable<Integer>,
+//! #    }
+//! # }
+//!
+//! #[derive(Insertable, GraphQLInputObject, ValidateInput, Clone, Debug)]
+//! #[table_name = "heros"]
+//! pub struct NewHero {
+//!    #[wundergraph(validate(non_empty, length(max = 255)))]
+//!    name: String,
+//!    #[wundergraph(validate(range(min = "1")))]
+//!    species: i32,
+//!    home_world: Option<i32>,
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::scalar::WundergraphScalarValue;
+use juniper::{FieldError, Object, Value};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A trait to validate the input of insert or update mutations before
+/// anything is sent to the database
+pub trait ValidateInput {
+    /// Check all validation rules and append each violation to `errors`
+    fn validate(&self, errors: &mut ValidationErrors);
+}
+
+impl<'a, T> ValidateInput for &'a T
+where
+    T: ValidateInput + ?Sized,
+{
+    fn validate(&self, errors: &mut ValidationErrors) {
+        (**self).validate(errors)
+    }
+}
+
+/// A function checking all validation rules of an input type
+#[doc(hidden)]
+pub type Validator<T> = fn(&T, &mut ValidationErrors);
+
+/// Helper used by `mutation_object!` to select the validator of an input type
+///
+/// Input types implementing [`ValidateInput`](trait.ValidateInput.html) are
+/// validated, for all other input types validation is skipped.
+/// See the `__validator!` macro for the actual selection.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ValidatorFor<T>(PhantomData<T>);
+
+impl<T> ValidatorFor<T> {
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        ValidatorFor(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithValidation<T> {
+    fn validator(&self) -> Validator<T>;
+}
+
+impl<'a, T> WithValidation<T> for &'a ValidatorFor<T>
+where
+    T: ValidateInput,
+{
+    fn validator(&self) -> Validator<T> {
+        <T as ValidateInput>::validate
+    }
+}
+
+#[doc(hidden)]
+pub trait WithoutValidation<T> {
+    fn validator(&self) -> Validator<T>;
+}
+
+impl<T> WithoutValidation<T> for ValidatorFor<T> {
+    fn validator(&self) -> Validator<T> {
+        |_, _| {}
+    }
+}
+
+/// A single violated validation rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValidationError {
+    index: Option<usize>,
+    field: &'static str,
+    message: String,
+}
+
+impl FieldValidationError {
+    /// The position of the invalid value in a batch insert
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// The graphql name of the invalid field
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// A description of the violated rule
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A collection of all violated validation rules for a given mutation input
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors {
+    current_index: Option<usize>,
+    errors: Vec<FieldValidationError>,
+}
+
+impl ValidationErrors {
+    /// Validate a single input value
+    pub fn check<T>(input: &T) -> Result<(), Self>
+    where
+        T: ValidateInput + ?Sized,
+    {
+        let mut errors = Self::default();
+        input.validate(&mut errors);
+        errors.into_result()
+    }
+
+    /// Validate a batch of input values
+    ///
+    /// Each reported error contains the index of the invalid value
+    pub fn check_batch<T>(inputs: &[T]) -> Result<(), Self>
+    where
+        T: ValidateInput,
+    {
+        Self::check_batch_with(inputs, <T as ValidateInput>::validate)
+    }
+
+    #[doc(hidden)]
+    pub fn check_with<T>(input: &T, validator: Validator<T>) -> Result<(), Self> {
+        let mut errors = Self::default();
+        validator(input, &mut errors);
+        errors.into_result()
+    }
+
+    #[doc(hidden)]
+    pub fn check_batch_with<T>(inputs: &[T], validator: Validator<T>) -> Result<(), Self> {
+        let mut errors = Self::default();
+        for (idx, input) in inputs.iter().enumerate() {
+            errors.current_index = Some(idx);
+            validator(input, &mut errors);
+        }
+        errors.current_index = None;
+        errors.into_result()
+    }
+
+    /// Record that the given field violates a validation rule
+    pub fn add(&mut self, field: &'static str, message: impl Into<String>) {
+        self.errors.push(FieldValidationError {
+            index: self.current_index,
+            field,
+            message: message.into(),
+        });
+    }
+
+    /// Check if no rule was violated
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// All violated rules
+    pub fn errors(&self) -> &[FieldValidationError] {
+        &self.errors
+    }
+
+    fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl From<ValidationErrors> for FieldError<WundergraphScalarValue> {
+    fn from(errors: ValidationErrors) -> Self {
+        let list = errors
+            .errors
+            .into_iter()
+            .map(|e| {
+                let mut obj = Object::with_capacity(3);
+                if let Some(idx) = e.index {
+                    obj.add_field("index", Value::scalar(idx as i32));
+                }
+                obj.add_field("field", Value::scalar(e.field));
+                obj.add_field("message", Value::scalar(e.message));
+                Value::object(obj)
+            })
+            .collect();
+        let mut extensions = Object::with_capacity(1);
+        extensions.add_field("validation", Value::list(list));
+        FieldError::new("Input validation failed", Value::object(extensions))
+    }
+}
+
+/// Types that have a length that could be checked by the `length` and
+/// `non_empty` validation rules
+pub trait ValidateLength {
+    /// The length used for validation
+    ///
+    /// For strings this is the number of characters, not the number of bytes
+    fn validation_length(&self) -> usize;
+}
+
+impl ValidateLength for str {
+    fn validation_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl ValidateLength for String {
+    fn validation_length(&self) -> usize {
+        self.as_str().validation_length()
+    }
+}
+
+impl<T> ValidateLength for [T] {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ValidateLength for Vec<T> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Implementation of the `length` validation rule
+pub fn length<T>(value: &T, min: Option<usize>, max: Option<usize>) -> Result<(), String>
+where
+    T: ValidateLength + ?Sized,
+{
+    let len = value.validation_length();
+    match (min, max) {
+        (Some(min), _) if len < min => Err(format!("must have a length of at least {}", min)),
+        (_, Some(max)) if len > max => Err(format!("must have a length of at most {}", max)),
+        _ => Ok(()),
+    }
+}
+
+/// Implementation of the `non_empty` validation rule
+pub fn non_empty<T>(value: &T) -> Result<(), String>
+where
+    T: ValidateLength + ?Sized,
+{
+    if value.validation_length() == 0 {
+        Err(String::from("must not be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Implementation of the `range` validation rule
+pub fn range<T>(value: &T, min: Option<T>, max: Option<T>) -> Result<(), String>
+where
+    T: PartialOrd + Display,
+{
+    match (min, max) {
+        (Some(ref min), _) if value < min => Err(format!("must be at least {}", min)),
+        (_, Some(ref max)) if value > max => Err(format!("must be at most {}", max)),
+        _ => Ok(()),
+    }
+}
+
+/// Implementation of the `regex` validation rule
+///
+/// Compiled expressions are cached per thread
+#[cfg(feature = "regex")]
+pub fn regex<T>(value: &T, pattern: &'static str) -> Result<(), String>
+where
+    T: AsRef<str> + ?Sized,
+{
+    use regex_internal::Regex;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    thread_local! {
+        static CACHE: RefCell<HashMap<&'static str, Regex>> = RefCell::new(HashMap::new());
+    }
+
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if !cache.contains_key(pattern) {
+            let regex = Regex::new(pattern)
+                .map_err(|e| format!("invalid validation pattern `{}`: {}", pattern, e))?;
+            cache.insert(pattern, regex);
+        }
+        if cache[pattern].is_match(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(format!("must match the pattern `{}`", pattern))
+        }
+    })
+}
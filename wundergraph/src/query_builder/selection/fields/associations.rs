@@ -1,10 +1,11 @@
 use super::WundergraphFieldList;
+use crate::context::WundergraphContext;
+use crate::query_builder::selection::filter::with_field_path;
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::LoadingHandler;
 use crate::query_builder::types::HasMany;
 use crate::query_builder::selection::query_resolver::get_sub_field;
 use crate::scalar::WundergraphScalarValue;
-use diesel::associations::HasTable;
 use diesel::backend::Backend;
 use diesel::expression::NonAggregate;
 use diesel::query_builder::QueryFragment;
@@ -50,6 +51,7 @@ impl<'a, K: Eq + Hash> AssociationsReturn<'a, K> {
         let (name, alias, loc, selection) = get_sub_field(field, selection);
         let executor = executor.field_sub_executor(alias, name, loc, selection);
 
+        let _field = crate::instrumentation::enter_field(name);
         let values = T::resolve(look_ahead, selection, &self.keys, &executor)?;
 
         let len = self.fields.len();
@@ -183,19 +185,12 @@ where
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<HashMap<Option<Self::Key>, Vec<juniper::Value<WundergraphScalarValue>>>, Error>
+    where
+        Ctx: WundergraphContext,
     {
         let (keys, vals): (Vec<_>, Vec<_>) = res.into_iter().unzip();
-        let vals = <<Self as LoadingHandler<DB, Ctx>>::FieldList as WundergraphFieldList<
-            DB,
-            <Self as LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
-            <Self as HasTable>::Table,
-            Ctx,
-        >>::resolve(
-            vals,
-            look_ahead,
-            selection,
-            <Self as LoadingHandler<DB, Ctx>>::FIELD_NAMES,
-            executor,
+        let (_, vals) = <Self as LoadingHandler<DB, Ctx>>::resolve_loaded(
+            vals, look_ahead, selection, executor,
         )?;
         Ok(keys
             .into_iter()
@@ -250,9 +245,12 @@ macro_rules! wundergraph_impl_resolve_association {
                 {
                     let mut ret = AssociationsReturn::empty();
                     $(
-                        if let Some(look_ahead) = look_ahead.select_child(get_name($idx)) {
+                        if let Some(child) = look_ahead.select_child(get_name($idx)) {
                             ret.init(&get_keys);
-                            ret.push_field::<$T, Other, Back, Ctx>(get_name($idx), look_ahead, selection, executor)?;
+                            // Invalid arguments of nested fields are reported
+                            // including the path to them
+                            ret.push_field::<$T, Other, Back, Ctx>(get_name($idx), child, selection, executor)
+                                .map_err(|e| with_field_path(look_ahead.field_name(), e))?;
                         }
                     )*
                     Ok(ret)
@@ -1,7 +1,7 @@
 use super::{FieldListExtractor, NonTableFieldExtractor, WundergraphResolveAssociations};
 use crate::context::WundergraphContext;
 use crate::query_builder::types::placeholder::PlaceHolderMarker;
-use crate::query_builder::types::WundergraphValue;
+use crate::query_builder::types::{FullTextFields, WundergraphValue};
 use crate::query_builder::selection::query_resolver::WundergraphResolvePlaceHolderList;
 use crate::helper::tuple::TupleIndex;
 use crate::scalar::WundergraphScalarValue;
@@ -22,11 +22,18 @@ pub trait WundergraphFieldList<DB: Backend, Key, Table, Ctx> {
         placeholder: Vec<Self::PlaceHolder>,
         select: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
-        name_list: &'static [&'static str],
+        name_list: &[&'static str],
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>, Error>;
 
     fn map_table_field<F: Fn(usize) -> R, R>(local_index: usize, callback: F) -> Option<R>;
+
+    /// Whether the table field with the given index is a
+    /// [`FullText`](../../../types/struct.FullText.html) field
+    fn is_full_text(_local_index: usize) -> bool {
+        false
+    }
+
     fn map_non_table_field<Func: Fn(usize) -> Ret, Ret>(
         local_index: usize,
         callback: Func,
@@ -44,7 +51,7 @@ macro_rules! wundergraph_impl_field_list {
             impl<Back, Key, Table, Ctx, $($T,)*> WundergraphFieldList<Back, Key, Table, Ctx> for ($($T,)*)
             where Back: Backend,
                   ($($T,)*): FieldListExtractor + NonTableFieldExtractor,
-                  <($($T,)*) as FieldListExtractor>::Out: WundergraphValue,
+                  <($($T,)*) as FieldListExtractor>::Out: WundergraphValue + FullTextFields,
                   <<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::PlaceHolder: TupleIndex<Key> +
                       Queryable<<<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::SqlType, Back> + 'static,
             Vec<<<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::PlaceHolder>:
@@ -65,7 +72,7 @@ macro_rules! wundergraph_impl_field_list {
                     placeholder: Vec<Self::PlaceHolder>,
                     look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
                     selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
-                    name_list: &'static [&'static str],
+                    name_list: &[&'static str],
                     executor: &Executor<'_, Ctx, WundergraphScalarValue>,
                 ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>, Error> {
                     let extern_values = {
@@ -105,6 +112,10 @@ macro_rules! wundergraph_impl_field_list {
                     <($($T,)*) as FieldListExtractor>::map(local_index, callback)
                 }
 
+                fn is_full_text(local_index: usize) -> bool {
+                    <<($($T,)*) as FieldListExtractor>::Out as FullTextFields>::is_full_text(local_index)
+                }
+
                 fn map_non_table_field<Func: Fn(usize) -> Ret, Ret>(local_index: usize, callback: Func) -> Option<Ret> {
                     <($($T,)*) as NonTableFieldExtractor>::map(local_index, callback)
                 }
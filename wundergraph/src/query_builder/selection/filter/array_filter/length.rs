@@ -0,0 +1,99 @@
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::WundergraphScalarValue;
+use indexmap::IndexMap;
+use juniper::meta::MetaType;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+
+/// The graphql field names of the supported comparisons and the
+/// corresponding sql operators
+const COMPARISONS: [(&str, &str); 6] = [
+    ("eq", "="),
+    ("not_eq", "<>"),
+    ("gt", ">"),
+    ("ge", ">="),
+    ("lt", "<"),
+    ("le", "<="),
+];
+
+/// Input of the `length` operation
+///
+/// Compares the number of elements of an array column. All given
+/// comparisons need to match.
+#[derive(Debug, Clone, Default)]
+pub struct ArrayLengthFilter([Option<i32>; 6]);
+
+impl ArrayLengthFilter {
+    /// The given comparisons as pair of sql operator and length
+    pub(super) fn comparisons(&self) -> impl Iterator<Item = (&'static str, i32)> + '_ {
+        COMPARISONS
+            .iter()
+            .zip(self.0.iter())
+            .filter_map(|((_, op), length)| length.map(|l| (*op, l)))
+    }
+}
+
+impl GraphQLType<WundergraphScalarValue> for ArrayLengthFilter {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("ArrayLengthFilter")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = COMPARISONS
+            .iter()
+            .map(|(name, _)| registry.arg_with_default::<Option<i32>>(name, &None, &()))
+            .collect::<Vec<_>>();
+        registry
+            .build_input_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl FromInputValue<WundergraphScalarValue> for ArrayLengthFilter {
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        let obj = v.to_object_value()?;
+        let mut ret = Self::default();
+        for (length, (name, _)) in ret.0.iter_mut().zip(COMPARISONS.iter()) {
+            *length = obj
+                .get(name)
+                .map_or(Some(None), |v| Option::from_input_value(*v))?;
+        }
+        Some(ret)
+    }
+}
+
+impl ToInputValue<WundergraphScalarValue> for ArrayLengthFilter {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        let map = COMPARISONS
+            .iter()
+            .zip(self.0.iter())
+            .map(|((name, _), length)| (*name, length.to_input_value()))
+            .collect::<IndexMap<_, _>>();
+        InputValue::object(map)
+    }
+}
+
+impl FromLookAheadValue for ArrayLengthFilter {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let mut ret = Self::default();
+            for (length, (name, _)) in ret.0.iter_mut().zip(COMPARISONS.iter()) {
+                *length = match obj.iter().find(|o| o.0 == *name) {
+                    None | Some((_, LookAheadValue::Null)) => None,
+                    Some((_, v)) => Some(i32::from_look_ahead(v)?),
+                };
+            }
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
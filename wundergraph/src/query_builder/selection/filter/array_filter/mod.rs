@@ -0,0 +1,194 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, Column};
+use failure::Error;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use std::marker::PhantomData;
+
+mod length;
+mod operation;
+
+use self::length::ArrayLengthFilter;
+use self::operation::{ArrayOp, ArrayOperation};
+
+/// Additional filter operations for array columns, available for fields
+/// of the type [`PgArray`](../../types/struct.PgArray.html)
+///
+/// * `contains` checks if the column contains all given elements
+/// * `contained_by` checks if all elements of the column are given
+/// * `overlaps` checks if the column has any element in common with the
+///   given elements
+/// * `length` compares the number of elements of the column
+#[derive(Debug)]
+pub struct ArrayFilter<V, C> {
+    contains: Option<Vec<V>>,
+    contained_by: Option<Vec<V>>,
+    overlaps: Option<Vec<V>>,
+    length: Option<ArrayLengthFilter>,
+    p: PhantomData<C>,
+}
+
+impl<V, C> Clone for ArrayFilter<V, C>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            contains: self.contains.clone(),
+            contained_by: self.contained_by.clone(),
+            overlaps: self.overlaps.clone(),
+            length: self.length.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<V, C> Nameable for ArrayFilter<V, C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<V, C, DB> BuildFilter<DB> for ArrayFilter<V, C>
+where
+    DB: Backend + 'static,
+    C: Column + Default + 'static,
+    C::Table: 'static,
+    V: 'static,
+    ArrayOperation<V, C>: AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(
+            self.contains
+                .map(|e| ArrayOperation::<V, C>::new(ArrayOp::Contains(e))),
+        );
+        combinator.append_filter(
+            self.contained_by
+                .map(|e| ArrayOperation::<V, C>::new(ArrayOp::ContainedBy(e))),
+        );
+        combinator.append_filter(
+            self.overlaps
+                .map(|e| ArrayOperation::<V, C>::new(ArrayOp::Overlaps(e))),
+        );
+        if let Some(length) = self.length {
+            for (op, l) in length.comparisons() {
+                combinator.append_filter(ArrayOperation::<V, C>::new(ArrayOp::Length(op, l)));
+            }
+        }
+        combinator.into_filter()
+    }
+}
+
+impl<V, C> InnerFilter for ArrayFilter<V, C>
+where
+    V: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 4;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let contains = obj.get("contains").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let contained_by = obj.get("contained_by").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let overlaps = obj.get("overlaps").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let length = obj.get("length").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            contains,
+            contained_by,
+            overlaps,
+            length,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let contains = obj
+            .iter()
+            .find(|o| o.0 == "contains")
+            .and_then(|o| Vec::from_look_ahead(&o.1));
+        let contained_by = obj
+            .iter()
+            .find(|o| o.0 == "contained_by")
+            .and_then(|o| Vec::from_look_ahead(&o.1));
+        let overlaps = obj
+            .iter()
+            .find(|o| o.0 == "overlaps")
+            .and_then(|o| Vec::from_look_ahead(&o.1));
+        let length = obj
+            .iter()
+            .find(|o| o.0 == "length")
+            .and_then(|o| ArrayLengthFilter::from_look_ahead(&o.1));
+        Self {
+            contains,
+            contained_by,
+            overlaps,
+            length,
+            p: PhantomData,
+        }
+    }
+
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<Vec<V>>(path, "contains", obj)?;
+        validate_look_ahead_field::<Vec<V>>(path, "contained_by", obj)?;
+        validate_look_ahead_field::<Vec<V>>(path, "overlaps", obj)?;
+        validate_look_ahead_field::<ArrayLengthFilter>(path, "length", obj)
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("contains", self.contains.to_input_value());
+        map.insert("contained_by", self.contained_by.to_input_value());
+        map.insert("overlaps", self.overlaps.to_input_value());
+        map.insert("length", self.length.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let contains =
+            registry.arg_with_default::<Option<Vec<V>>>("contains", &None, &Default::default());
+        let contained_by =
+            registry.arg_with_default::<Option<Vec<V>>>("contained_by", &None, &Default::default());
+        let overlaps =
+            registry.arg_with_default::<Option<Vec<V>>>("overlaps", &None, &Default::default());
+        let length = registry.arg_with_default::<Option<ArrayLengthFilter>>(
+            "length",
+            &None,
+            &Default::default(),
+        );
+        vec![contains, contained_by, overlaps, length]
+    }
+}
@@ -0,0 +1,110 @@
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Bool;
+
+/// An array specific filter operation
+#[derive(Debug, Clone)]
+pub enum ArrayOp<T> {
+    /// The column contains all given elements
+    Contains(Vec<T>),
+    /// All elements of the column are contained in the given elements
+    ContainedBy(Vec<T>),
+    /// The column has at least one element in common with the given elements
+    Overlaps(Vec<T>),
+    /// Compares the number of elements of the column using the given
+    /// sql operator
+    Length(&'static str, i32),
+}
+
+/// Applies an array filter operation to a column
+///
+/// The operations are translated to the postgres array operators
+/// `@>`, `<@` and `&&`. The length of an array is `cardinality(column)`,
+/// so an empty array has the length 0.
+#[derive(Debug, Clone)]
+pub struct ArrayOperation<T, C> {
+    column: C,
+    op: ArrayOp<T>,
+}
+
+impl<T, C> ArrayOperation<T, C>
+where
+    C: Default,
+{
+    pub(super) fn new(op: ArrayOp<T>) -> Self {
+        Self {
+            column: C::default(),
+            op,
+        }
+    }
+}
+
+impl<T, C> Expression for ArrayOperation<T, C>
+where
+    C: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<T, C> NonAggregate for ArrayOperation<T, C> {}
+
+impl<T, C, QS> AppearsOnTable<QS> for ArrayOperation<T, C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<T, C, QS> SelectableExpression<QS> for ArrayOperation<T, C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<T, C> QueryId for ArrayOperation<T, C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T, C, DB> BuildFilter<DB> for ArrayOperation<T, C>
+where
+    DB: Backend,
+    C: Expression,
+    Self: QueryFragment<DB>,
+{
+    type Ret = Self;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        Some(self)
+    }
+}
+
+impl<T, C> QueryFragment<diesel::pg::Pg> for ArrayOperation<T, C>
+where
+    C: Expression + QueryFragment<diesel::pg::Pg>,
+    diesel::pg::Pg: diesel::sql_types::HasSqlType<C::SqlType>,
+    Vec<T>: diesel::serialize::ToSql<C::SqlType, diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        let (operator, elements) = match self.op {
+            ArrayOp::Contains(ref e) => (" @> ", e),
+            ArrayOp::ContainedBy(ref e) => (" <@ ", e),
+            ArrayOp::Overlaps(ref e) => (" && ", e),
+            ArrayOp::Length(operator, length) => {
+                pass.push_sql("cardinality(");
+                self.column.walk_ast(pass.reborrow())?;
+                pass.push_sql(") ");
+                pass.push_sql(operator);
+                pass.push_sql(" ");
+                return pass.push_bind_param::<diesel::sql_types::Integer, _>(&length);
+            }
+        };
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(operator);
+        pass.push_bind_param::<C::SqlType, _>(elements)
+    }
+}
@@ -2,6 +2,7 @@ use super::build_filter::BuildFilter;
 use super::collector::{AndCollector, FilterCollector};
 use super::filter_value::FilterValue;
 use super::inner_filter::InnerFilter;
+use super::validate_look_ahead_field;
 use crate::diesel_ext::BoxableFilter;
 use crate::juniper_ext::{NameBuilder, Nameable, FromLookAheadValue};
 use crate::scalar::WundergraphScalarValue;
@@ -12,6 +13,7 @@ use diesel::query_builder::QueryFragment;
 use diesel::serialize::ToSql;
 use diesel::sql_types::{Bool, HasSqlType, SingleValue};
 use diesel::Column;
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::{Argument, MetaType};
 use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
@@ -128,6 +130,16 @@ where
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<V::RawValue>(path, "eq", obj)?;
+        validate_look_ahead_field::<V::RawValue>(path, "not_eq", obj)?;
+        validate_look_ahead_field::<Vec<V::RawValue>>(path, "eq_any", obj)?;
+        V::AdditionalFilter::validate_inner_look_ahead(path, obj)
+    }
+
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         map.insert("eq", self.eq.to_input_value());
         map.insert("not_eq", self.neq.to_input_value());
@@ -0,0 +1,140 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, Column};
+use failure::Error;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use std::marker::PhantomData;
+
+mod operation;
+
+use self::operation::ComparisonOperation;
+
+/// The graphql field names of the supported comparisons and the
+/// corresponding sql operators
+const COMPARISONS: [(&str, &str); 4] = [("gt", ">"), ("ge", ">="), ("lt", "<"), ("le", "<=")];
+
+/// Additional filter operations for ordered values
+///
+/// Provides `gt`, `ge`, `lt` and `le`, that compare the column with
+/// the given value. All given comparisons need to match.
+#[derive(Debug)]
+pub struct ComparisonFilter<V, C> {
+    values: [Option<V>; 4],
+    p: PhantomData<C>,
+}
+
+impl<V, C> Clone for ComparisonFilter<V, C>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<V, C> Nameable for ComparisonFilter<V, C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<V, C, DB> BuildFilter<DB> for ComparisonFilter<V, C>
+where
+    DB: Backend + 'static,
+    C: Column + Default + 'static,
+    C::Table: 'static,
+    V: 'static,
+    ComparisonOperation<V, C>: AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let mut combinator = AndCollector::default();
+        let [gt, ge, lt, le] = self.values;
+        for (value, (_, operator)) in vec![gt, ge, lt, le].into_iter().zip(COMPARISONS.iter()) {
+            combinator.append_filter(value.map(|v| ComparisonOperation::<V, C>::new(*operator, v)));
+        }
+        combinator.into_filter()
+    }
+}
+
+impl<V, C> InnerFilter for ComparisonFilter<V, C>
+where
+    V: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 4;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let mut values = [None, None, None, None];
+        for (value, (name, _)) in values.iter_mut().zip(COMPARISONS.iter()) {
+            *value = obj.get(name).map_or_else(
+                || Option::from_input_value(&InputValue::Null),
+                |v| Option::from_input_value(*v),
+            )?;
+        }
+        Some(Self {
+            values,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let mut values = [None, None, None, None];
+        for (value, (name, _)) in values.iter_mut().zip(COMPARISONS.iter()) {
+            *value = obj
+                .iter()
+                .find(|o| o.0 == *name)
+                .and_then(|o| V::from_look_ahead(&o.1));
+        }
+        Self {
+            values,
+            p: PhantomData,
+        }
+    }
+
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        for (name, _) in &COMPARISONS {
+            validate_look_ahead_field::<V>(path, name, obj)?;
+        }
+        Ok(())
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        for (value, (name, _)) in self.values.iter().zip(COMPARISONS.iter()) {
+            map.insert(*name, value.to_input_value());
+        }
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        COMPARISONS
+            .iter()
+            .map(|(name, _)| registry.arg_with_default::<Option<V>>(name, &None, &()))
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{Bool, HasSqlType};
+
+/// Compares a column with a value using the given sql operator
+#[derive(Debug, Clone)]
+pub struct ComparisonOperation<T, C> {
+    column: C,
+    operator: &'static str,
+    value: T,
+}
+
+impl<T, C> ComparisonOperation<T, C>
+where
+    C: Default,
+{
+    pub(super) fn new(operator: &'static str, value: T) -> Self {
+        Self {
+            column: C::default(),
+            operator,
+            value,
+        }
+    }
+}
+
+impl<T, C> Expression for ComparisonOperation<T, C>
+where
+    C: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<T, C> NonAggregate for ComparisonOperation<T, C> {}
+
+impl<T, C, QS> AppearsOnTable<QS> for ComparisonOperation<T, C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<T, C, QS> SelectableExpression<QS> for ComparisonOperation<T, C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<T, C> QueryId for ComparisonOperation<T, C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T, C, DB> BuildFilter<DB> for ComparisonOperation<T, C>
+where
+    DB: Backend,
+    C: Expression,
+    Self: QueryFragment<DB>,
+{
+    type Ret = Self;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        Some(self)
+    }
+}
+
+impl<T, C, DB> QueryFragment<DB> for ComparisonOperation<T, C>
+where
+    DB: Backend + HasSqlType<C::SqlType>,
+    C: Expression + QueryFragment<DB>,
+    T: ToSql<C::SqlType, DB>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(" ");
+        pass.push_sql(self.operator);
+        pass.push_sql(" ");
+        pass.push_bind_param::<C::SqlType, _>(&self.value)
+    }
+}
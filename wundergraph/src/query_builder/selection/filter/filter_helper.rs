@@ -4,7 +4,7 @@ use super::filter_value::FilterValue;
 use super::inner_filter::InnerFilter;
 use super::nullable_filter::IsNull;
 use super::reference_filter::ReferenceFilter;
-use super::Filter;
+use super::{validate_look_ahead_filter_field, Filter};
 use crate::diesel_ext::BoxableFilter;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::query_builder::selection::fields::WundergraphBelongsTo;
@@ -22,6 +22,7 @@ use diesel::sql_types::Bool;
 use diesel::Expression;
 use diesel::QuerySource;
 use diesel::Table;
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
@@ -224,6 +225,10 @@ where
     fn into_filter(f: F) -> Option<Self::Ret>;
 
     fn from_inner_look_ahead(objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> F;
+    fn validate_inner_look_ahead(
+        path: &str,
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error>;
     fn from_inner_input_value(
         obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
     ) -> Option<F>;
@@ -301,6 +306,13 @@ where
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        L::Table::validate_inner_look_ahead(path, objs)
+    }
+
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         L::Table::to_inner_input_value(&self.filter, v)
     }
@@ -345,7 +357,7 @@ macro_rules! __impl_build_filter_for_tuples {
                 Loading: LoadingHandler<Back, Ctx>,
                 <Loading::Table as QuerySource>::FromClause: QueryFragment<Back>,
                 Back::QueryBuilder: Default,
-                $($T: GraphQLType<WundergraphScalarValue, TypeInfo = NameBuilder<$T>> + ToInputValue<WundergraphScalarValue> + FromInputValue<WundergraphScalarValue> + Nameable + FromLookAheadValue,)*
+                $($T: GraphQLType<WundergraphScalarValue, TypeInfo = NameBuilder<$T>> + ToInputValue<WundergraphScalarValue> + FromInputValue<WundergraphScalarValue> + Nameable + FromLookAheadValue + InnerFilter,)*
             {
                 type Context = ();
 
@@ -385,6 +397,20 @@ macro_rules! __impl_build_filter_for_tuples {
                     FilterBuildHelper(values, PhantomData)
                 }
 
+                fn validate_inner_look_ahead(
+                    path: &str,
+                    objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]
+                ) -> Result<(), Error> {
+                    $(
+                        validate_look_ahead_filter_field::<$T>(
+                            path,
+                            Loading::FIELD_NAMES[$idx],
+                            objs,
+                        )?;
+                    )*
+                    Ok(())
+                }
+
                 fn to_inner_input_value(
                     &self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>
                 ) {
@@ -0,0 +1,45 @@
+use crate::diesel_ext::{BoxableFilter, FullTextMatch};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, Column};
+use juniper::{InputValue, ToInputValue};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct Matches<C>(Option<String>, PhantomData<C>);
+
+impl<C> Matches<C> {
+    pub(super) fn new(v: Option<String>) -> Self {
+        Self(v, PhantomData)
+    }
+}
+
+impl<C> Clone for Matches<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for Matches<C>
+where
+    C: Column + Default + 'static,
+    C::Table: 'static,
+    DB: Backend + 'static,
+    FullTextMatch<C>: AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let Self(query, _) = self;
+        query.map(|q| Box::new(FullTextMatch::new(C::default(), q)) as Box<_>)
+    }
+}
+
+impl<C> ToInputValue<WundergraphScalarValue> for Matches<C> {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.0.to_input_value()
+    }
+}
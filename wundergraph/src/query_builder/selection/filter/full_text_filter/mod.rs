@@ -0,0 +1,127 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::string_filter::Like;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::sql_types::Bool;
+use diesel::Column;
+use failure::Error;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
+
+mod matches;
+use self::matches::Matches;
+
+/// Additional filter operations for text columns with full text search
+///
+/// Besides `like` this provides a `matches` operation that accepts a plain
+/// text search query. See
+/// [`FullTextMatch`](../../../diesel_ext/struct.FullTextMatch.html)
+/// for the generated sql and the required database setup.
+#[derive(Debug)]
+pub struct FullTextFilter<C> {
+    like: Like<C>,
+    matches: Matches<C>,
+}
+
+impl<C> Clone for FullTextFilter<C> {
+    fn clone(&self) -> Self {
+        Self {
+            like: self.like.clone(),
+            matches: self.matches.clone(),
+        }
+    }
+}
+
+impl<C> Nameable for FullTextFilter<C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for FullTextFilter<C>
+where
+    DB: Backend + 'static,
+    C: Column + 'static,
+    C::Table: 'static,
+    Like<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+    Matches<C>: BuildFilter<DB, Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(self.like);
+        combinator.append_filter(self.matches);
+        combinator.into_filter()
+    }
+}
+
+impl<C> InnerFilter for FullTextFilter<C> {
+    type Context = ();
+
+    const FIELD_COUNT: usize = 2;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let like = Like::new(obj.get("like").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?);
+        let matches = Matches::new(obj.get("matches").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?);
+        Some(Self { like, matches })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let like = obj
+            .iter()
+            .find(|o| o.0 == "like")
+            .and_then(|o| String::from_look_ahead(&o.1));
+        let matches = obj
+            .iter()
+            .find(|o| o.0 == "matches")
+            .and_then(|o| String::from_look_ahead(&o.1));
+        Self {
+            like: Like::new(like),
+            matches: Matches::new(matches),
+        }
+    }
+
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<String>(path, "like", obj)?;
+        validate_look_ahead_field::<String>(path, "matches", obj)
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("like", self.like.to_input_value());
+        map.insert("matches", self.matches.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let like = registry.arg_with_default::<Option<String>>("like", &None, &Default::default());
+        let matches =
+            registry.arg_with_default::<Option<String>>("matches", &None, &Default::default());
+        vec![like, matches]
+    }
+}
@@ -1,5 +1,6 @@
 use crate::juniper_ext::{NameBuilder, Nameable};
 use crate::scalar::WundergraphScalarValue;
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{InputValue, LookAheadValue, Registry};
@@ -21,6 +22,17 @@ pub trait InnerFilter: Sized + Nameable {
     ) -> Option<Self>;
     /// Create the given filter from a graphql lookahead value
     fn from_inner_look_ahead(v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self;
+    /// Check that all fields of the given graphql lookahead value could be
+    /// decoded into this filter
+    ///
+    /// `from_inner_look_ahead` silently skips values that could not be
+    /// converted, so this should be called before building a filter from
+    /// user input. `path` denotes the position of the given value inside
+    /// of the graphql argument and is used to report the invalid field.
+    fn validate_inner_look_ahead(
+        path: &str,
+        v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error>;
     /// Covert the given filter into a graphql value
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>);
     /// Register all fields of the the filter in a given graphql schema
@@ -44,6 +56,12 @@ impl InnerFilter for () {
     }
 
     fn from_inner_look_ahead(_v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {}
+    fn validate_inner_look_ahead(
+        _path: &str,
+        _v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        Ok(())
+    }
     fn to_inner_input_value(&self, _v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {}
     fn register_fields<'r>(
         _info: &NameBuilder<Self>,
@@ -0,0 +1,188 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, Column};
+use failure::Error;
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
+use serde_json_internal::Value as JsonValue;
+use std::marker::PhantomData;
+
+mod operation;
+mod path_eq;
+
+use self::operation::{JsonOp, JsonOperation};
+use self::path_eq::JsonPathEq;
+
+/// Additional filter operations for json columns
+///
+/// * `contains` checks if the column contains the given json document,
+///   following the rules of the postgres `@>` operator
+/// * `has_key` checks if the given string is a key of an object, an
+///   element of an array or the string value of the column, following the
+///   rules of the postgres `?` operator
+/// * `has_any_keys` works like `has_key`, but checks if at least one of the
+///   given strings matches
+/// * `path_eq` compares the value at a path of the column with a json value
+///
+/// Json values are passed as json encoded strings, see the `JSON` scalar.
+#[derive(Debug)]
+pub struct JsonFilter<C> {
+    contains: Option<JsonValue>,
+    has_key: Option<String>,
+    has_any_keys: Option<Vec<String>>,
+    path_eq: Option<JsonPathEq>,
+    p: PhantomData<C>,
+}
+
+impl<C> Clone for JsonFilter<C> {
+    fn clone(&self) -> Self {
+        Self {
+            contains: self.contains.clone(),
+            has_key: self.has_key.clone(),
+            has_any_keys: self.has_any_keys.clone(),
+            path_eq: self.path_eq.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C> Nameable for JsonFilter<C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for JsonFilter<C>
+where
+    DB: Backend + 'static,
+    C: Column + Default + 'static,
+    C::Table: 'static,
+    JsonOperation<C>: AppearsOnTable<C::Table, SqlType = Bool> + QueryFragment<DB>,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, DB, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let mut combinator = AndCollector::default();
+        combinator.append_filter(
+            self.contains
+                .map(|v| JsonOperation::<C>::new(JsonOp::Contains(v))),
+        );
+        combinator.append_filter(
+            self.has_key
+                .map(|k| JsonOperation::<C>::new(JsonOp::HasKey(k))),
+        );
+        combinator.append_filter(
+            self.has_any_keys
+                .map(|k| JsonOperation::<C>::new(JsonOp::HasAnyKeys(k))),
+        );
+        combinator.append_filter(
+            self.path_eq
+                .map(|p| JsonOperation::<C>::new(JsonOp::PathEq(p.path, p.value))),
+        );
+        combinator.into_filter()
+    }
+}
+
+impl<C> InnerFilter for JsonFilter<C> {
+    type Context = ();
+
+    const FIELD_COUNT: usize = 4;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let contains = obj.get("contains").map_or_else(
+            || Option::from_input_value(&InputValue::<WundergraphScalarValue>::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let has_key = obj.get("has_key").map_or_else(
+            || Option::from_input_value(&InputValue::<WundergraphScalarValue>::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let has_any_keys = obj.get("has_any_keys").map_or_else(
+            || Option::from_input_value(&InputValue::<WundergraphScalarValue>::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let path_eq = obj.get("path_eq").map_or_else(
+            || Option::from_input_value(&InputValue::<WundergraphScalarValue>::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            contains,
+            has_key,
+            has_any_keys,
+            path_eq,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
+        let contains = obj
+            .iter()
+            .find(|o| o.0 == "contains")
+            .and_then(|o| JsonValue::from_look_ahead(&o.1));
+        let has_key = obj
+            .iter()
+            .find(|o| o.0 == "has_key")
+            .and_then(|o| String::from_look_ahead(&o.1));
+        let has_any_keys = obj
+            .iter()
+            .find(|o| o.0 == "has_any_keys")
+            .and_then(|o| Vec::from_look_ahead(&o.1));
+        let path_eq = obj
+            .iter()
+            .find(|o| o.0 == "path_eq")
+            .and_then(|o| JsonPathEq::from_look_ahead(&o.1));
+        Self {
+            contains,
+            has_key,
+            has_any_keys,
+            path_eq,
+            p: PhantomData,
+        }
+    }
+
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<JsonValue>(path, "contains", obj)?;
+        validate_look_ahead_field::<String>(path, "has_key", obj)?;
+        validate_look_ahead_field::<Vec<String>>(path, "has_any_keys", obj)?;
+        validate_look_ahead_field::<JsonPathEq>(path, "path_eq", obj)
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("contains", self.contains.to_input_value());
+        map.insert("has_key", self.has_key.to_input_value());
+        map.insert("has_any_keys", self.has_any_keys.to_input_value());
+        map.insert("path_eq", self.path_eq.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let contains =
+            registry.arg_with_default::<Option<JsonValue>>("contains", &None, &Default::default());
+        let has_key =
+            registry.arg_with_default::<Option<String>>("has_key", &None, &Default::default());
+        let has_any_keys = registry.arg_with_default::<Option<Vec<String>>>(
+            "has_any_keys",
+            &None,
+            &Default::default(),
+        );
+        let path_eq =
+            registry.arg_with_default::<Option<JsonPathEq>>("path_eq", &None, &Default::default());
+        vec![contains, has_key, has_any_keys, path_eq]
+    }
+}
@@ -0,0 +1,388 @@
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Bool;
+use serde_json_internal::Value as JsonValue;
+
+/// A json specific filter operation
+#[derive(Debug, Clone)]
+pub enum JsonOp {
+    /// The column contains the given json document
+    Contains(JsonValue),
+    /// The given string is a key of the column, an element of the column
+    /// or the column itself
+    HasKey(String),
+    /// At least one of the given strings is a key of the column, an
+    /// element of the column or the column itself
+    HasAnyKeys(Vec<String>),
+    /// The value at the given path of the column equals the given json value
+    PathEq(Vec<String>, JsonValue),
+}
+
+/// Applies a json filter operation to a column
+///
+/// On postgres the column is cast to `jsonb` and the operation is
+/// translated to the corresponding `jsonb` operator (`@>`, `?`, `?|`, `#>`).
+/// On sqlite the column has to contain json text, the operations are
+/// emulated using the json1 functions (`json_type`, `json_extract` and
+/// `json_each`).
+#[derive(Debug, Clone)]
+pub struct JsonOperation<C> {
+    column: C,
+    op: JsonOp,
+}
+
+impl<C> JsonOperation<C>
+where
+    C: Default,
+{
+    pub(super) fn new(op: JsonOp) -> Self {
+        Self {
+            column: C::default(),
+            op,
+        }
+    }
+}
+
+impl<C> Expression for JsonOperation<C>
+where
+    C: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<C> NonAggregate for JsonOperation<C> {}
+
+impl<C, QS> AppearsOnTable<QS> for JsonOperation<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<C, QS> SelectableExpression<QS> for JsonOperation<C>
+where
+    C: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<C> QueryId for JsonOperation<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C, DB> BuildFilter<DB> for JsonOperation<C>
+where
+    DB: Backend,
+    C: Expression,
+    Self: QueryFragment<DB>,
+{
+    type Ret = Self;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<diesel::pg::Pg> for JsonOperation<C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        use diesel::sql_types::{Array, Jsonb, Text};
+
+        pass.push_sql("(CAST(");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(" AS jsonb)");
+        match self.op {
+            JsonOp::Contains(ref v) => {
+                pass.push_sql(" @> ");
+                pass.push_bind_param::<Jsonb, _>(v)?;
+            }
+            JsonOp::HasKey(ref k) => {
+                pass.push_sql(" ? ");
+                pass.push_bind_param::<Text, _>(k)?;
+            }
+            JsonOp::HasAnyKeys(ref k) => {
+                pass.push_sql(" ?| ");
+                pass.push_bind_param::<Array<Text>, _>(k)?;
+            }
+            JsonOp::PathEq(ref p, ref v) => {
+                pass.push_sql(" #> ");
+                pass.push_bind_param::<Array<Text>, _>(p)?;
+                pass.push_sql(" = ");
+                pass.push_bind_param::<Jsonb, _>(v)?;
+            }
+        }
+        pass.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C> QueryFragment<diesel::sqlite::Sqlite> for JsonOperation<C>
+where
+    C: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        use self::sqlite::Target;
+
+        match self.op {
+            JsonOp::Contains(ref v) => Target::Column(&self.column).contains(v, pass),
+            JsonOp::HasKey(ref k) => sqlite::has_key(&self.column, k, pass),
+            JsonOp::HasAnyKeys(ref keys) if keys.is_empty() => {
+                pass.push_sql("0");
+                Ok(())
+            }
+            JsonOp::HasAnyKeys(ref keys) => {
+                pass.push_sql("(");
+                for (idx, k) in keys.iter().enumerate() {
+                    if idx != 0 {
+                        pass.push_sql(" OR ");
+                    }
+                    sqlite::has_key(&self.column, k, pass.reborrow())?;
+                }
+                pass.push_sql(")");
+                Ok(())
+            }
+            JsonOp::PathEq(ref p, ref v) => sqlite::path_eq(&self.column, p, v, pass),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use diesel::query_builder::{AstPass, QueryFragment};
+    use diesel::result::QueryResult;
+    use diesel::sql_types::{BigInt, Double, Text};
+    use diesel::sqlite::Sqlite;
+    use serde_json_internal::Value as JsonValue;
+
+    // Keys are always compared with the `key` column of `json_each` instead
+    // of being part of a json path, because quotes inside of a path are
+    // not supported by all sqlite versions.
+
+    /// Works like the postgres `?` operator, so `key` is either a key of
+    /// an object, a string element of an array or a string itself
+    pub(super) fn has_key<C>(
+        column: &C,
+        key: &str,
+        mut pass: AstPass<'_, Sqlite>,
+    ) -> QueryResult<()>
+    where
+        C: QueryFragment<Sqlite>,
+    {
+        // Only the rows of an object have a text key
+        pass.push_sql("EXISTS (SELECT 1 FROM json_each(");
+        column.walk_ast(pass.reborrow())?;
+        pass.push_sql(") AS j0 WHERE (typeof(j0.key) = 'text' AND j0.key = ");
+        pass.push_bind_param::<Text, _>(&key)?;
+        pass.push_sql(") OR (typeof(j0.key) != 'text' AND j0.type = 'text' AND j0.value = ");
+        pass.push_bind_param::<Text, _>(&key)?;
+        pass.push_sql("))");
+        Ok(())
+    }
+
+    /// Works like comparing the result of the postgres `#>` operator, so
+    /// each element of `path` is either a key of an object or an index
+    /// of an array
+    pub(super) fn path_eq<C>(
+        column: &C,
+        path: &[String],
+        value: &JsonValue,
+        mut pass: AstPass<'_, Sqlite>,
+    ) -> QueryResult<()>
+    where
+        C: QueryFragment<Sqlite>,
+    {
+        if path.is_empty() {
+            return Target::Column(column).eq(value, pass);
+        }
+        pass.push_sql("EXISTS (SELECT 1 FROM ");
+        for depth in 0..path.len() {
+            if depth == 0 {
+                Target::Column(column).push_each(pass.reborrow())?;
+            } else {
+                pass.push_sql(", ");
+                Target::<C>::Each(depth - 1).push_each(pass.reborrow())?;
+            }
+            pass.push_sql(&format!(" AS j{}", depth));
+        }
+        pass.push_sql(" WHERE ");
+        for (depth, key) in path.iter().enumerate() {
+            // Array indices are integers, so the key is compared as text
+            pass.push_sql(&format!("CAST(j{}.key AS TEXT) = ", depth));
+            pass.push_bind_param::<Text, _>(key)?;
+            pass.push_sql(" AND ");
+        }
+        Target::<C>::Each(path.len() - 1).eq(value, pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+
+    /// A json value that is compared by a filter operation
+    pub(super) enum Target<'a, C> {
+        /// The json document stored in the column
+        Column(&'a C),
+        /// The row of the `json_each` table with the alias `j<depth>`
+        Each(usize),
+    }
+
+    impl<'a, C> Target<'a, C>
+    where
+        C: QueryFragment<Sqlite>,
+    {
+        fn depth(&self) -> usize {
+            match *self {
+                Target::Column(_) => 0,
+                Target::Each(depth) => depth + 1,
+            }
+        }
+
+        fn push_type(&self, mut pass: AstPass<'_, Sqlite>) -> QueryResult<()> {
+            match *self {
+                Target::Column(c) => {
+                    pass.push_sql("json_type(");
+                    c.walk_ast(pass.reborrow())?;
+                    pass.push_sql(")");
+                }
+                Target::Each(depth) => pass.push_sql(&format!("j{}.type", depth)),
+            }
+            Ok(())
+        }
+
+        fn push_value(&self, mut pass: AstPass<'_, Sqlite>) -> QueryResult<()> {
+            match *self {
+                Target::Column(c) => {
+                    pass.push_sql("json_extract(");
+                    c.walk_ast(pass.reborrow())?;
+                    pass.push_sql(", '$')");
+                }
+                Target::Each(depth) => pass.push_sql(&format!("j{}.value", depth)),
+            }
+            Ok(())
+        }
+
+        /// The `json_each` table of the elements of the target
+        ///
+        /// Values of `json_each` that are no array or object are plain
+        /// sql values, so they have no elements
+        fn push_each(&self, mut pass: AstPass<'_, Sqlite>) -> QueryResult<()> {
+            match *self {
+                Target::Column(c) => {
+                    pass.push_sql("json_each(");
+                    c.walk_ast(pass.reborrow())?;
+                    pass.push_sql(")");
+                }
+                Target::Each(depth) => pass.push_sql(&format!(
+                    "json_each(CASE WHEN j{0}.type IN ('object', 'array') THEN j{0}.value END)",
+                    depth
+                )),
+            }
+            Ok(())
+        }
+
+        fn push_type_is(&self, types: &str, mut pass: AstPass<'_, Sqlite>) -> QueryResult<()> {
+            self.push_type(pass.reborrow())?;
+            pass.push_sql(" IN (");
+            pass.push_sql(types);
+            pass.push_sql(")");
+            Ok(())
+        }
+
+        /// The target equals `value`
+        ///
+        /// Objects and arrays are compared by their minified json text,
+        /// so the order of the keys of an object matters
+        pub(super) fn eq(
+            &self,
+            value: &JsonValue,
+            mut pass: AstPass<'_, Sqlite>,
+        ) -> QueryResult<()> {
+            let types = match *value {
+                JsonValue::Null => "'null'",
+                JsonValue::Bool(true) => "'true'",
+                JsonValue::Bool(false) => "'false'",
+                JsonValue::Number(_) => "'integer', 'real'",
+                JsonValue::String(_) => "'text'",
+                JsonValue::Array(_) => "'array'",
+                JsonValue::Object(_) => "'object'",
+            };
+            pass.push_sql("(");
+            self.push_type_is(types, pass.reborrow())?;
+            match *value {
+                JsonValue::Null | JsonValue::Bool(_) => {}
+                JsonValue::Number(ref n) => {
+                    pass.push_sql(" AND ");
+                    self.push_value(pass.reborrow())?;
+                    pass.push_sql(" = ");
+                    match n.as_i64() {
+                        Some(i) => pass.push_bind_param::<BigInt, _>(&i)?,
+                        None => {
+                            pass.push_bind_param::<Double, _>(&n.as_f64().unwrap_or_default())?
+                        }
+                    }
+                }
+                JsonValue::String(ref s) => {
+                    pass.push_sql(" AND ");
+                    self.push_value(pass.reborrow())?;
+                    pass.push_sql(" = ");
+                    pass.push_bind_param::<Text, _>(s)?;
+                }
+                JsonValue::Array(_) | JsonValue::Object(_) => {
+                    pass.push_sql(" AND ");
+                    self.push_value(pass.reborrow())?;
+                    pass.push_sql(" = json(");
+                    pass.push_bind_param::<Text, _>(&value.to_string())?;
+                    pass.push_sql(")");
+                }
+            }
+            pass.push_sql(")");
+            Ok(())
+        }
+
+        /// The target contains `value`, following the rules of the
+        /// postgres `@>` operator for `jsonb`
+        pub(super) fn contains(
+            &self,
+            value: &JsonValue,
+            mut pass: AstPass<'_, Sqlite>,
+        ) -> QueryResult<()> {
+            let depth = self.depth();
+            match *value {
+                JsonValue::Object(ref o) => {
+                    pass.push_sql("(");
+                    self.push_type_is("'object'", pass.reborrow())?;
+                    for (k, v) in o {
+                        pass.push_sql(" AND EXISTS (SELECT 1 FROM ");
+                        self.push_each(pass.reborrow())?;
+                        pass.push_sql(&format!(" AS j{0} WHERE j{0}.key = ", depth));
+                        pass.push_bind_param::<Text, _>(k)?;
+                        pass.push_sql(" AND ");
+                        Target::<C>::Each(depth).contains(v, pass.reborrow())?;
+                        pass.push_sql(")");
+                    }
+                    pass.push_sql(")");
+                }
+                JsonValue::Array(ref a) => {
+                    pass.push_sql("(");
+                    self.push_type_is("'array'", pass.reborrow())?;
+                    for v in a {
+                        pass.push_sql(" AND EXISTS (SELECT 1 FROM ");
+                        self.push_each(pass.reborrow())?;
+                        pass.push_sql(&format!(" AS j{} WHERE ", depth));
+                        Target::<C>::Each(depth).contains(v, pass.reborrow())?;
+                        pass.push_sql(")");
+                    }
+                    pass.push_sql(")");
+                }
+                _ => self.eq(value, pass.reborrow())?,
+            }
+            Ok(())
+        }
+    }
+}
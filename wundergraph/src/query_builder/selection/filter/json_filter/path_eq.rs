@@ -0,0 +1,79 @@
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::WundergraphScalarValue;
+use indexmap::IndexMap;
+use juniper::meta::MetaType;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use serde_json_internal::Value as JsonValue;
+
+/// Input of the `path_eq` operation
+///
+/// Compares the value at `path` with `value`. The path is a list of object
+/// keys and array indices. A missing `value` compares with json `null`.
+#[derive(Debug, Clone)]
+pub struct JsonPathEq {
+    pub(super) path: Vec<String>,
+    pub(super) value: JsonValue,
+}
+
+impl GraphQLType<WundergraphScalarValue> for JsonPathEq {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("JsonPathEq")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[
+            registry.arg::<Vec<String>>("path", &()),
+            registry.arg_with_default::<Option<JsonValue>>("value", &None, &()),
+        ];
+        registry
+            .build_input_object_type::<Self>(info, fields)
+            .into_meta()
+    }
+}
+
+impl FromInputValue<WundergraphScalarValue> for JsonPathEq {
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        let obj = v.to_object_value()?;
+        let path = Vec::from_input_value(obj.get("path")?)?;
+        let value = obj
+            .get("value")
+            .map_or(Some(JsonValue::Null), |v| JsonValue::from_input_value(*v))?;
+        Some(Self { path, value })
+    }
+}
+
+impl ToInputValue<WundergraphScalarValue> for JsonPathEq {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        let mut map = IndexMap::with_capacity(2);
+        map.insert("path", self.path.to_input_value());
+        map.insert("value", self.value.to_input_value());
+        InputValue::object(map)
+    }
+}
+
+impl FromLookAheadValue for JsonPathEq {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let path = obj
+                .iter()
+                .find(|o| o.0 == "path")
+                .and_then(|o| Vec::from_look_ahead(&o.1))?;
+            let value = obj
+                .iter()
+                .find(|o| o.0 == "value")
+                .map_or(Some(JsonValue::Null), |o| JsonValue::from_look_ahead(&o.1))?;
+            Some(Self { path, value })
+        } else {
+            None
+        }
+    }
+}
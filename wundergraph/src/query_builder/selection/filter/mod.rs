@@ -2,6 +2,7 @@
 //! filter entities. The main entry point is the [`Filter`](struct.Filter.html) struct
 
 use crate::diesel_ext::BoxableFilter;
+use crate::error::WundergraphError;
 use crate::juniper_ext::{NameBuilder, Nameable, FromLookAheadValue};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -10,6 +11,7 @@ use diesel::sql_types::Bool;
 use diesel::AppearsOnTable;
 use diesel::QueryDsl;
 use diesel::Table;
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::{Argument, MetaType};
 use juniper::FromInputValue;
@@ -20,12 +22,18 @@ use juniper::Registry;
 use juniper::ToInputValue;
 use std::marker::PhantomData;
 
+#[cfg(feature = "postgres")]
+mod array_filter;
 pub mod build_filter;
 pub mod collector;
 mod common_filter;
+mod comparison_filter;
 pub mod filter_helper;
 pub mod filter_value;
+mod full_text_filter;
 pub mod inner_filter;
+#[cfg(feature = "serde_json")]
+mod json_filter;
 mod not;
 mod nullable_filter;
 mod reference_filter;
@@ -35,10 +43,129 @@ use self::build_filter::BuildFilter;
 use self::collector::{AndCollector, FilterCollector, OrCollector};
 use self::inner_filter::InnerFilter;
 
+#[cfg(feature = "postgres")]
+pub use self::array_filter::ArrayFilter;
 pub use self::common_filter::FilterOption;
+pub use self::comparison_filter::ComparisonFilter;
+pub use self::full_text_filter::FullTextFilter;
+#[cfg(feature = "serde_json")]
+pub use self::json_filter::JsonFilter;
 pub use self::not::Not;
 pub use self::reference_filter::ReferenceFilter;
 
+/// Construct the error returned for an invalid part of a graphql argument
+pub(crate) fn invalid_argument(path: impl Into<String>, reason: impl Into<String>) -> Error {
+    Error::from(WundergraphError::CouldNotBuildFilterArgument {
+        path: path.into(),
+        reason: reason.into(),
+    })
+}
+
+/// Check that the field `name` of a graphql lookahead object could be
+/// decoded as `T`
+///
+/// Missing fields and explicit `null` values are accepted
+pub(crate) fn validate_look_ahead_field<T>(
+    path: &str,
+    name: &str,
+    objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+) -> Result<(), Error>
+where
+    T: FromLookAheadValue,
+{
+    match objs.iter().find(|o| o.0 == name) {
+        None | Some((_, LookAheadValue::Null)) => Ok(()),
+        Some((_, v)) if T::from_look_ahead(v).is_some() => Ok(()),
+        Some(_) => Err(invalid_argument(
+            format!("{}.{}", path, name),
+            "value does not match the type of the field",
+        )),
+    }
+}
+
+/// Check that a graphql lookahead value could be decoded as the input
+/// object represented by the filter `T`
+///
+/// `null` values are accepted
+pub(crate) fn validate_look_ahead_object<T>(
+    path: &str,
+    value: &LookAheadValue<'_, WundergraphScalarValue>,
+) -> Result<(), Error>
+where
+    T: InnerFilter,
+{
+    match *value {
+        LookAheadValue::Null => Ok(()),
+        LookAheadValue::Object(ref obj) => T::validate_inner_look_ahead(path, obj),
+        _ => Err(invalid_argument(path, "expected an input object")),
+    }
+}
+
+/// Check that the field `name` of a graphql lookahead object could be
+/// decoded as the filter `T`, including all nested filters
+///
+/// Missing fields and explicit `null` values are accepted. Used by
+/// `#[derive(WundergraphFilter)]`.
+#[doc(hidden)]
+pub fn validate_look_ahead_filter_field<T>(
+    path: &str,
+    name: &str,
+    objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+) -> Result<(), Error>
+where
+    T: InnerFilter + FromLookAheadValue,
+{
+    match objs.iter().find(|o| o.0 == name) {
+        None | Some((_, LookAheadValue::Null)) => Ok(()),
+        Some((_, v)) => {
+            let path = format!("{}.{}", path, name);
+            validate_look_ahead_object::<T>(&path, v)?;
+            if T::from_look_ahead(v).is_some() {
+                Ok(())
+            } else {
+                Err(invalid_argument(path, "value does not match the filter type"))
+            }
+        }
+    }
+}
+
+/// Prefix the path reported by an invalid argument error with the name
+/// of the graphql field the argument belongs to
+///
+/// Other errors are returned unchanged
+pub(crate) fn with_field_path(field: &str, e: Error) -> Error {
+    match e.downcast::<WundergraphError>() {
+        Ok(WundergraphError::CouldNotBuildFilterArgument { path, reason }) => {
+            invalid_argument(format!("{}.{}", field, path), reason)
+        }
+        Ok(e) => e.into(),
+        Err(e) => e,
+    }
+}
+
+/// Check that a graphql lookahead value could be decoded as list of
+/// input objects represented by the filter `T`
+///
+/// `null` values are accepted
+pub(crate) fn validate_look_ahead_object_list<T>(
+    path: &str,
+    value: &LookAheadValue<'_, WundergraphScalarValue>,
+) -> Result<(), Error>
+where
+    T: InnerFilter,
+{
+    match *value {
+        LookAheadValue::Null => Ok(()),
+        LookAheadValue::List(ref list) => {
+            for (idx, v) in list.iter().enumerate() {
+                validate_look_ahead_object::<T>(&format!("{}[{}]", path, idx), v)?;
+            }
+            Ok(())
+        }
+        _ => Err(invalid_argument(path, "expected a list of input objects")),
+    }
+}
+
 /// Main filter struct
 ///
 /// This struct is the main entry point to wundergraphs filter api
@@ -263,6 +390,22 @@ where
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        for (name, value) in objs {
+            match *name {
+                "and" | "or" => {
+                    validate_look_ahead_object_list::<Self>(&format!("{}.{}", path, name), value)?
+                }
+                "not" => validate_look_ahead_object::<Self>(&format!("{}.not", path), value)?,
+                _ => {}
+            }
+        }
+        F::validate_inner_look_ahead(path, objs)
+    }
+
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         map.insert("and", self.and.to_input_value());
         map.insert("or", self.or.to_input_value());
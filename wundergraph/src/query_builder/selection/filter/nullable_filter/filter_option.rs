@@ -4,12 +4,14 @@ use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
 use crate::query_builder::selection::filter::filter_value::FilterValue;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::expression::{AsExpression, NonAggregate};
 use diesel::query_builder::QueryFragment;
 use diesel::sql_types::{Bool, SingleValue};
 use diesel::{AppearsOnTable, Column};
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{FromInputValue, InputValue, LookAheadValue, Registry};
@@ -112,6 +114,14 @@ where
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<bool>(path, "is_null", obj)?;
+        V::AdditionalFilter::validate_inner_look_ahead(path, obj)
+    }
+
     fn to_inner_input_value(&self, _v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {}
 
     fn register_fields<'r>(
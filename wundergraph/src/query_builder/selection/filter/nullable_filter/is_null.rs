@@ -2,12 +2,14 @@ use crate::diesel_ext::BoxableFilter;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::expression::{operators, NonAggregate};
 use diesel::query_builder::QueryFragment;
 use diesel::sql_types::Bool;
 use diesel::{AppearsOnTable, Column, ExpressionMethods};
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
@@ -83,6 +85,13 @@ impl<C> InnerFilter for Option<IsNull<C>> {
             .map(IsNull::new)
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<bool>(path, "is_null", obj)
+    }
+
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         v.insert("is_null", self.to_input_value());
     }
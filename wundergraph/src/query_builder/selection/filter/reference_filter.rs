@@ -14,6 +14,7 @@ use diesel::query_builder::{AsQuery, BoxedSelectStatement, Query, QueryFragment}
 use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, SelectDsl, SelectNullableDsl};
 use diesel::sql_types::{Bool, SingleValue};
 use diesel::{AppearsOnTable, Column, ExpressionMethods, NullableExpressionMethods, QueryDsl};
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::{Argument, MetaType};
 use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
@@ -220,6 +221,14 @@ where
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        I::validate_inner_look_ahead(path, obj)?;
+        A::validate_inner_look_ahead(path, obj)
+    }
+
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         self.inner.to_inner_input_value(map);
         self.additional.to_inner_input_value(map);
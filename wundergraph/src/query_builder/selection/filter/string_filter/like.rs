@@ -14,7 +14,7 @@ use std::marker::PhantomData;
 pub struct Like<C>(Option<String>, ::std::marker::PhantomData<C>);
 
 impl<C> Like<C> {
-    pub(super) fn new(v: Option<String>) -> Self {
+    pub(crate) fn new(v: Option<String>) -> Self {
         Self(v, PhantomData)
     }
 }
@@ -2,16 +2,18 @@ use crate::diesel_ext::BoxableFilter;
 use crate::juniper_ext::{NameBuilder, Nameable, FromLookAheadValue};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::validate_look_ahead_field;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::sql_types::Bool;
 use diesel::Column;
+use failure::Error;
 use indexmap::IndexMap;
 use juniper::meta::Argument;
 use juniper::{FromInputValue, InputValue, LookAheadValue, Registry, ToInputValue};
 
 mod like;
-use self::like::Like;
+pub(super) use self::like::Like;
 
 #[derive(Debug)]
 pub struct StringFilter<C> {
@@ -73,6 +75,13 @@ impl<C> InnerFilter for StringFilter<C> {
         }
     }
 
+    fn validate_inner_look_ahead(
+        path: &str,
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<(), Error> {
+        validate_look_ahead_field::<String>(path, "like", obj)
+    }
+
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
         map.insert("like", self.like.to_input_value());
     }
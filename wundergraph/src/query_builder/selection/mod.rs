@@ -1,11 +1,15 @@
 use crate::context::WundergraphContext;
 use crate::diesel_ext::BoxableFilter;
 use crate::error::WundergraphError;
-use crate::helper::primary_keys::{PrimaryKeyArgument, UnRef};
+use crate::helper::primary_keys::{
+    PrimaryKeyArgument, PrimaryKeyInputObject, PrimaryKeysFilter, UnRef,
+};
+use crate::instrumentation::{instrument, Explain};
 use crate::juniper_ext::FromLookAheadValue;
 use crate::query_builder::selection::order::BuildOrder;
 use crate::query_builder::selection::query_modifier::QueryModifier;
 use crate::query_builder::selection::select::BuildSelect;
+use crate::query_builder::selection::version::VersionIncrement;
 use crate::helper::tuple::IsPrimaryKeyIndex;
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
@@ -23,22 +27,29 @@ use diesel::Identifiable;
 use diesel::QuerySource;
 use diesel::{AppearsOnTable, Connection, QueryDsl, Table};
 use failure::Error;
+use juniper::meta::Field;
 use juniper::LookAheadValue;
-use juniper::{Executor, LookAheadSelection, Selection};
+use juniper::{Executor, InputValue, LookAheadSelection, Registry, Selection};
+use std::collections::HashMap;
+use std::hash::Hash;
 
 pub mod fields;
 pub mod filter;
+pub mod node;
 pub mod offset;
 pub mod order;
 pub mod query_modifier;
 pub mod select;
 pub mod query_resolver;
+pub mod soft_delete;
+pub mod unique;
+pub mod version;
 
 use self::fields::WundergraphFieldList;
 use self::filter::build_filter::BuildFilter;
 use self::filter::inner_filter::InnerFilter;
-use self::filter::Filter;
-use self::offset::ApplyOffset;
+use self::filter::{invalid_argument, with_field_path, Filter};
+use self::offset::{get_pagination_argument, ApplyOffset};
 
 #[doc(inline)]
 pub use self::query_resolver::SqlTypeOfPlaceholder;
@@ -73,6 +84,23 @@ where
 
     const FIELD_NAMES: &'static [&'static str];
     const TYPE_NAME: &'static str;
+    /// Whether rows of this entity are only marked as deleted
+    ///
+    /// See the [`soft_delete`](soft_delete/index.html) module for details
+    const SOFT_DELETE: bool = false;
+    /// The graphql name of the version field, if this entity uses
+    /// optimistic concurrency control
+    ///
+    /// See the [`version`](version/index.html) module for details
+    const VERSION_FIELD: Option<&'static str> = None;
+    /// The suffixes of the lookup fields for the unique column sets of
+    /// this entity, like `BySlug`
+    ///
+    /// The graphql name of each lookup field is the suffix prefixed with
+    /// `TYPE_NAME`
+    ///
+    /// See the [`unique`](unique/index.html) module for details
+    const UNIQUE_LOOKUPS: &'static [&'static str] = &[];
 
     fn load<'a>(
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
@@ -86,34 +114,116 @@ where
         >,
         Ctx: WundergraphContext + QueryModifier<Self, DB>,
         Ctx::Connection: Connection<Backend = DB>,
+        DB: Explain,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        let (_, values) = Self::load_with_keys(select, selection, executor, query)?;
+        Ok(values)
+    }
+
+    /// Load rows like `load`, additionally returning the primary key
+    /// of each row as input object like it is accepted by the
+    /// `primaryKey` argument
+    fn load_with_keys<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<
+        (
+            Vec<Option<InputValue<WundergraphScalarValue>>>,
+            Vec<juniper::Value<WundergraphScalarValue>>,
+        ),
+        Error,
+    >
+    where
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: Explain,
         DB::QueryBuilder: Default,
         <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
     {
         use diesel::RunQueryDsl;
         let ctx = executor.context();
-        let conn = ctx.get_connection();
+        let conn = ctx.get_read_connection();
         let query = ctx.modify_query(select, query)?;
-        if cfg!(feature = "debug") {
-            #[allow(clippy::use_debug, clippy::print_stdout)]
-            {
-                println!("{:?}", diesel::debug_query(&query));
-            }
+        let placeholder = instrument(
+            ctx,
+            conn,
+            Self::TYPE_NAME,
+            query,
+            |query, conn| <_ as RunQueryDsl<_>>::load(query, conn),
+            Vec::len,
+        )?;
+        Self::resolve_loaded(placeholder, select, selection, executor)
+    }
+
+    /// Resolve rows already loaded from the database, for example
+    /// by the `RETURNING` clause of a statement
+    ///
+    /// Returns the primary key of each row like `load_with_keys`
+    fn resolve_loaded(
+        placeholder: Vec<
+            <Self::FieldList as WundergraphFieldList<
+                DB,
+                Self::PrimaryKeyIndex,
+                Self::Table,
+                Ctx,
+            >>::PlaceHolder,
+        >,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<
+        (
+            Vec<Option<InputValue<WundergraphScalarValue>>>,
+            Vec<juniper::Value<WundergraphScalarValue>>,
+        ),
+        Error,
+    >
+    where
+        Ctx: WundergraphContext,
+    {
+        use juniper::LookAheadMethods;
+        let keys = Self::primary_keys(&placeholder);
+        let names = Self::FIELD_NAMES
+            .iter()
+            .map(|name| node::output_field_name(name, Ctx::NODE_INTERFACE))
+            .collect::<Vec<_>>();
+        let mut values =
+            Self::FieldList::resolve(placeholder, select, selection, &names, executor)?;
+        if Ctx::NODE_INTERFACE && select.has_child(node::NODE_ID_FIELD) {
+            node::add_node_ids(Self::TYPE_NAME, keys.clone(), selection, &mut values)?;
         }
-        let placeholder = <_ as RunQueryDsl<_>>::load(query, conn)?;
-        Ok(Self::FieldList::resolve(
-            placeholder,
-            select,
-            selection,
-            Self::FIELD_NAMES,
-            executor,
-        )?)
+        Ok((keys, values))
+    }
+
+    /// The primary key of each loaded row as input object, like it is
+    /// accepted by the `primaryKey` argument
+    ///
+    /// Used to build the node ids of the
+    /// [`Node`](node/struct.Node.html) interface. Defaults to not
+    /// providing any key, `#[derive(WundergraphEntity)]` overrides it.
+    fn primary_keys(
+        placeholder: &[<Self::FieldList as WundergraphFieldList<
+            DB,
+            Self::PrimaryKeyIndex,
+            Self::Table,
+            Ctx,
+        >>::PlaceHolder],
+    ) -> Vec<Option<InputValue<WundergraphScalarValue>>> {
+        vec![None; placeholder.len()]
     }
 
     fn load_by_primary_key<'a>(
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
-        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
     ) -> Result<Option<juniper::Value<WundergraphScalarValue>>, Error>
     where
         Self: 'static,
@@ -131,6 +241,7 @@ where
         DB: HasSqlType<
             SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
         >,
+        DB: Explain,
         DB::QueryBuilder: Default,
         <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
     {
@@ -144,12 +255,159 @@ where
             <&'static Self as Identifiable>::Id,
             >::from_look_ahead(v.value())
             .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
-        query = <_ as QueryDsl>::filter(query, Self::table().primary_key().eq_all(key.values));
+        Self::load_by_key(select, selection, executor, query, key.values)
+    }
+
+    /// Load the row with the given primary key
+    fn load_by_key<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+        key: <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+    ) -> Result<Option<juniper::Value<WundergraphScalarValue>>, Error>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed>,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table> + NonAggregate + QueryFragment<DB>,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB: Explain,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        query = <_ as QueryDsl>::filter(query, Self::table().primary_key().eq_all(key));
         query = <_ as QueryDsl>::limit(query, 1);
         let res = Self::load(select, selection, executor, query)?;
         Ok(res.into_iter().next())
     }
 
+    fn load_by_primary_keys<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Vec<Option<juniper::Value<WundergraphScalarValue>>>, Error>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed: Hash + Eq,
+        <Self::Table as Table>::PrimaryKey: PrimaryKeysFilter<
+            Self::Table,
+            DB,
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >,
+        <Self::Table as Table>::PrimaryKey: PrimaryKeyInputObject<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+            (),
+        >,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB: Explain,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        use juniper::LookAheadMethods;
+        let v = select
+            .argument("ids")
+            .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+        let keys = Vec::<
+            PrimaryKeyArgument<'static, Self::Table, (), <&'static Self as Identifiable>::Id>,
+        >::from_look_ahead(v.value())
+        .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+        Self::load_by_keys(
+            select,
+            selection,
+            executor,
+            query,
+            keys.into_iter().map(|k| k.values).collect(),
+        )
+    }
+
+    /// Load the rows with the given primary keys using a single query
+    ///
+    /// Returns one entry for each key in the order of the given keys,
+    /// `None` for keys without a matching row
+    fn load_by_keys<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+        keys: Vec<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed>,
+    ) -> Result<Vec<Option<juniper::Value<WundergraphScalarValue>>>, Error>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed: Hash + Eq,
+        <Self::Table as Table>::PrimaryKey: PrimaryKeysFilter<
+            Self::Table,
+            DB,
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >,
+        <Self::Table as Table>::PrimaryKey: PrimaryKeyInputObject<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+            (),
+        >,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB: Explain,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        let key_values = keys
+            .iter()
+            .map(<Self::Table as Table>::PrimaryKey::to_input_value)
+            .collect::<Vec<_>>();
+        let filter = match Self::table().primary_key().keys_filter(keys) {
+            Some(filter) => filter,
+            None => return Ok(Vec::new()),
+        };
+        let query = <_ as QueryDsl>::filter(query, filter);
+        // Rows are matched to the given keys, even if the key is not selected
+        let query = <_ as SelectDsl<_>>::select(query, Self::get_select_with_primary_key(select)?);
+        let (row_keys, values) = Self::load_with_keys(select, selection, executor, query)?;
+        // The keys are moved into the filter, so the positions are indexed
+        // by the keys read back from their input values. The same key
+        // could be requested multiple times.
+        let mut positions = HashMap::<_, Vec<usize>>::with_capacity(key_values.len());
+        for (pos, key) in key_values.iter().enumerate() {
+            if let Some(key) = <Self::Table as Table>::PrimaryKey::from_input_value(key) {
+                positions.entry(key).or_default().push(pos);
+            }
+        }
+        let mut items = vec![None; key_values.len()];
+        for (row_key, value) in row_keys.into_iter().zip(values) {
+            let row_key = row_key
+                .as_ref()
+                .and_then(<Self::Table as Table>::PrimaryKey::from_input_value);
+            if let Some(positions) = row_key.and_then(|key| positions.remove(&key)) {
+                if let Some((last, others)) = positions.split_last() {
+                    for pos in others {
+                        items[*pos] = Some(value.clone());
+                    }
+                    items[*last] = Some(value);
+                }
+            }
+        }
+        Ok(items)
+    }
+
     fn build_query<'a>(
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
     ) -> Result<BoxedQuery<'a, Self, DB, Ctx>, Error>
@@ -165,16 +423,21 @@ where
                 >,
             > + 'static,
         <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        Ctx: WundergraphContext,
     {
-        let mut query =
+        use juniper::LookAheadMethods;
+        let query =
             <_ as SelectDsl<_>>::select(Self::table().into_boxed(), Self::get_select(select)?);
 
-        query = Self::apply_filter(query, select)?;
-        query = Self::apply_limit(query, select)?;
-        query = Self::apply_offset(query, select)?;
-        query = Self::apply_order(query, select)?;
-
-        Ok(query)
+        // Invalid arguments are reported including the field they belong to
+        let apply_arguments = || -> Result<_, Error> {
+            let mut query = Self::apply_filter(query, select)?;
+            query = Self::apply_soft_delete(query, select)?;
+            query = Self::apply_limit(query, select)?;
+            query = Self::apply_offset(query, select)?;
+            Self::apply_order(query, select)
+        };
+        apply_arguments().map_err(|e| with_field_path(select.field_name(), e))
     }
 
     fn get_select(
@@ -194,21 +457,65 @@ where
             >,
         >,
         Error,
-    > {
+    >
+    where
+        Ctx: WundergraphContext,
+    {
         use juniper::LookAheadMethods;
+        // Associations and node ids are resolved using the primary key
+        let select_primary_key = select.has_child(node::NODE_ID_FIELD)
+            || (0..Self::FieldList::NON_TABLE_FIELD_COUNT).any(|i| {
+                Self::FieldList::map_non_table_field(i, |global| {
+                    select.has_child(Self::FIELD_NAMES[global])
+                })
+                .unwrap_or(false)
+            });
         <Self::Columns as BuildSelect<Self::Table, DB, _>>::build_select(
             select,
             |local_index| {
-                Self::FieldList::map_table_field(local_index, |global| Self::FIELD_NAMES[global])
-                    .expect("Field is there")
+                Self::FieldList::map_table_field(local_index, |global| {
+                    node::output_field_name(Self::FIELD_NAMES[global], Ctx::NODE_INTERFACE)
+                })
+                .expect("Field is there")
             },
             Self::PrimaryKeyIndex::is_index,
-            (0..Self::FieldList::NON_TABLE_FIELD_COUNT).any(|i| {
-                Self::FieldList::map_non_table_field(i, |global| {
-                    select.has_child(Self::FIELD_NAMES[global])
+            select_primary_key,
+        )
+    }
+
+    /// Like [`get_select`](#method.get_select), but always selects
+    /// the primary key
+    fn get_select_with_primary_key(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<
+        Box<
+            dyn BoxableExpression<
+                Self::Table,
+                DB,
+                SqlType = SqlTypeOfPlaceholder<
+                    Self::FieldList,
+                    DB,
+                    Self::PrimaryKeyIndex,
+                    Self::Table,
+                    Ctx,
+                >,
+            >,
+        >,
+        Error,
+    >
+    where
+        Ctx: WundergraphContext,
+    {
+        <Self::Columns as BuildSelect<Self::Table, DB, _>>::build_select(
+            select,
+            |local_index| {
+                Self::FieldList::map_table_field(local_index, |global| {
+                    node::output_field_name(Self::FIELD_NAMES[global], Ctx::NODE_INTERFACE)
                 })
-                .unwrap_or(false)
-            }),
+                .expect("Field is there")
+            },
+            Self::PrimaryKeyIndex::is_index,
+            true,
         )
     }
 
@@ -219,10 +526,21 @@ where
         Self::Table: 'static,
         <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
     {
-        Ok(
-            <Filter<Self::Filter, Self::Table> as FromLookAheadValue>::from_look_ahead(input)
-                .and_then(<_ as BuildFilter<DB>>::into_filter),
-        )
+        match *input {
+            LookAheadValue::Null => Ok(None),
+            LookAheadValue::Object(ref obj) => {
+                <Filter<Self::Filter, Self::Table> as InnerFilter>::validate_inner_look_ahead(
+                    "filter", obj,
+                )?;
+                let filter =
+                    <Filter<Self::Filter, Self::Table> as FromLookAheadValue>::from_look_ahead(
+                        input,
+                    )
+                    .ok_or_else(|| invalid_argument("filter", "could not decode filter"))?;
+                Ok(<_ as BuildFilter<DB>>::into_filter(filter))
+            }
+            _ => Err(invalid_argument("filter", "expected an input object")),
+        }
     }
 
     fn apply_filter<'a>(
@@ -245,6 +563,68 @@ where
         }
     }
 
+    /// Hide rows marked as deleted, if this entity supports soft deletes
+    fn apply_soft_delete<'a>(
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<BoxedQuery<'a, Self, DB, Ctx>, Error> {
+        Ok(query)
+    }
+
+    /// Mark all rows matching `filter` as deleted (or restore them if
+    /// `deleted` is false)
+    ///
+    /// Returns the number of changed rows or `None` if this entity
+    /// does not support soft deletes
+    fn set_soft_deleted(
+        _ctx: &Ctx,
+        _filter: Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>,
+        _deleted: bool,
+    ) -> Result<Option<usize>, Error>
+    where
+        Ctx: WundergraphContext,
+        Ctx::Connection: Connection<Backend = DB>,
+    {
+        Ok(None)
+    }
+
+    /// Build a filter matching only rows whose version is `expected`
+    ///
+    /// Returns `None` if this entity is not versioned
+    fn version_filter(
+        _expected: &LookAheadValue<'_, WundergraphScalarValue>,
+    ) -> Result<Option<Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>>, Error> {
+        Ok(None)
+    }
+
+    /// The assignment `version = version + 1` executed after the changeset
+    /// of each update
+    ///
+    /// Returns `None` if this entity is not versioned
+    fn version_increment() -> Option<VersionIncrement<Self::Table, DB>> {
+        None
+    }
+
+    /// Register the arguments of the unique lookup field with the given
+    /// index in `UNIQUE_LOOKUPS`
+    fn register_unique_lookup_arguments<'r>(
+        _idx: usize,
+        _registry: &mut Registry<'r, WundergraphScalarValue>,
+        field: Field<'r, WundergraphScalarValue>,
+    ) -> Field<'r, WundergraphScalarValue> {
+        field
+    }
+
+    /// Restrict the query to the row matching the arguments of the unique
+    /// lookup field with the given index in `UNIQUE_LOOKUPS`
+    fn apply_unique_lookup<'a>(
+        _idx: usize,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<BoxedQuery<'a, Self, DB, Ctx>, Error> {
+        Ok(query)
+    }
+
     fn apply_order<'a>(
         mut query: BoxedQuery<'a, Self, DB, Ctx>,
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
@@ -253,9 +633,17 @@ where
         Self::Table: 'static,
     {
         use juniper::{LookAheadArgument, LookAheadMethods};
-        if let Some(LookAheadValue::List(order)) =
-            select.argument("order").map(LookAheadArgument::value)
-        {
+        let order = match select.argument("order").map(LookAheadArgument::value) {
+            Some(LookAheadValue::List(order)) => Some(order),
+            None | Some(LookAheadValue::Null) => None,
+            Some(_) => {
+                return Err(invalid_argument(
+                    "order",
+                    "expected a list of order clauses",
+                ));
+            }
+        };
+        if let Some(order) = order {
             let order_stmts = <Self::Columns as BuildOrder<Self::Table, DB>>::build_order(
                 order,
                 |local_index| {
@@ -264,6 +652,7 @@ where
                     })
                     .expect("Field is there")
                 },
+                Self::FieldList::is_full_text,
             )?;
             for s in order_stmts {
                 query = query.then_order_by(s);
@@ -278,13 +667,8 @@ where
         query: BoxedQuery<'a, Self, DB, Ctx>,
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
     ) -> Result<BoxedQuery<'a, Self, DB, Ctx>, Error> {
-        use juniper::LookAheadMethods;
-        if let Some(limit) = select.argument("limit") {
-            Ok(<_ as LimitDsl>::limit(
-                query,
-                i64::from_look_ahead(limit.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
-            ))
+        if let Some(limit) = get_pagination_argument(select, "limit")? {
+            Ok(<_ as LimitDsl>::limit(query, limit))
         } else {
             Ok(query)
         }
@@ -0,0 +1,259 @@
+//! Support for the relay global object identification specification
+//!
+//! Enabled by setting
+//! [`WundergraphContext::NODE_INTERFACE`](../../../context/trait.WundergraphContext.html#associatedconstant.NODE_INTERFACE)
+//! for the context type of a schema:
+//!
+//! * Each entity implements the `Node` interface, consisting of a single
+//!   `id: ID!` field. If the entity has a field named `id` the value of
+//!   that field is returned by the `raw_id` field instead. The `filter`,
+//!   `order` and `primaryKey` arguments still refer to that field as `id`.
+//! * The query object gets a `node(id: ID!)` and a `nodes(ids: [ID!]!)`
+//!   field, loading entities by their node id. `nodes` returns the
+//!   entities in the order of the given ids, with `null` for ids that do
+//!   not belong to any row. All ids belonging to the same entity are
+//!   loaded using a single query.
+//!
+//! A node id is an opaque hex encoded string, containing the graphql type
+//! name of the entity and all values of the primary key (including
+//! composite keys). The id is not encrypted, clients are able to decode it.
+//! Selecting `__typename` on the result of `node` or `nodes` returns the
+//! graphql type name of the entity, which is required to use fragments on
+//! the `Node` interface.
+
+use super::query_resolver::get_sub_field;
+use crate::error::WundergraphError;
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::{WundergraphScalarValue, WundergraphScalarVisitor};
+use failure::Error;
+use indexmap::IndexMap;
+use juniper::meta::{Field, MetaType};
+use juniper::{
+    GraphQLType, InputValue, LookAheadMethods, LookAheadSelection, LookAheadValue, Registry,
+    Selection, Value, ID,
+};
+use std::fmt::Write;
+
+/// The name of the node interface
+pub const NODE_INTERFACE: &str = "Node";
+
+/// The name of the field containing the node id
+pub const NODE_ID_FIELD: &str = "id";
+
+/// The name of the field returning the value of an entity field named `id`
+pub const RAW_ID_FIELD: &str = "raw_id";
+
+/// The name of the output field of an entity field
+///
+/// With the node interface enabled a field named `id` is returned as
+/// `raw_id`, as `id` contains the node id
+pub fn output_field_name(name: &'static str, node_interface: bool) -> &'static str {
+    if node_interface && name == NODE_ID_FIELD {
+        RAW_ID_FIELD
+    } else {
+        name
+    }
+}
+
+/// The `Node` interface implemented by each entity
+#[derive(Debug, Clone, Copy)]
+pub struct Node;
+
+impl GraphQLType<WundergraphScalarValue> for Node {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some(NODE_INTERFACE)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = &[registry
+            .field::<ID>(NODE_ID_FIELD, &())
+            .description("A globally unique identifier of this object")];
+        registry
+            .build_interface_type::<Self>(info, fields)
+            .description("An object with a globally unique identifier")
+            .into_meta()
+    }
+}
+
+/// Register the `node` and `nodes` fields of a query object
+pub fn register_node_fields<'r>(
+    registry: &mut Registry<'r, WundergraphScalarValue>,
+) -> Vec<Field<'r, WundergraphScalarValue>> {
+    let id = registry.arg::<ID>("id", &());
+    let node = registry
+        .field::<Option<Node>>("node", &())
+        .argument(id)
+        .description("Fetches an object given its node id");
+    let ids = registry.arg::<Vec<ID>>("ids", &());
+    let nodes = registry
+        .field::<Vec<Option<Node>>>("nodes", &())
+        .argument(ids)
+        .description("Fetches objects given their node ids");
+    vec![node, nodes]
+}
+
+/// Encode a node id from a graphql type name and a primary key
+///
+/// The primary key is given as input object like it is accepted
+/// by the `primaryKey` argument. Returns `None` if the key contains
+/// something else than scalar or enum values.
+pub fn encode_node_id(type_name: &str, key: &InputValue<WundergraphScalarValue>) -> Option<String> {
+    let mut raw = String::from(type_name);
+    if let InputValue::Object(ref fields) = *key {
+        for (name, value) in fields {
+            write!(raw, ":{}=", name.item).ok()?;
+            encode_value(&value.item, &mut raw)?;
+        }
+    } else {
+        return None;
+    }
+    Some(raw.bytes().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Decode a node id into the graphql type name and the primary key
+///
+/// Returns `None` for invalid ids
+pub fn decode_node_id(id: &str) -> Option<(String, InputValue<WundergraphScalarValue>)> {
+    if !id.is_ascii() || id.len() % 2 != 0 {
+        return None;
+    }
+    let raw = (0..id.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&id[i..i + 2], 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let raw = String::from_utf8(raw).ok()?;
+    let mut parts = raw.split(':');
+    let type_name = parts.next()?.to_owned();
+    let key = parts
+        .map(|part| {
+            let mut field = part.splitn(2, '=');
+            let name = field.next()?.to_owned();
+            let value = decode_value(field.next()?)?;
+            Some((name, value))
+        })
+        .collect::<Option<IndexMap<_, _>>>()?;
+    if key.is_empty() {
+        None
+    } else {
+        Some((type_name, InputValue::object(key)))
+    }
+}
+
+fn encode_value(value: &InputValue<WundergraphScalarValue>, out: &mut String) -> Option<()> {
+    let res = match *value {
+        InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => write!(out, "i{}", i),
+        InputValue::Scalar(WundergraphScalarValue::Int(i)) => write!(out, "i{}", i),
+        InputValue::Scalar(WundergraphScalarValue::BigInt(i)) => write!(out, "i{}", i),
+        InputValue::Scalar(WundergraphScalarValue::Float(f)) => write!(out, "f{}", f),
+        InputValue::Scalar(WundergraphScalarValue::Double(f)) => write!(out, "f{}", f),
+        InputValue::Scalar(WundergraphScalarValue::Boolean(b)) => write!(out, "b{}", b),
+        InputValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+            write!(out, "s{}", s.replace('%', "%25").replace(':', "%3A"))
+        }
+        InputValue::Enum(ref e) => write!(out, "e{}", e),
+        _ => return None,
+    };
+    res.ok()
+}
+
+fn decode_value(value: &str) -> Option<InputValue<WundergraphScalarValue>> {
+    use serde::de::{value::Error, Visitor};
+
+    if !value.is_char_boundary(1) {
+        return None;
+    }
+    let (tag, value) = value.split_at(1);
+    match tag {
+        "i" => value
+            .parse()
+            .ok()
+            .and_then(|i| WundergraphScalarVisitor.visit_i64::<Error>(i).ok())
+            .map(InputValue::Scalar),
+        "f" => value
+            .parse()
+            .ok()
+            .map(|f| InputValue::Scalar(WundergraphScalarValue::Double(f))),
+        "b" => value
+            .parse()
+            .ok()
+            .map(|b| InputValue::Scalar(WundergraphScalarValue::Boolean(b))),
+        "s" => Some(InputValue::Scalar(WundergraphScalarValue::String(
+            value.replace("%3A", ":").replace("%25", "%"),
+        ))),
+        "e" => Some(InputValue::enum_value(value)),
+        _ => None,
+    }
+}
+
+/// Decode a node id, returning an error for invalid ids
+pub fn decode_node_id_argument(
+    id: &str,
+) -> Result<(String, InputValue<WundergraphScalarValue>), Error> {
+    decode_node_id(id).ok_or_else(|| WundergraphError::InvalidNodeId { id: id.to_owned() }.into())
+}
+
+/// Decode the `id` argument of the `node` field or the `ids` argument
+/// of the `nodes` field
+pub fn node_id_arguments(
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+) -> Result<Vec<(String, InputValue<WundergraphScalarValue>)>, Error> {
+    let arg = look_ahead
+        .argument("id")
+        .or_else(|| look_ahead.argument("ids"))
+        .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+    let ids = match *arg.value() {
+        LookAheadValue::List(_) => Vec::<ID>::from_look_ahead(arg.value()),
+        _ => ID::from_look_ahead(arg.value()).map(|id| vec![id]),
+    }
+    .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+    ids.iter().map(|id| decode_node_id_argument(id)).collect()
+}
+
+/// Add the node id to loaded entities
+///
+/// `keys` contains the primary key of each entity, as returned by
+/// [`LoadingHandler::primary_keys`](../trait.LoadingHandler.html#method.primary_keys)
+pub fn add_node_ids(
+    type_name: &str,
+    keys: Vec<Option<InputValue<WundergraphScalarValue>>>,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    values: &mut [Value<WundergraphScalarValue>],
+) -> Result<(), Error> {
+    let (_, alias, _, _) = get_sub_field(NODE_ID_FIELD, selection);
+    for (value, key) in values.iter_mut().zip(keys) {
+        if let Value::Object(ref mut obj) = *value {
+            let id = key
+                .and_then(|key| encode_node_id(type_name, &key))
+                .ok_or_else(|| WundergraphError::UnsupportedNodeKey {
+                    entity: type_name.to_owned(),
+                })?;
+            obj.add_field(alias, Value::scalar(id));
+        }
+    }
+    Ok(())
+}
+
+/// Add the `__typename` field to an entity returned by the `node`
+/// or `nodes` field, if it is selected
+pub fn add_typename(
+    type_name: &str,
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    value: &mut Value<WundergraphScalarValue>,
+) {
+    if look_ahead.has_child("__typename") {
+        let (_, alias, _, _) = get_sub_field("__typename", selection);
+        if let Value::Object(ref mut obj) = *value {
+            obj.add_field(alias, Value::scalar(type_name));
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::error::WundergraphError;
 use crate::juniper_ext::FromLookAheadValue;
+use crate::query_builder::selection::filter::invalid_argument;
 use crate::query_builder::selection::{BoxedQuery, LoadingHandler};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -7,8 +7,30 @@ use diesel::backend::Backend;
 use diesel::query_dsl::methods::LimitDsl;
 use diesel::query_dsl::methods::OffsetDsl;
 use failure::Error;
-use juniper::LookAheadSelection;
+use juniper::{LookAheadSelection, LookAheadValue};
 
+/// Read a `limit` or `offset` argument from the given selection
+///
+/// Returns `None` if the argument is not given or explicitly set to `null`.
+/// Any value that is not a non-negative integer is rejected.
+pub(crate) fn get_pagination_argument(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+) -> Result<Option<i64>, Error> {
+    use juniper::LookAheadMethods;
+    match select.argument(name).map(|a| a.value()) {
+        None | Some(LookAheadValue::Null) => Ok(None),
+        Some(v) => match i64::from_look_ahead(v) {
+            Some(v) if v >= 0 => Ok(Some(v)),
+            Some(_) => Err(invalid_argument(name, "expected a non-negative integer")),
+            None => Err(invalid_argument(name, "expected an integer")),
+        },
+    }
+}
+
+/// Backend specific handling of the `offset` argument
+///
+/// Implemented for all backends supported by wundergraph
 pub trait ApplyOffset: Backend {
     fn apply_offset<'a, L, Ctx>(
         query: BoxedQuery<'a, L, Self, Ctx>,
@@ -27,13 +49,8 @@ impl ApplyOffset for diesel::pg::Pg {
     where
         L: LoadingHandler<Self, Ctx>,
     {
-        use juniper::LookAheadMethods;
-        if let Some(offset) = select.argument("offset") {
-            Ok(<_ as OffsetDsl>::offset(
-                query,
-                i64::from_look_ahead(offset.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
-            ))
+        if let Some(offset) = get_pagination_argument(select, "offset")? {
+            Ok(<_ as OffsetDsl>::offset(query, offset))
         } else {
             Ok(query)
         }
@@ -49,14 +66,9 @@ impl ApplyOffset for diesel::sqlite::Sqlite {
     where
         L: LoadingHandler<Self, Ctx>,
     {
-        use juniper::LookAheadMethods;
-        if let Some(offset) = select.argument("offset") {
-            let q = <_ as OffsetDsl>::offset(
-                query,
-                i64::from_look_ahead(offset.value())
-                    .ok_or(WundergraphError::CouldNotBuildFilterArgument)?,
-            );
-            if select.argument("limit").is_some() {
+        if let Some(offset) = get_pagination_argument(select, "offset")? {
+            let q = <_ as OffsetDsl>::offset(query, offset);
+            if get_pagination_argument(select, "limit")?.is_some() {
                 Ok(q)
             } else {
                 Ok(<_ as LimitDsl>::limit(q, -1))
@@ -1,5 +1,7 @@
 use super::offset::ApplyOffset;
+use super::filter::invalid_argument;
 use super::LoadingHandler;
+use crate::diesel_ext::FullTextRank;
 use crate::error::WundergraphError;
 use crate::juniper_ext::FromLookAheadValue;
 use crate::query_builder::selection::fields::FieldListExtractor;
@@ -15,9 +17,14 @@ use juniper::{
 use std::marker::PhantomData;
 
 pub trait BuildOrder<T, DB> {
+    /// Build the order clauses for the given `order` argument
+    ///
+    /// Ordering by relevance is only allowed for columns where
+    /// `is_full_text` returns `true`
     fn build_order(
         order: &[LookAheadValue<'_, WundergraphScalarValue>],
         field_name: impl Fn(usize) -> &'static str,
+        is_full_text: impl Fn(usize) -> bool,
     ) -> Result<Vec<Box<dyn BoxableExpression<T, DB, SqlType = ()>>>, Error>;
 }
 
@@ -102,12 +109,25 @@ where
     where
         WundergraphScalarValue: 'r,
     {
-        let args = &[
+        use crate::query_builder::selection::fields::WundergraphFieldList;
+
+        let mut args = vec![
             registry.arg::<GraphqlOrderWrapper<T, DB, Ctx>>("column", &Default::default()),
             registry.arg_with_default("direction", &Order::Asc, &()),
         ];
+        // Only entities with a full text column could be ordered by relevance
+        if (0..T::FieldList::TABLE_FIELD_COUNT).any(T::FieldList::is_full_text) {
+            args.push(
+                registry
+                    .arg_with_default::<Option<String>>("relevance", &None, &())
+                    .description(
+                        "Order by the relevance of the column for this full text search \
+                         query instead of the column value. Requires a full text column",
+                    ),
+            );
+        }
 
-        let obj = registry.build_input_object_type::<Self>(info, args);
+        let obj = registry.build_input_object_type::<Self>(info, &args);
         meta::MetaType::InputObject(obj)
     }
 }
@@ -194,15 +214,17 @@ macro_rules! impl_order_traits {
             where Table: ::diesel::Table,
                   DB: Backend,
             $($T: Column<Table = Table> + ExpressionMethods + Copy + Default +
-              SelectableExpression<Table> + NonAggregate + QueryFragment<DB> + 'static,)+
+              SelectableExpression<Table> + NonAggregate + QueryFragment<DB> + 'static,
+              FullTextRank<$T>: QueryFragment<DB>,)+
             {
                 fn build_order(
                     fields: &[LookAheadValue<'_, WundergraphScalarValue>],
                     field_name: impl Fn(usize) -> &'static str,
+                    is_full_text: impl Fn(usize) -> bool,
                 ) -> Result<Vec<Box<dyn BoxableExpression<Table, DB, SqlType = ()>>>, Error>
                 {
                     let mut ret = Vec::with_capacity(fields.len());
-                    for f in fields {
+                    for (idx, f) in fields.iter().enumerate() {
                         if let LookAheadValue::Object(o) = f {
                             let column = o.iter().find(|(k, _)| *k == "column")
                                 .and_then(|(_, v)| if let LookAheadValue::Enum(c) = v {
@@ -210,17 +232,49 @@ macro_rules! impl_order_traits {
                                 } else {
                                     None
                                 })
-                                .ok_or(WundergraphError::CouldNotBuildFilterArgument)?;
-                            let order = o.iter().find(|(k, _)| *k == "direction")
-                                .and_then(|(_, v)| Order::from_look_ahead(v))
-                                .unwrap_or(Order::Asc);
+                                .ok_or_else(|| invalid_argument(
+                                    format!("order[{}].column", idx),
+                                    "expected a column name",
+                                ))?;
+                            let order = match o.iter().find(|(k, _)| *k == "direction") {
+                                None | Some((_, LookAheadValue::Null)) => Order::Asc,
+                                Some((_, v)) => Order::from_look_ahead(v).ok_or_else(|| {
+                                    invalid_argument(
+                                        format!("order[{}].direction", idx),
+                                        "expected either ASC or DESC",
+                                    )
+                                })?,
+                            };
+                            let relevance = match o.iter().find(|(k, _)| *k == "relevance") {
+                                None | Some((_, LookAheadValue::Null)) => None,
+                                Some((_, v)) => Some(String::from_look_ahead(v).ok_or_else(|| {
+                                    invalid_argument(
+                                        format!("order[{}].relevance", idx),
+                                        "expected a search query",
+                                    )
+                                })?),
+                            };
+                            let relevance_of = |local_index| match relevance {
+                                Some(_) if !is_full_text(local_index) => {
+                                    Err(invalid_argument(
+                                        format!("order[{}].relevance", idx),
+                                        format!("{} is not a full text column", column),
+                                    ))
+                                }
+                                ref relevance => Ok(relevance.clone()),
+                            };
                             match *column {
                             $(
-                                x if x == field_name($idx) => if order == Order::Desc {
-                                    ret.push(Box::new($T::default().desc())
-                                             as Box<dyn BoxableExpression<Table, DB, SqlType = ()>>)
-                                } else {
-                                    ret.push(Box::new($T::default().asc()) as Box<_>)
+                                x if x == field_name($idx) => match (relevance_of($idx)?, order) {
+                                    (Some(query), Order::Desc) => {
+                                        ret.push(Box::new(FullTextRank::new($T::default(), query).desc())
+                                                 as Box<dyn BoxableExpression<Table, DB, SqlType = ()>>)
+                                    }
+                                    (Some(query), Order::Asc) => {
+                                        ret.push(Box::new(FullTextRank::new($T::default(), query).asc()) as Box<_>)
+                                    }
+                                    (None, Order::Desc) => ret.push(Box::new($T::default().desc()) as Box<_>),
+                                    (None, Order::Asc) => ret.push(Box::new($T::default().asc()) as Box<_>),
                                 }
                             )+
                                 x => {
@@ -232,8 +286,9 @@ macro_rules! impl_order_traits {
                                 }
                             }
                         } else {
-                            return Err(Error::from(
-                                WundergraphError::CouldNotBuildFilterArgument
+                            return Err(invalid_argument(
+                                format!("order[{}]", idx),
+                                "expected an input object",
                             ));
                         }
                     }
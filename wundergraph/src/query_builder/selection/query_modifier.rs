@@ -38,3 +38,41 @@ where
         Ok(query)
     }
 }
+
+impl<Conn, DB, T> QueryModifier<T, DB> for crate::context::ReadWriteConnections<Conn>
+where
+    T: LoadingHandler<DB, Self>,
+    Conn: Connection<Backend = DB> + 'static,
+    DB: Backend + ApplyOffset + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    DB::QueryBuilder: Default,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DB, Self>,
+    ) -> Result<BoxedQuery<'a, T, DB, Self>, Error> {
+        Ok(query)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Conn, T> QueryModifier<T, diesel::pg::Pg> for crate::context::TenantConnection<Conn>
+where
+    T: LoadingHandler<diesel::pg::Pg, Self>,
+    Conn: Connection<
+            Backend = diesel::pg::Pg,
+            TransactionManager = diesel::connection::AnsiTransactionManager,
+        > + 'static,
+    T::Table: 'static,
+    <T::Table as QuerySource>::FromClause: QueryFragment<diesel::pg::Pg>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, diesel::pg::Pg, Self>,
+    ) -> Result<BoxedQuery<'a, T, diesel::pg::Pg, Self>, Error> {
+        Ok(query)
+    }
+}
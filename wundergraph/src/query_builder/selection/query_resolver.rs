@@ -79,6 +79,7 @@ macro_rules! wundergraph_value_impl {
                         if let Some(look_ahead) = look_ahead.select_child(get_name($idx)) {
                             let (name, alias, pos, selection) = get_sub_field(get_name($idx), selection);
                             let executor = executor.field_sub_executor(alias, name, pos, selection);
+                            let _field = crate::instrumentation::enter_field(name);
                             let vals = resolver.$idx.finalize(look_ahead, selection, &executor)?;
                             if let Some(vals) = vals {
                                 for (obj, val) in objs.iter_mut().zip(vals.into_iter()) {
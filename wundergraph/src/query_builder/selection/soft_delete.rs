@@ -0,0 +1,80 @@
+//! Helper types and functions for entities marked with
+//! `#[wundergraph(soft_delete = "column")]`
+//!
+//! Rows of such an entity are not removed by the generated delete mutation.
+//! Instead the given nullable timestamp column is set to the current time.
+//! The column needs to be a field of the entity, for example of the type
+//! `Option<NaiveDateTime>`, so that clients could see when a row was deleted.
+//! All queries (including association loads) hide those rows by default.
+//! This can be changed by the following optional arguments:
+//!
+//! * `includeDeleted: true` returns deleted and not deleted rows
+//! * `onlyDeleted: true` returns only deleted rows
+
+use super::filter::invalid_argument;
+use super::offset::ApplyOffset;
+use super::LoadingHandler;
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use failure::Error;
+use juniper::{meta, LookAheadSelection, LookAheadValue, Registry};
+
+/// Which rows of a soft deletable entity should be returned by a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftDeleteMode {
+    /// Only return rows that are not marked as deleted (default)
+    Exclude,
+    /// Return all rows
+    Include,
+    /// Only return rows that are marked as deleted
+    Only,
+}
+
+/// Extract the requested [`SoftDeleteMode`](enum.SoftDeleteMode.html)
+/// from the arguments of a given selection
+pub fn soft_delete_mode(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+) -> Result<SoftDeleteMode, Error> {
+    let include = bool_argument(select, "includeDeleted")?;
+    let only = bool_argument(select, "onlyDeleted")?;
+    Ok(match (include, only) {
+        (_, true) => SoftDeleteMode::Only,
+        (true, false) => SoftDeleteMode::Include,
+        (false, false) => SoftDeleteMode::Exclude,
+    })
+}
+
+fn bool_argument(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+) -> Result<bool, Error> {
+    use juniper::{LookAheadArgument, LookAheadMethods};
+    match select.argument(name).map(LookAheadArgument::value) {
+        None | Some(LookAheadValue::Null) => Ok(false),
+        Some(v) => {
+            bool::from_look_ahead(v).ok_or_else(|| invalid_argument(name, "expected a boolean"))
+        }
+    }
+}
+
+/// Register the `includeDeleted` and `onlyDeleted` arguments for a field
+/// returning the given entity
+///
+/// Does nothing if the entity does not support soft deletes
+pub fn register_soft_delete_arguments<'r, L, DB, Ctx>(
+    registry: &mut Registry<'r, WundergraphScalarValue>,
+    field: meta::Field<'r, WundergraphScalarValue>,
+) -> meta::Field<'r, WundergraphScalarValue>
+where
+    L: LoadingHandler<DB, Ctx>,
+    DB: Backend + ApplyOffset + 'static,
+{
+    if L::SOFT_DELETE {
+        let include = registry.arg_with_default::<Option<bool>>("includeDeleted", &None, &());
+        let only = registry.arg_with_default::<Option<bool>>("onlyDeleted", &None, &());
+        field.argument(include).argument(only)
+    } else {
+        field
+    }
+}
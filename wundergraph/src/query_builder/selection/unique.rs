@@ -0,0 +1,32 @@
+//! Helper functions for entities marked with
+//! `#[wundergraph(unique(column, ...))]`
+//!
+//! Each `unique(...)` lists a set of columns with a unique constraint,
+//! multiple sets could be given. For each set the query object gets a
+//! lookup field named `<Entity>By<Field>` (or
+//! `<Entity>By<FieldA>And<FieldB>` for sets with multiple columns),
+//! built from the graphql names of the entity and of the fields. The
+//! lookup field takes the value of each column as argument named like
+//! the corresponding field and returns the matching row or `null` if
+//! there is none.
+
+use super::filter::invalid_argument;
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::WundergraphScalarValue;
+use failure::Error;
+use juniper::{LookAheadMethods, LookAheadSelection};
+
+/// Extract the value of a unique column passed as argument `name`
+/// to a lookup field
+pub fn unique_argument<T>(
+    select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    name: &str,
+) -> Result<T, Error>
+where
+    T: FromLookAheadValue,
+{
+    select
+        .argument(name)
+        .and_then(|arg| T::from_look_ahead(arg.value()))
+        .ok_or_else(|| invalid_argument(name, "expected a value of the unique column"))
+}
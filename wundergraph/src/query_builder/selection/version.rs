@@ -0,0 +1,255 @@
+//! Helper types and functions for entities marked with
+//! `#[wundergraph(version_column = "column")]`
+//!
+//! Such entities use optimistic concurrency control. The input objects of
+//! the generated update and delete mutations need to contain a field named
+//! like the version field of the entity, holding the version the client
+//! based its change on. The mutation is only executed if this version still
+//! matches the stored one, otherwise a [`Conflict`](struct.Conflict.html)
+//! error is returned.
+//!
+//! Updates apply the changeset only to a row with the expected version
+//! and increment the version afterwards in the same transaction, so
+//! `UPDATE table SET ... WHERE id = $1 AND version = $2` followed by
+//! `UPDATE table SET version = version + 1 WHERE id = $1 AND version = $2`.
+//! The changeset may therefore simply derive `AsChangeset` including the
+//! version field. Writing back the expected version does not change the
+//! matched row:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate diesel;
+//! # table! {
+//! #     species {
+//! #         id -> Integer,
+//! #         name -> Text,
+//! #         version -> Integer,
+//! #     }
+//! # }
+//! #[derive(AsChangeset, Identifiable)]
+//! #[table_name = "species"]
+//! pub struct SpeciesChangeset {
+//!     id: i32,
+//!     name: Option<String>,
+//!     // The version the change is based on
+//!     version: i32,
+//! }
+//! # fn main() {}
+//! ```
+
+use super::filter::invalid_argument;
+use super::offset::ApplyOffset;
+use super::LoadingHandler;
+use crate::context::WundergraphContext;
+use crate::diesel_ext::BoxableFilter;
+use crate::instrumentation::{instrument, Explain};
+use crate::juniper_ext::FromLookAheadValue;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::FromSql;
+use diesel::dsl::{not, sql, SqlTypeOf};
+use diesel::query_builder::{AsChangeset, AstPass, BoxedSelectStatement, QueryFragment};
+use diesel::query_dsl::methods::{BoxedDsl, FilterDsl, LimitDsl, SelectDsl};
+use diesel::sql_types::{Bool, HasSqlType, Integer};
+use diesel::{BoolExpressionMethods, Connection, QueryResult, QuerySource, RunQueryDsl, Table};
+use failure::Error;
+use juniper::{FieldError, LookAheadSelection, LookAheadValue, Object, Value};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+/// Error returned if a versioned entity was changed since the client
+/// loaded it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conflict {
+    /// The graphql name of the entity
+    pub entity: &'static str,
+}
+
+impl From<Conflict> for FieldError<WundergraphScalarValue> {
+    fn from(conflict: Conflict) -> Self {
+        let mut extensions = Object::with_capacity(2);
+        extensions.add_field("code", Value::scalar("CONFLICT"));
+        extensions.add_field("entity", Value::scalar(conflict.entity));
+        FieldError::new(
+            format!("{} was modified concurrently", conflict.entity),
+            Value::object(extensions),
+        )
+    }
+}
+
+/// Extract the expected version from the input object passed as
+/// `argument` to a mutation
+///
+/// Returns `None` if the given entity is not versioned
+pub fn expected_version<'a, L, DB, Ctx>(
+    select: &'a LookAheadSelection<'a, WundergraphScalarValue>,
+    argument: &str,
+) -> Result<Option<&'a LookAheadValue<'a, WundergraphScalarValue>>, Error>
+where
+    L: LoadingHandler<DB, Ctx>,
+    DB: Backend + ApplyOffset + 'static,
+{
+    use juniper::{LookAheadArgument, LookAheadMethods};
+    let field = match L::VERSION_FIELD {
+        Some(field) => field,
+        None => return Ok(None),
+    };
+    let version = match select.argument(argument).map(LookAheadArgument::value) {
+        Some(LookAheadValue::Object(ref o)) => o.iter().find(|&(n, _)| *n == field).map(|(_, v)| v),
+        _ => None,
+    };
+    match version {
+        None | Some(LookAheadValue::Null) => Err(invalid_argument(
+            format!("{}.{}", argument, field),
+            "the version is required for versioned entities",
+        )),
+        Some(v) => Ok(Some(v)),
+    }
+}
+
+/// Parse the version passed to a mutation into the type of the version
+/// column
+///
+/// `field` is the graphql name of the version field, used in the error
+/// message
+pub fn parse_version<T>(
+    value: &LookAheadValue<'_, WundergraphScalarValue>,
+    field: &str,
+) -> Result<T, Error>
+where
+    T: FromLookAheadValue,
+{
+    T::from_look_ahead(value).ok_or_else(|| invalid_argument(field, "expected an integer"))
+}
+
+/// Combine a filter with the version check of the given entity
+///
+/// Returns the filter unchanged if `expected` is `None` or the entity
+/// is not versioned
+pub fn with_version_check<L, DB, Ctx>(
+    filter: Box<dyn BoxableFilter<L::Table, DB, SqlType = Bool>>,
+    expected: Option<&LookAheadValue<'_, WundergraphScalarValue>>,
+) -> Result<Box<dyn BoxableFilter<L::Table, DB, SqlType = Bool>>, Error>
+where
+    L: LoadingHandler<DB, Ctx>,
+    L::Table: 'static,
+    DB: Backend + ApplyOffset + 'static,
+{
+    let check = match expected {
+        Some(expected) => L::version_filter(expected)?,
+        None => None,
+    };
+    Ok(match check {
+        Some(check) => Box::new(filter.and(check)),
+        None => filter,
+    })
+}
+
+/// Fail with a [`Conflict`](struct.Conflict.html) if a row matching
+/// `filter` exists, but its version is not `expected`
+///
+/// Used after a versioned statement did not change any row, to
+/// distinguish an outdated version from a missing row
+pub fn check_conflict<L, DB, Ctx>(
+    ctx: &Ctx,
+    filter: Box<dyn BoxableFilter<L::Table, DB, SqlType = Bool>>,
+    expected: &LookAheadValue<'_, WundergraphScalarValue>,
+) -> Result<(), FieldError<WundergraphScalarValue>>
+where
+    L: LoadingHandler<DB, Ctx>,
+    L::Table: BoxedDsl<
+            'static,
+            DB,
+            Output = BoxedSelectStatement<
+                'static,
+                SqlTypeOf<<L::Table as Table>::AllColumns>,
+                L::Table,
+                DB,
+            >,
+        > + 'static,
+    <L::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    DB: Backend + ApplyOffset + Explain + HasSqlType<Integer> + 'static,
+    DB::QueryBuilder: Default,
+    i32: FromSql<Integer, DB>,
+    Ctx: WundergraphContext,
+    Ctx::Connection: Connection<Backend = DB>,
+{
+    let check = match L::version_filter(expected)? {
+        Some(check) => check,
+        None => return Ok(()),
+    };
+    let outdated = FilterDsl::filter(
+        BoxedDsl::internal_into_boxed(L::table()),
+        filter.and(not(check)),
+    );
+    let outdated = LimitDsl::limit(SelectDsl::select(outdated, sql::<Integer>("1")), 1);
+    let outdated: Vec<i32> = instrument(
+        ctx,
+        ctx.get_connection(),
+        L::TYPE_NAME,
+        outdated,
+        |outdated, conn| outdated.load(conn),
+        Vec::len,
+    )?;
+    if outdated.is_empty() {
+        Ok(())
+    } else {
+        Err(Conflict {
+            entity: L::TYPE_NAME,
+        }
+        .into())
+    }
+}
+
+/// The assignment `version = version + 1` of a versioned entity
+///
+/// Executed after the changeset of an update was applied, see
+/// [`LoadingHandler::version_increment`](../trait.LoadingHandler.html#method.version_increment)
+pub struct VersionIncrement<T, DB> {
+    assignment: Box<dyn QueryFragment<DB>>,
+    table: PhantomData<T>,
+}
+
+impl<T, DB> VersionIncrement<T, DB>
+where
+    DB: Backend,
+{
+    /// Wrap the given assignment, normally `version.eq(version + 1)`
+    pub fn new<A>(assignment: A) -> Self
+    where
+        A: AsChangeset<Target = T>,
+        A::Changeset: QueryFragment<DB> + 'static,
+    {
+        Self {
+            assignment: Box::new(assignment.as_changeset()),
+            table: PhantomData,
+        }
+    }
+}
+
+impl<T, DB> Debug for VersionIncrement<T, DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VersionIncrement").finish()
+    }
+}
+
+impl<T, DB> AsChangeset for VersionIncrement<T, DB>
+where
+    T: QuerySource,
+{
+    type Target = T;
+    type Changeset = Self;
+
+    fn as_changeset(self) -> Self {
+        self
+    }
+}
+
+impl<T, DB> QueryFragment<DB> for VersionIncrement<T, DB>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, pass: AstPass<'_, DB>) -> QueryResult<()> {
+        self.assignment.walk_ast(pass)
+    }
+}
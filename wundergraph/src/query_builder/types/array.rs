@@ -0,0 +1,186 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{ArrayFilter, FilterOption};
+use crate::scalar::WundergraphScalarValue;
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::bound::Bound;
+use diesel::expression::AsExpression;
+use diesel::pg::Pg;
+use diesel::serialize::{self, ToSql};
+use diesel::sql_types::{Array, NotNull, Nullable};
+use juniper::meta::MetaType;
+use juniper::{
+    Executor, FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, Selection,
+    ToInputValue, Value,
+};
+use std::fmt::Debug;
+use std::io::Write;
+
+/// A postgres array
+///
+/// Use this type for fields of postgres array columns (`Array<T>`) to get
+/// the array specific filter operations `contains`, `contained_by`,
+/// `overlaps` and `length`. In graphql the value is represented as list,
+/// like a plain `Vec<T>` field that only supports the common filter
+/// operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, FromSqlRow)]
+pub struct PgArray<T>(pub Vec<T>);
+
+impl<T> From<Vec<T>> for PgArray<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self(v)
+    }
+}
+
+impl<T> From<PgArray<T>> for Vec<T> {
+    fn from(v: PgArray<T>) -> Self {
+        v.0
+    }
+}
+
+impl<ST, T> FromSql<Array<ST>, Pg> for PgArray<T>
+where
+    Vec<T>: FromSql<Array<ST>, Pg>,
+{
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        Vec::from_sql(bytes).map(PgArray)
+    }
+}
+
+impl<ST, T> ToSql<Array<ST>, Pg> for PgArray<T>
+where
+    T: Debug,
+    Vec<T>: ToSql<Array<ST>, Pg>,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, Pg>) -> serialize::Result {
+        ToSql::<Array<ST>, Pg>::to_sql(&self.0, out)
+    }
+}
+
+impl<ST, T> ToSql<Nullable<Array<ST>>, Pg> for PgArray<T>
+where
+    T: Debug,
+    Vec<T>: ToSql<Array<ST>, Pg>,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, Pg>) -> serialize::Result {
+        ToSql::<Array<ST>, Pg>::to_sql(&self.0, out)
+    }
+}
+
+macro_rules! array_as_expression {
+    ($ty: ty, $sql_type: ty) => {
+        impl<'a, ST, T> AsExpression<$sql_type> for $ty {
+            type Expression = Bound<$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    };
+}
+
+array_as_expression!(PgArray<T>, Array<ST>);
+array_as_expression!(PgArray<T>, Nullable<Array<ST>>);
+array_as_expression!(&'a PgArray<T>, Array<ST>);
+array_as_expression!(&'a PgArray<T>, Nullable<Array<ST>>);
+
+impl<T> GraphQLType<WundergraphScalarValue> for PgArray<T>
+where
+    T: GraphQLType<WundergraphScalarValue>,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        <Vec<T> as GraphQLType<WundergraphScalarValue>>::name(info)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        <Vec<T> as GraphQLType<WundergraphScalarValue>>::meta(info, registry)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Self::Context, WundergraphScalarValue>,
+    ) -> Value<WundergraphScalarValue> {
+        self.0.resolve(info, selection_set, executor)
+    }
+}
+
+impl<T> FromInputValue<WundergraphScalarValue> for PgArray<T>
+where
+    T: FromInputValue<WundergraphScalarValue>,
+{
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        Vec::from_input_value(v).map(PgArray)
+    }
+}
+
+impl<T> ToInputValue<WundergraphScalarValue> for PgArray<T>
+where
+    T: ToInputValue<WundergraphScalarValue>,
+{
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.0.to_input_value()
+    }
+}
+
+impl<T> FromLookAheadValue for PgArray<T>
+where
+    T: FromLookAheadValue,
+{
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        Vec::from_look_ahead(v).map(PgArray)
+    }
+}
+
+impl<T> Nameable for PgArray<T>
+where
+    T: Nameable,
+{
+    fn name() -> String {
+        format!("PgArray_{}_", T::name())
+    }
+}
+
+impl<T, Inner> WundergraphValue for PgArray<T>
+where
+    T: WundergraphValue<SqlType = Nullable<Inner>> + 'static,
+    Inner: NotNull + 'static,
+{
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Array<Inner>>;
+}
+
+impl<C, T> FilterValue<C> for PgArray<T>
+where
+    T: FromLookAheadValue
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FilterValue<C>
+        + Clone,
+{
+    type RawValue = Vec<T>;
+    type AdditionalFilter = ArrayFilter<T, C>;
+}
+
+impl<C, DB, T, Ctx> AsColumnFilter<C, DB, Ctx> for PgArray<T>
+where
+    T: FromLookAheadValue
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FilterValue<C>
+        + Clone,
+{
+    type Filter = FilterOption<Self, C>;
+}
@@ -0,0 +1,117 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::FilterOption;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, ToSql};
+use diesel::sql_types::{Binary, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+use std::io::Write;
+
+/// A binary value
+///
+/// Use this type for fields of binary columns (`bytea` on postgres,
+/// `BLOB` on sqlite). In graphql the value is represented by the
+/// `Base64` scalar, a base64 encoded string using the standard alphabet
+/// with padding. Requires the `base64` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, AsExpression, FromSqlRow)]
+#[sql_type = "Binary"]
+pub struct Base64(pub Vec<u8>);
+
+impl From<Vec<u8>> for Base64 {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Base64> for Vec<u8> {
+    fn from(v: Base64) -> Self {
+        v.0
+    }
+}
+
+impl<DB> FromSql<Binary, DB> for Base64
+where
+    DB: Backend,
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        Vec::from_sql(bytes).map(Base64)
+    }
+}
+
+impl<DB> ToSql<Binary, DB> for Base64
+where
+    DB: Backend,
+    Vec<u8>: ToSql<Binary, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+fn decode(s: &WundergraphScalarValue) -> Option<Base64> {
+    if let WundergraphScalarValue::String(ref s) = *s {
+        base64_internal::decode(s).ok().map(Base64)
+    } else {
+        None
+    }
+}
+
+graphql_scalar!(Base64 where Scalar = WundergraphScalarValue {
+    description: "Binary data, represented as base64 encoded string"
+
+    resolve(&self) -> Value {
+        Value::scalar(base64_internal::encode(&self.0))
+    }
+
+    from_input_value(v: &InputValue) -> Option<Base64> {
+        if let InputValue::Scalar(ref s) = *v {
+            decode(s)
+        } else {
+            None
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(s) = value {
+            Ok(WundergraphScalarValue::String(s.into()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+impl FromLookAheadValue for Base64 {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(s) = *v {
+            decode(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl Nameable for Base64 {
+    fn name() -> String {
+        String::from("Base64")
+    }
+}
+
+impl WundergraphValue for Base64 {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Binary>;
+}
+
+impl<C> FilterValue<C> for Base64 {
+    type RawValue = Self;
+    type AdditionalFilter = ();
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Base64 {
+    type Filter = FilterOption<Self, C>;
+}
@@ -0,0 +1,509 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::error::WundergraphError;
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::FilterOption;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::bound::Bound;
+use diesel::expression::AsExpression;
+use diesel::serialize::{self, Output, ToSql};
+use juniper::meta::MetaType;
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    Executor, FieldError, FromInputValue, GraphQLType, InputValue, LookAheadValue,
+    ParseScalarResult, ParseScalarValue, Registry, Selection, ToInputValue, Value,
+};
+use std::any::{self, Any};
+use std::cell::Cell;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::marker::PhantomData;
+
+/// A codec used by [`EncodedKey`](struct.EncodedKey.html) to hide the
+/// actual value of a primary key from clients
+///
+/// Implementations could for example use hashids or encrypt the key.
+pub trait KeyCodec<K> {
+    /// The name of the graphql scalar representing encoded keys
+    ///
+    /// Different codecs should use different names
+    const NAME: &'static str;
+
+    /// The value passed to `encode` and `decode`
+    ///
+    /// This needs to be the type of the value returned by
+    /// [`WundergraphContext::key_codec_context`](../../context/trait.WundergraphContext.html#method.key_codec_context),
+    /// codecs not depending on the context use `()`.
+    type Context: Any;
+
+    /// Encode a key before returning it to a client
+    fn encode(ctx: &Self::Context, key: &K) -> String;
+
+    /// Decode a key provided by a client
+    ///
+    /// Returns `None` if the value is not a valid encoded key
+    fn decode(ctx: &Self::Context, value: &str) -> Option<K>;
+}
+
+thread_local! {
+    static KEY_CODEC_CONTEXT: Cell<Option<*const dyn Any>> = Cell::new(None);
+}
+
+/// Make `ctx` available to all key codecs while `f` runs
+///
+/// Called by the query and mutation objects for each executed field
+#[doc(hidden)]
+pub fn with_key_codec_context<R>(ctx: &dyn Any, f: impl FnOnce() -> R) -> R {
+    struct Reset(Option<*const dyn Any>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            KEY_CODEC_CONTEXT.with(|current| current.set(self.0));
+        }
+    }
+
+    let _reset = Reset(KEY_CODEC_CONTEXT.with(|current| current.replace(Some(ctx))));
+    f()
+}
+
+/// Call `f` with the current codec context
+///
+/// Returns an error outside of `with_key_codec_context` (unless the codec
+/// does not need a context) and if the context has not the type required
+/// by the codec
+fn with_codec_context<K, C, R>(f: impl FnOnce(&C::Context) -> R) -> Result<R, WundergraphError>
+where
+    C: KeyCodec<K>,
+{
+    if let Some(ctx) = (&() as &dyn Any).downcast_ref::<C::Context>() {
+        return Ok(f(ctx));
+    }
+    let ctx = KEY_CODEC_CONTEXT.with(Cell::get).ok_or_else(|| {
+        WundergraphError::MissingKeyCodecContext {
+            codec: C::NAME.to_owned(),
+        }
+    })?;
+    // The pointer is only set while `with_key_codec_context`
+    // holds the reference
+    let ctx = unsafe { &*ctx };
+    let ctx = ctx.downcast_ref::<C::Context>().ok_or_else(|| {
+        WundergraphError::InvalidKeyCodecContext {
+            codec: C::NAME.to_owned(),
+            expected: any::type_name::<C::Context>().to_owned(),
+        }
+    })?;
+    Ok(f(ctx))
+}
+
+enum KeyRepr<K> {
+    Decoded(K),
+    Encoded(String),
+}
+
+/// A primary key encoded by the given codec
+///
+/// Use this type instead of the plain key type for the primary key field
+/// of an entity and for all fields referring to that key, like `HasOne`
+/// fields of other entities or the fields of insert, update and delete
+/// mutations. Clients then only see keys encoded by the given
+/// [`KeyCodec`](trait.KeyCodec.html), this applies to the entity field,
+/// the `primaryKey` argument, filters, node ids and all mutation inputs.
+/// Mark the entity with `#[wundergraph(key_codec = "YourCodec")]` to let
+/// `#[derive(WundergraphEntity)]` check that all primary key fields use
+/// the codec.
+///
+/// Codecs get access to a value provided by the context using
+/// [`WundergraphContext::key_codec_context`](../../context/trait.WundergraphContext.html#method.key_codec_context),
+/// for example a secret that differs between tenants. Keys are encoded
+/// and decoded while a field of the query or mutation object is
+/// executed, keys received before that (while juniper validates the
+/// request) are decoded lazily once they are bound to a statement.
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate diesel;
+/// # use wundergraph::context::WundergraphContext;
+/// use wundergraph::query_builder::types::{EncodedKey, KeyCodec};
+/// use wundergraph::WundergraphEntity;
+///
+/// # table! {
+/// #     heros {
+/// #         id -> Integer,
+/// #         name -> Text,
+/// #     }
+/// # }
+/// #
+/// /// The secret of the current tenant, returned by the
+/// /// `key_codec_context` method of the context
+/// pub struct KeySecret(pub i32);
+///
+/// pub struct HeroIdCodec;
+///
+/// impl KeyCodec<i32> for HeroIdCodec {
+///     const NAME: &'static str = "HeroId";
+///     type Context = KeySecret;
+///
+///     fn encode(secret: &KeySecret, key: &i32) -> String {
+///         format!("{:x}", key ^ secret.0)
+///     }
+///
+///     fn decode(secret: &KeySecret, value: &str) -> Option<i32> {
+///         i32::from_str_radix(value, 16).ok().map(|key| key ^ secret.0)
+///     }
+/// }
+///
+/// #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+/// #[table_name = "heros"]
+/// #[wundergraph(key_codec = "HeroIdCodec")]
+/// pub struct Hero {
+///     id: EncodedKey<i32, HeroIdCodec>,
+///     name: String,
+/// }
+///
+/// # struct MyContext<Conn> {
+/// #     conn: Conn,
+/// #     secret: KeySecret,
+/// # }
+/// #
+/// impl<Conn> WundergraphContext for MyContext<Conn>
+/// where
+///     Conn: diesel::Connection + 'static,
+/// {
+///     type Connection = Conn;
+///
+///     fn get_connection(&self) -> &Conn {
+///         &self.conn
+///     }
+///
+///     fn key_codec_context(&self) -> &dyn std::any::Any {
+///         &self.secret
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[derive(FromSqlRow)]
+pub struct EncodedKey<K, C> {
+    key: KeyRepr<K>,
+    codec: PhantomData<C>,
+}
+
+impl<K, C> EncodedKey<K, C> {
+    /// Wrap a plain key
+    pub fn new(key: K) -> Self {
+        Self {
+            key: KeyRepr::Decoded(key),
+            codec: PhantomData,
+        }
+    }
+
+    fn encoded(value: String) -> Self {
+        Self {
+            key: KeyRepr::Encoded(value),
+            codec: PhantomData,
+        }
+    }
+
+    /// Get the plain key
+    ///
+    /// Returns `None` for keys received while no codec context was
+    /// available, those are only decoded once they are bound to a
+    /// statement
+    pub fn key(&self) -> Option<&K> {
+        match self.key {
+            KeyRepr::Decoded(ref key) => Some(key),
+            KeyRepr::Encoded(_) => None,
+        }
+    }
+
+    /// Get the plain key
+    ///
+    /// See [`key`](#method.key) for when this returns `None`
+    pub fn into_key(self) -> Option<K> {
+        match self.key {
+            KeyRepr::Decoded(key) => Some(key),
+            KeyRepr::Encoded(_) => None,
+        }
+    }
+}
+
+impl<K, C> EncodedKey<K, C>
+where
+    C: KeyCodec<K>,
+{
+    fn encode(&self) -> Result<String, WundergraphError> {
+        match self.key {
+            KeyRepr::Decoded(ref key) => with_codec_context::<K, C, _>(|ctx| C::encode(ctx, key)),
+            KeyRepr::Encoded(ref value) => Ok(value.clone()),
+        }
+    }
+
+    /// Call `f` with the plain key
+    ///
+    /// Keys received while no codec context was available are decoded
+    /// first, `f` gets `None` if that is still not possible
+    fn with_decoded<R>(&self, f: impl FnOnce(Option<&K>) -> R) -> R {
+        match self.key {
+            KeyRepr::Decoded(ref key) => f(Some(key)),
+            KeyRepr::Encoded(ref value) => {
+                let key = with_codec_context::<K, C, _>(|ctx| C::decode(ctx, value))
+                    .ok()
+                    .and_then(|key| key);
+                f(key.as_ref())
+            }
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        match with_codec_context::<K, C, _>(|ctx| C::decode(ctx, value)) {
+            Ok(key) => key.map(Self::new),
+            // Decoded once bound to a statement, which reports the error
+            Err(_) => Some(Self::encoded(value.to_owned())),
+        }
+    }
+
+    fn from_scalar_value(value: &WundergraphScalarValue) -> Option<Self> {
+        if let WundergraphScalarValue::String(ref s) = *value {
+            Self::from_str(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, C> Debug for EncodedKey<K, C>
+where
+    K: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.key {
+            KeyRepr::Decoded(ref key) => f.debug_tuple("EncodedKey").field(key).finish(),
+            KeyRepr::Encoded(ref value) => f.debug_tuple("EncodedKey").field(value).finish(),
+        }
+    }
+}
+
+impl<K, C> Clone for EncodedKey<K, C>
+where
+    K: Clone,
+{
+    fn clone(&self) -> Self {
+        match self.key {
+            KeyRepr::Decoded(ref key) => Self::new(key.clone()),
+            KeyRepr::Encoded(ref value) => Self::encoded(value.clone()),
+        }
+    }
+}
+
+/// Keys are compared by their plain value, encoded keys that could not
+/// be decoded are only equal to the same encoded value
+impl<K, C> PartialEq for EncodedKey<K, C>
+where
+    K: PartialEq,
+    C: KeyCodec<K>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.with_decoded(|a| {
+            other.with_decoded(|b| match (a, b, &self.key, &other.key) {
+                (Some(a), Some(b), _, _) => a == b,
+                (None, None, KeyRepr::Encoded(a), KeyRepr::Encoded(b)) => a == b,
+                _ => false,
+            })
+        })
+    }
+}
+
+impl<K, C> Eq for EncodedKey<K, C>
+where
+    K: Eq,
+    C: KeyCodec<K>,
+{
+}
+
+impl<K, C> Hash for EncodedKey<K, C>
+where
+    K: Hash,
+    C: KeyCodec<K>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.key {
+            KeyRepr::Decoded(ref key) => key.hash(state),
+            KeyRepr::Encoded(ref value) => self.with_decoded(|key| match key {
+                Some(key) => key.hash(state),
+                None => value.hash(state),
+            }),
+        }
+    }
+}
+
+impl<K, C, ST, DB> FromSql<ST, DB> for EncodedKey<K, C>
+where
+    DB: Backend,
+    K: FromSql<ST, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        K::from_sql(bytes).map(Self::new)
+    }
+}
+
+impl<K, C, ST, DB> ToSql<ST, DB> for EncodedKey<K, C>
+where
+    DB: Backend,
+    K: ToSql<ST, DB>,
+    C: KeyCodec<K>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<'_, W, DB>) -> serialize::Result {
+        match self.key {
+            KeyRepr::Decoded(ref key) => key.to_sql(out),
+            KeyRepr::Encoded(ref value) => {
+                match with_codec_context::<K, C, _>(|ctx| C::decode(ctx, value)) {
+                    Ok(Some(key)) => key.to_sql(out),
+                    Ok(None) => Err(format!("Invalid key `{}`", value).into()),
+                    Err(e) => Err(e.to_string().into()),
+                }
+            }
+        }
+    }
+}
+
+impl<K, C, ST> AsExpression<ST> for EncodedKey<K, C> {
+    type Expression = Bound<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a, K, C, ST> AsExpression<ST> for &'a EncodedKey<K, C> {
+    type Expression = Bound<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<K, C> GraphQLType<WundergraphScalarValue> for EncodedKey<K, C>
+where
+    K: 'static,
+    C: KeyCodec<K> + 'static,
+{
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some(C::NAME)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        registry
+            .build_scalar_type::<Self>(info)
+            .description("An encoded primary key")
+            .into_meta()
+    }
+
+    fn resolve(
+        &self,
+        _info: &Self::TypeInfo,
+        _selection_set: Option<&[Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Self::Context, WundergraphScalarValue>,
+    ) -> Value<WundergraphScalarValue> {
+        match self.encode() {
+            Ok(value) => Value::scalar(value),
+            Err(e) => {
+                executor.push_error(FieldError::new(e, Value::null()));
+                Value::null()
+            }
+        }
+    }
+}
+
+impl<K, C> FromInputValue<WundergraphScalarValue> for EncodedKey<K, C>
+where
+    C: KeyCodec<K>,
+{
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        if let InputValue::Scalar(ref s) = *v {
+            Self::from_scalar_value(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, C> ToInputValue<WundergraphScalarValue> for EncodedKey<K, C>
+where
+    C: KeyCodec<K>,
+{
+    /// Returns `null` if the key could not be encoded
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.encode()
+            .map_or_else(|_| InputValue::null(), InputValue::scalar)
+    }
+}
+
+impl<K, C> ParseScalarValue<WundergraphScalarValue> for EncodedKey<K, C> {
+    fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_, WundergraphScalarValue> {
+        if let ScalarToken::String(s) = value {
+            Ok(WundergraphScalarValue::String(s.into()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+}
+
+impl<K, C> FromLookAheadValue for EncodedKey<K, C>
+where
+    C: KeyCodec<K>,
+{
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(s) = *v {
+            Self::from_scalar_value(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, C> Nameable for EncodedKey<K, C>
+where
+    C: KeyCodec<K>,
+{
+    fn name() -> String {
+        String::from(C::NAME)
+    }
+}
+
+impl<K, C> WundergraphValue for EncodedKey<K, C>
+where
+    K: WundergraphValue + 'static,
+    C: 'static,
+{
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = K::SqlType;
+}
+
+impl<K, C, Col> FilterValue<Col> for EncodedKey<K, C>
+where
+    K: Clone,
+    C: KeyCodec<K>,
+{
+    type RawValue = Self;
+    type AdditionalFilter = ();
+}
+
+impl<K, C, Col, DB, Ctx> AsColumnFilter<Col, DB, Ctx> for EncodedKey<K, C>
+where
+    K: Clone,
+    C: KeyCodec<K>,
+{
+    type Filter = FilterOption<Self, Col>;
+}
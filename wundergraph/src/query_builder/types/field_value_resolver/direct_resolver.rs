@@ -1,10 +1,13 @@
 use super::{FieldValueResolver, ResolveWundergraphFieldValue};
+use crate::context::WundergraphContext;
 use crate::error::WundergraphError;
 use crate::query_builder::types::WundergraphValue;
-use crate::scalar::WundergraphScalarValue;
+use crate::scalar::{bigint_as_string, WundergraphScalarValue};
 use diesel::backend::Backend;
+use diesel::sql_types::{Array, BigInt, Nullable};
 use failure::Error;
 use juniper::{Executor, FromContext, GraphQLType, Selection};
+use std::any::TypeId;
 
 #[derive(Debug, Clone, Copy)]
 pub struct DirectResolver;
@@ -15,6 +18,7 @@ where
     T: GraphQLType<WundergraphScalarValue, TypeInfo = ()> + WundergraphValue,
     T::PlaceHolder: Into<Option<T>>,
     <T as GraphQLType<WundergraphScalarValue>>::Context: FromContext<Ctx>,
+    Ctx: WundergraphContext,
 {
     fn new(_elements: usize) -> Self {
         Self
@@ -27,11 +31,16 @@ where
         _selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<Option<juniper::Value<WundergraphScalarValue>>, Error> {
-        Ok(Some(
-            executor
-                .resolve_with_ctx(&(), &value.into().expect("Loading should not fail"))
-                .map_err(|inner| WundergraphError::JuniperError { inner })?,
-        ))
+        let value = executor
+            .resolve_with_ctx(&(), &value.into().expect("Loading should not fail"))
+            .map_err(|inner| WundergraphError::JuniperError { inner })?;
+        let is_bigint = TypeId::of::<T::SqlType>() == TypeId::of::<Nullable<BigInt>>()
+            || TypeId::of::<T::SqlType>() == TypeId::of::<Nullable<Array<BigInt>>>();
+        if Ctx::BIGINT_AS_STRING && is_bigint {
+            Ok(Some(bigint_as_string(value)))
+        } else {
+            Ok(Some(value))
+        }
     }
 
     fn finalize(
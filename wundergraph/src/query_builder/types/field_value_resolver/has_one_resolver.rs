@@ -1,5 +1,6 @@
 use super::{FieldValueResolver, ResolveWundergraphFieldValue};
 use crate::context::WundergraphContext;
+use crate::instrumentation::{instrument, Explain};
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::offset::ApplyOffset;
@@ -36,6 +37,7 @@ impl<'a, R, T, DB, Ctx> FieldValueResolver<HasOne<R, T>, DB, Ctx> for HasOneReso
 where
     DB: Backend
         + ApplyOffset
+        + Explain
         + HasSqlType<SqlTypeOfPlaceholder<T::FieldList, DB, T::PrimaryKeyIndex, T::Table, Ctx>>
         + HasSqlType<SqlTypeOf<NullableExpression<<T::Table as Table>::PrimaryKey>>>
         + 'static,
@@ -97,7 +99,8 @@ where
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<Option<Vec<juniper::Value<WundergraphScalarValue>>>, Error> {
         use diesel::RunQueryDsl;
-        let conn = executor.context().get_connection();
+        let ctx = executor.context();
+        let conn = ctx.get_read_connection();
         let q = T::build_query(look_ahead)?
             .filter(
                 <T::Table as Table>::primary_key(&<T as HasTable>::table())
@@ -109,15 +112,23 @@ where
                 T::get_select(look_ahead)?,
             ));
 
-        let items = q.load::<(
-            Option<R>,
-            <T::FieldList as WundergraphFieldList<_, _, _, Ctx>>::PlaceHolder,
-        )>(conn)?;
+        let items = instrument(
+            ctx,
+            conn,
+            T::TYPE_NAME,
+            q,
+            |q, conn| {
+                q.load::<(
+                    Option<R>,
+                    <T::FieldList as WundergraphFieldList<_, _, _, Ctx>>::PlaceHolder,
+                )>(conn)
+            },
+            Vec::len,
+        )?;
 
         let (keys, placeholder): (Vec<_>, Vec<_>) = items.into_iter().unzip();
 
-        let values =
-            T::FieldList::resolve(placeholder, look_ahead, selection, T::FIELD_NAMES, executor)?;
+        let (_, values) = T::resolve_loaded(placeholder, look_ahead, selection, executor)?;
 
         let map = keys
             .into_iter()
@@ -0,0 +1,169 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{FilterOption, FullTextFilter};
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, ToSql};
+use diesel::sql_types::{Nullable, Text};
+use juniper::meta::MetaType;
+use juniper::{
+    Executor, FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, Selection,
+    ToInputValue, Value,
+};
+use std::any::TypeId;
+use std::io::Write;
+
+/// A text value that could be searched using full text search
+///
+/// Use this type instead of `String` for a field of an entity to opt in
+/// into full text search for the corresponding column. The filter of the
+/// field then provides a `matches` operation in addition to the operations
+/// available for `String` fields and the column could be used to order
+/// by relevance. In graphql the value is represented as `String`.
+/// Filters derived using `WundergraphFilter` opt in by using
+/// `FilterOption<FullText, column>` as type of the corresponding field.
+///
+/// On postgres this works for all text columns, on sqlite a fts5 table
+/// named `<table>_fts` is required. See
+/// [`FullTextMatch`](../../diesel_ext/struct.FullTextMatch.html) for details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, AsExpression, FromSqlRow)]
+#[sql_type = "Text"]
+pub struct FullText(pub String);
+
+impl From<String> for FullText {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl From<FullText> for String {
+    fn from(s: FullText) -> Self {
+        s.0
+    }
+}
+
+impl<DB> FromSql<Text, DB> for FullText
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        String::from_sql(bytes).map(FullText)
+    }
+}
+
+impl<DB> ToSql<Text, DB> for FullText
+where
+    DB: Backend,
+    String: ToSql<Text, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl GraphQLType<WundergraphScalarValue> for FullText {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        <String as GraphQLType<WundergraphScalarValue>>::name(info)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        <String as GraphQLType<WundergraphScalarValue>>::meta(info, registry)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Self::Context, WundergraphScalarValue>,
+    ) -> Value<WundergraphScalarValue> {
+        self.0.resolve(info, selection_set, executor)
+    }
+}
+
+impl FromInputValue<WundergraphScalarValue> for FullText {
+    fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        String::from_input_value(v).map(FullText)
+    }
+}
+
+impl ToInputValue<WundergraphScalarValue> for FullText {
+    fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+        self.0.to_input_value()
+    }
+}
+
+impl FromLookAheadValue for FullText {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        String::from_look_ahead(v).map(FullText)
+    }
+}
+
+impl Nameable for FullText {
+    fn name() -> String {
+        String::from("FullText")
+    }
+}
+
+impl WundergraphValue for FullText {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Text>;
+}
+
+impl<C> FilterValue<C> for FullText {
+    type RawValue = Self;
+    type AdditionalFilter = FullTextFilter<C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for FullText {
+    type Filter = FilterOption<Self, C>;
+}
+
+/// Find the [`FullText`](struct.FullText.html) fields in a tuple of field
+/// types
+///
+/// Used to restrict ordering by relevance to full text columns
+#[doc(hidden)]
+pub trait FullTextFields {
+    fn is_full_text(index: usize) -> bool;
+}
+
+fn is_full_text<T: 'static>() -> bool {
+    let id = TypeId::of::<T>();
+    id == TypeId::of::<FullText>() || id == TypeId::of::<Option<FullText>>()
+}
+
+macro_rules! full_text_fields_impl {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST: ident, $TT: ident,) +
+        }
+    )+) => {
+        $(
+            impl<$($T,)+> FullTextFields for ($($T,)+)
+                where $($T: 'static,)+
+            {
+                fn is_full_text(index: usize) -> bool {
+                    match index {
+                        $($idx => is_full_text::<$T>(),)+
+                        _ => false,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+__diesel_for_each_tuple!(full_text_fields_impl);
@@ -0,0 +1,163 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{FilterOption, JsonFilter};
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, IsNull, ToSql};
+use diesel::sql_types::{Nullable, Text};
+use juniper::meta::MetaType;
+use juniper::{
+    Executor, FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, Selection,
+    ToInputValue, Value,
+};
+use serde_json_internal::Value as JsonValue;
+use std::io::Write;
+
+/// A json document stored in a text column
+///
+/// Use this type for columns that store json as text, for example on
+/// sqlite, that has no dedicated json type. In graphql the value is
+/// represented by the `JSON` scalar, like `serde_json::Value` that is used
+/// for `Jsonb` columns on postgres. The filter of the field provides the
+/// same json specific operations. On sqlite those are implemented using
+/// the json1 functions (`json_extract`, `json_type`, `json_each`), on
+/// postgres the column is cast to `jsonb`.
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[sql_type = "Text"]
+pub struct JsonText(pub JsonValue);
+
+/// A json document stored in a postgres `json` column
+///
+/// Works like `serde_json::Value` for `jsonb` columns, but uses the sql
+/// type `Json`. The json specific filter operations cast the column to `jsonb`.
+#[cfg(feature = "postgres")]
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[sql_type = "diesel::sql_types::Json"]
+pub struct Json(pub JsonValue);
+
+impl<DB> FromSql<Text, DB> for JsonText
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        let s = String::from_sql(bytes)?;
+        Ok(JsonText(serde_json_internal::from_str(&s)?))
+    }
+}
+
+impl<DB> ToSql<Text, DB> for JsonText
+where
+    DB: Backend,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, DB>) -> serialize::Result {
+        serde_json_internal::to_writer(out, &self.0)?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl FromSql<diesel::sql_types::Json, diesel::pg::Pg> for Json {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        <JsonValue as FromSql<diesel::sql_types::Json, diesel::pg::Pg>>::from_sql(bytes).map(Json)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl ToSql<diesel::sql_types::Json, diesel::pg::Pg> for Json {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut serialize::Output<'_, W, diesel::pg::Pg>,
+    ) -> serialize::Result {
+        <JsonValue as ToSql<diesel::sql_types::Json, diesel::pg::Pg>>::to_sql(&self.0, out)
+    }
+}
+
+macro_rules! json_wrapper {
+    ($name: ident, $sql_type: ty) => {
+        impl From<JsonValue> for $name {
+            fn from(v: JsonValue) -> Self {
+                $name(v)
+            }
+        }
+
+        impl From<$name> for JsonValue {
+            fn from(v: $name) -> Self {
+                v.0
+            }
+        }
+
+        impl GraphQLType<WundergraphScalarValue> for $name {
+            type Context = ();
+            type TypeInfo = ();
+
+            fn name(info: &Self::TypeInfo) -> Option<&str> {
+                <JsonValue as GraphQLType<WundergraphScalarValue>>::name(info)
+            }
+
+            fn meta<'r>(
+                info: &Self::TypeInfo,
+                registry: &mut Registry<'r, WundergraphScalarValue>,
+            ) -> MetaType<'r, WundergraphScalarValue>
+            where
+                WundergraphScalarValue: 'r,
+            {
+                <JsonValue as GraphQLType<WundergraphScalarValue>>::meta(info, registry)
+            }
+
+            fn resolve(
+                &self,
+                info: &Self::TypeInfo,
+                selection_set: Option<&[Selection<'_, WundergraphScalarValue>]>,
+                executor: &Executor<'_, Self::Context, WundergraphScalarValue>,
+            ) -> Value<WundergraphScalarValue> {
+                self.0.resolve(info, selection_set, executor)
+            }
+        }
+
+        impl FromInputValue<WundergraphScalarValue> for $name {
+            fn from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+                JsonValue::from_input_value(v).map($name)
+            }
+        }
+
+        impl ToInputValue<WundergraphScalarValue> for $name {
+            fn to_input_value(&self) -> InputValue<WundergraphScalarValue> {
+                self.0.to_input_value()
+            }
+        }
+
+        impl FromLookAheadValue for $name {
+            fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+                JsonValue::from_look_ahead(v).map($name)
+            }
+        }
+
+        impl Nameable for $name {
+            fn name() -> String {
+                <JsonValue as Nameable>::name()
+            }
+        }
+
+        impl WundergraphValue for $name {
+            type PlaceHolder = PlaceHolder<Self>;
+            type SqlType = Nullable<$sql_type>;
+        }
+
+        impl<C> FilterValue<C> for $name {
+            type RawValue = Self;
+            type AdditionalFilter = JsonFilter<C>;
+        }
+
+        impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for $name {
+            type Filter = FilterOption<Self, C>;
+        }
+    };
+}
+
+json_wrapper!(JsonText, Text);
+#[cfg(feature = "postgres")]
+json_wrapper!(Json, diesel::sql_types::Json);
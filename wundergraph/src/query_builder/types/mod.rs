@@ -1,11 +1,37 @@
+#[cfg(feature = "postgres")]
+mod array;
+#[cfg(feature = "base64")]
+mod binary;
+mod encoded_key;
 pub(crate) mod field_value_resolver;
+mod full_text;
 mod has_many;
 mod has_one;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "chrono")]
+mod naive_date_time;
 pub(crate) mod placeholder;
 mod wundergraph_value;
 
+#[cfg(feature = "postgres")]
+pub use self::array::PgArray;
+#[cfg(feature = "base64")]
+pub use self::binary::Base64;
+#[doc(hidden)]
+pub use self::encoded_key::with_key_codec_context;
+pub use self::encoded_key::{EncodedKey, KeyCodec};
 pub use self::field_value_resolver::ResolveWundergraphFieldValue;
+pub use self::full_text::FullText;
+#[doc(hidden)]
+pub use self::full_text::FullTextFields;
 pub use self::has_many::HasMany;
 pub use self::has_one::HasOne;
+#[cfg(all(feature = "serde_json", feature = "postgres"))]
+pub use self::json::Json;
+#[cfg(feature = "serde_json")]
+pub use self::json::JsonText;
+#[cfg(feature = "chrono")]
+pub use self::naive_date_time::IsoNaiveDateTime;
 pub use self::placeholder::PlaceHolder;
 pub use self::wundergraph_value::WundergraphValue;
@@ -0,0 +1,122 @@
+use super::{PlaceHolder, WundergraphValue};
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{ComparisonFilter, FilterOption};
+use crate::scalar::WundergraphScalarValue;
+use chrono_internal::NaiveDateTime;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, ToSql};
+use diesel::sql_types::{Nullable, Timestamp};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+use std::io::Write;
+
+const ISO_8601_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// A timestamp without time zone
+///
+/// Use this type for fields of timestamp columns (`timestamp` on postgres,
+/// `TIMESTAMP` on sqlite). In graphql the value is represented by the
+/// `IsoNaiveDateTime` scalar, an ISO 8601 string like
+/// `2019-06-15T12:30:00` (optionally with fractional seconds). A plain
+/// `NaiveDateTime` field uses the juniper provided scalar instead, which
+/// represents the value as unix timestamp. Requires the `chrono` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, AsExpression, FromSqlRow)]
+#[sql_type = "Timestamp"]
+pub struct IsoNaiveDateTime(pub NaiveDateTime);
+
+impl From<NaiveDateTime> for IsoNaiveDateTime {
+    fn from(v: NaiveDateTime) -> Self {
+        Self(v)
+    }
+}
+
+impl From<IsoNaiveDateTime> for NaiveDateTime {
+    fn from(v: IsoNaiveDateTime) -> Self {
+        v.0
+    }
+}
+
+impl<DB> FromSql<Timestamp, DB> for IsoNaiveDateTime
+where
+    DB: Backend,
+    NaiveDateTime: FromSql<Timestamp, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        NaiveDateTime::from_sql(bytes).map(IsoNaiveDateTime)
+    }
+}
+
+impl<DB> ToSql<Timestamp, DB> for IsoNaiveDateTime
+where
+    DB: Backend,
+    NaiveDateTime: ToSql<Timestamp, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+fn parse(s: &WundergraphScalarValue) -> Option<IsoNaiveDateTime> {
+    if let WundergraphScalarValue::String(ref s) = *s {
+        s.parse::<NaiveDateTime>().ok().map(IsoNaiveDateTime)
+    } else {
+        None
+    }
+}
+
+graphql_scalar!(IsoNaiveDateTime where Scalar = WundergraphScalarValue {
+    description: "A date and time without time zone, represented as ISO 8601 string"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.0.format(ISO_8601_FORMAT).to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<IsoNaiveDateTime> {
+        if let InputValue::Scalar(ref s) = *v {
+            parse(s)
+        } else {
+            None
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(s) = value {
+            Ok(WundergraphScalarValue::String(s.into()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+impl FromLookAheadValue for IsoNaiveDateTime {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(s) = *v {
+            parse(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl Nameable for IsoNaiveDateTime {
+    fn name() -> String {
+        String::from("IsoNaiveDateTime")
+    }
+}
+
+impl WundergraphValue for IsoNaiveDateTime {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Timestamp>;
+}
+
+impl<C> FilterValue<C> for IsoNaiveDateTime {
+    type RawValue = Self;
+    type AdditionalFilter = ComparisonFilter<Self, C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for IsoNaiveDateTime {
+    type Filter = FilterOption<Self, C>;
+}
@@ -55,6 +55,118 @@ impl<'a> From<&'a str> for WundergraphScalarValue {
     }
 }
 
+/// A user defined scalar type
+///
+/// Custom scalars are represented by one of the existing variants of
+/// `WundergraphScalarValue`, for example an ip address or a geo point
+/// encoded as `String`. Incoming values are parsed as usual by
+/// `WundergraphScalarVisitor` and converted afterwards using
+/// `from_scalar_value`.
+///
+/// Use `#[derive(WundergraphScalar)]` in addition to implementing this
+/// trait to register the type as graphql scalar and to use it as field
+/// of an entity. The derive requires a `#[sql_type = "..."]` attribute,
+/// the graphql name defaults to the name of the type and could be changed
+/// by `#[graphql(name = "...")]`. Doc comments are used as description.
+/// Converting the type from and to sql is not covered by the derive,
+/// implement diesels `ToSql` and `FromSql` for this.
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate diesel;
+/// # use diesel::backend::Backend;
+/// # use diesel::deserialize::{self, FromSql};
+/// # use diesel::serialize::{self, Output, ToSql};
+/// # use diesel::sql_types::Text;
+/// # use std::io::Write;
+/// use wundergraph::scalar::{CustomScalar, WundergraphScalarValue};
+/// use wundergraph::{WundergraphEntity, WundergraphScalar};
+///
+/// #[derive(Debug, Clone, AsExpression, FromSqlRow, WundergraphScalar)]
+/// #[sql_type = "Text"]
+/// /// A ip address in the textual representation
+/// pub struct IpAddress(std::net::IpAddr);
+///
+/// impl CustomScalar for IpAddress {
+///     fn to_scalar_value(&self) -> WundergraphScalarValue {
+///         WundergraphScalarValue::String(self.0.to_string())
+///     }
+///
+///     fn from_scalar_value(value: &WundergraphScalarValue) -> Option<Self> {
+///         match *value {
+///             WundergraphScalarValue::String(ref s) => s.parse().ok().map(IpAddress),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// impl<DB> ToSql<Text, DB> for IpAddress
+/// where
+///     DB: Backend,
+///     str: ToSql<Text, DB>,
+/// {
+///     fn to_sql<W: Write>(&self, out: &mut Output<'_, W, DB>) -> serialize::Result {
+///         self.0.to_string().as_str().to_sql(out)
+///     }
+/// }
+///
+/// impl<DB> FromSql<Text, DB> for IpAddress
+/// where
+///     DB: Backend,
+///     String: FromSql<Text, DB>,
+/// {
+///     fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+///         Ok(IpAddress(String::from_sql(bytes)?.parse()?))
+///     }
+/// }
+///
+/// table! {
+///     servers {
+///         id -> Integer,
+///         address -> Text,
+///     }
+/// }
+///
+/// #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+/// #[table_name = "servers"]
+/// pub struct Server {
+///     id: i32,
+///     address: IpAddress,
+/// }
+/// # fn main() {}
+/// ```
+pub trait CustomScalar: Sized {
+    /// Convert the value into the scalar value returned to the client
+    fn to_scalar_value(&self) -> WundergraphScalarValue;
+
+    /// Convert a scalar value provided by the client
+    ///
+    /// Returns `None` if the value is not valid for this type
+    fn from_scalar_value(value: &WundergraphScalarValue) -> Option<Self>;
+}
+
+/// Parses a scalar literal of a graphql query using `WundergraphScalarVisitor`
+///
+/// Used by `#[derive(WundergraphScalar)]`
+#[doc(hidden)]
+pub fn parse_scalar_token(value: ScalarToken<'_>) -> ParseScalarResult<'_, WundergraphScalarValue> {
+    use serde::de::{Error, Visitor};
+
+    let visitor = WundergraphScalarVisitor;
+    let parsed: Result<_, de::value::Error> = match value {
+        ScalarToken::String(s) => visitor.visit_str(s),
+        ScalarToken::Int(i) => i
+            .parse()
+            .map_err(Error::custom)
+            .and_then(|i| visitor.visit_i64(i)),
+        ScalarToken::Float(f) => f
+            .parse()
+            .map_err(Error::custom)
+            .and_then(|f| visitor.visit_f64(f)),
+    };
+    parsed.map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+}
+
 #[doc(hidden)]
 #[derive(Default, Debug, Clone, Copy)]
 pub struct WundergraphScalarVisitor;
@@ -81,7 +193,7 @@ impl<'de> de::Visitor<'de> for WundergraphScalarVisitor {
     where
         E: de::Error,
     {
-        if value <= i32::from(i16::max_value()) {
+        if value >= i32::from(i16::min_value()) && value <= i32::from(i16::max_value()) {
             self.visit_i16(value as i16)
         } else {
             Ok(WundergraphScalarValue::Int(value))
@@ -92,7 +204,7 @@ impl<'de> de::Visitor<'de> for WundergraphScalarVisitor {
     where
         E: de::Error,
     {
-        if value <= i64::from(i32::max_value()) {
+        if value >= i64::from(i32::min_value()) && value <= i64::from(i32::max_value()) {
             self.visit_i32(value as i32)
         } else {
             Ok(WundergraphScalarValue::BigInt(value))
@@ -146,6 +258,26 @@ impl<'de> de::Visitor<'de> for WundergraphScalarVisitor {
     }
 }
 
+/// Parses a `BigInt` provided as string
+///
+/// Strings are accepted regardless of `WundergraphContext::BIGINT_AS_STRING`,
+/// so clients could always send values that do not fit into a javascript number
+pub(crate) fn bigint_from_str(s: &str) -> Option<i64> {
+    s.parse().ok()
+}
+
+/// Replaces all `BigInt` values contained in the given value by their
+/// string representation
+pub(crate) fn bigint_as_string(
+    value: Value<WundergraphScalarValue>,
+) -> Value<WundergraphScalarValue> {
+    match value {
+        Value::Scalar(WundergraphScalarValue::BigInt(i)) => Value::scalar(i.to_string()),
+        Value::List(values) => Value::list(values.into_iter().map(bigint_as_string).collect()),
+        v => v,
+    }
+}
+
 graphql_scalar!(i64 as "BigInt" where Scalar = WundergraphScalarValue {
     resolve(&self) -> Value {
         Value::scalar(*self)
@@ -156,17 +288,21 @@ graphql_scalar!(i64 as "BigInt" where Scalar = WundergraphScalarValue {
             InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => Some(i64::from(i)),
             InputValue::Scalar(WundergraphScalarValue::Int(i)) => Some(i64::from(i)),
             InputValue::Scalar(WundergraphScalarValue::BigInt(i)) => Some(i),
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => bigint_from_str(s),
             _ => None,
         }
     }
 
     from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
-        if let ScalarToken::Int(v) = value {
-            v.parse::<i64>()
+        match value {
+            ScalarToken::Int(v) => v
+                .parse::<i64>()
                 .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
-                .map(Into::into)
-        } else {
-                Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(Into::into),
+            ScalarToken::String(s) if bigint_from_str(s).is_some() => {
+                Ok(WundergraphScalarValue::String(s.into()))
+            }
+            _ => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
         }
     }
 });
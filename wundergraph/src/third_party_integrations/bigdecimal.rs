@@ -0,0 +1,80 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{ComparisonFilter, FilterOption};
+use crate::query_builder::types::{PlaceHolder, WundergraphValue};
+use crate::scalar::WundergraphScalarValue;
+use bigdecimal_internal::BigDecimal;
+use diesel::sql_types::{Nullable, Numeric};
+use juniper::parser::ScalarToken;
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+use std::str::FromStr;
+
+fn decimal_from_scalar(s: &WundergraphScalarValue) -> Option<BigDecimal> {
+    match *s {
+        WundergraphScalarValue::String(ref s) => BigDecimal::from_str(s).ok(),
+        WundergraphScalarValue::SmallInt(i) => Some(BigDecimal::from(i64::from(i))),
+        WundergraphScalarValue::Int(i) => Some(BigDecimal::from(i64::from(i))),
+        WundergraphScalarValue::BigInt(i) => Some(BigDecimal::from(i)),
+        // Go through the shortest string representation, otherwise
+        // 0.1 would become 0.1000000000000000055511151231257827
+        WundergraphScalarValue::Float(f) => BigDecimal::from_str(&f.to_string()).ok(),
+        WundergraphScalarValue::Double(d) => BigDecimal::from_str(&d.to_string()).ok(),
+        WundergraphScalarValue::Boolean(_) => None,
+    }
+}
+
+graphql_scalar!(BigDecimal as "Decimal" where Scalar = WundergraphScalarValue {
+    description: "An arbitrary precision decimal number, represented as string"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<BigDecimal> {
+        if let InputValue::Scalar(ref s) = *v {
+            decimal_from_scalar(s)
+        } else {
+            None
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        // Number literals are kept as string to not lose any precision
+        match value {
+            ScalarToken::String(s) | ScalarToken::Int(s) | ScalarToken::Float(s) => {
+                Ok(WundergraphScalarValue::String(s.into()))
+            }
+        }
+    }
+});
+
+impl Nameable for BigDecimal {
+    fn name() -> String {
+        String::from("Decimal")
+    }
+}
+
+impl FromLookAheadValue for BigDecimal {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(s) = *v {
+            decimal_from_scalar(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl WundergraphValue for BigDecimal {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Numeric>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for BigDecimal {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for BigDecimal {
+    type RawValue = Self;
+    type AdditionalFilter = ComparisonFilter<Self, C>;
+}
@@ -1,12 +1,18 @@
 use crate::juniper_ext::{FromLookAheadValue, Nameable};
 use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
 use crate::query_builder::selection::filter::filter_value::FilterValue;
-use crate::query_builder::selection::filter::FilterOption;
+use crate::query_builder::selection::filter::{ComparisonFilter, FilterOption};
+use crate::query_builder::types::placeholder::PlaceHolderMarker;
 use crate::query_builder::types::{PlaceHolder, WundergraphValue};
 use crate::scalar::WundergraphScalarValue;
-use chrono_internal::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
-use diesel::sql_types::{Date, Nullable, Timestamp, Timestamptz};
-use juniper::{FromInputValue, LookAheadValue, ToInputValue};
+use chrono_internal::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::sql_types::{Date, Nullable, Time, Timestamp, Timestamptz};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    graphql_scalar, FromInputValue, LookAheadValue, ParseScalarResult, ToInputValue, Value,
+};
 
 impl From<NaiveDateTime> for WundergraphScalarValue {
     fn from(n: NaiveDateTime) -> Self {
@@ -20,29 +26,72 @@ impl Nameable for NaiveDateTime {
     }
 }
 
-impl<O> Nameable for DateTime<O>
-where
-    O: TimeZone,
-{
+impl Nameable for DateTime<Utc> {
     fn name() -> String {
         String::from("DateTime")
     }
 }
+
+impl Nameable for DateTime<FixedOffset> {
+    fn name() -> String {
+        String::from("DateTimeFixedOffset")
+    }
+}
 impl Nameable for NaiveDate {
     fn name() -> String {
         String::from("Date")
     }
 }
 
+impl Nameable for NaiveTime {
+    fn name() -> String {
+        String::from("NaiveTime")
+    }
+}
+
 static RFC3339_PARSE_FORMAT: &'static str = "%+";
-static RFC3339_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+static DATE_FORMAT: &'static str = "%Y-%m-%d";
+static TIME_FORMAT: &'static str = "%H:%M:%S%.f";
+
+graphql_scalar!(NaiveTime as "NaiveTime" where Scalar = WundergraphScalarValue {
+    description: "A time of day without timezone in the ISO 8601 format `HH:MM:SS[.fraction]`"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.format(TIME_FORMAT).to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<NaiveTime> {
+        v.as_string_value()
+            .and_then(|s| NaiveTime::parse_from_str(s, TIME_FORMAT).ok())
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(s) = value {
+            Ok(WundergraphScalarValue::String(s.into()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
 
 impl FromLookAheadValue for NaiveDateTime {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
-        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
-            Self::parse_from_str(s, RFC3339_PARSE_FORMAT).ok()
-        } else {
-            None
+        // Juniper represents `NaiveDateTime` as unix timestamp,
+        // additionally accept a ISO 8601 formatted string
+        match *v {
+            LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+                Self::parse_from_str(s, RFC3339_PARSE_FORMAT).ok()
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Double(d)) => {
+                Self::from_timestamp_opt(*d as i64, 0)
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Int(i)) => {
+                Self::from_timestamp_opt(i64::from(*i), 0)
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::BigInt(i)) => {
+                Self::from_timestamp_opt(*i, 0)
+            }
+            _ => None,
         }
     }
 }
@@ -70,7 +119,17 @@ impl FromLookAheadValue for DateTime<FixedOffset> {
 impl FromLookAheadValue for NaiveDate {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
         if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
-            Self::parse_from_str(s, RFC3339_FORMAT).ok()
+            Self::parse_from_str(s, DATE_FORMAT).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl FromLookAheadValue for NaiveTime {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
+            Self::parse_from_str(s, TIME_FORMAT).ok()
         } else {
             None
         }
@@ -87,14 +146,24 @@ impl WundergraphValue for DateTime<Utc> {
     type SqlType = Nullable<Timestamptz>;
 }
 
+impl WundergraphValue for DateTime<FixedOffset> {
+    type PlaceHolder = PlaceHolder<LoadedFixedOffset>;
+    type SqlType = Nullable<Timestamptz>;
+}
+
 impl WundergraphValue for NaiveDate {
     type PlaceHolder = PlaceHolder<Self>;
     type SqlType = Nullable<Date>;
 }
 
+impl WundergraphValue for NaiveTime {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Time>;
+}
+
 impl<C> FilterValue<C> for NaiveDateTime {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = ComparisonFilter<Self, C>;
 }
 
 impl<O, C> FilterValue<C> for DateTime<O>
@@ -105,12 +174,17 @@ where
         + FromLookAheadValue,
 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = ComparisonFilter<Self, C>;
 }
 
 impl<C> FilterValue<C> for NaiveDate {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = ComparisonFilter<Self, C>;
+}
+
+impl<C> FilterValue<C> for NaiveTime {
+    type RawValue = Self;
+    type AdditionalFilter = ComparisonFilter<Self, C>;
 }
 
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveDateTime {
@@ -121,6 +195,45 @@ impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for DateTime<Utc> {
     type Filter = FilterOption<Self, C>;
 }
 
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for DateTime<FixedOffset> {
+    type Filter = FilterOption<Self, C>;
+}
+
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveDate {
     type Filter = FilterOption<Self, C>;
 }
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveTime {
+    type Filter = FilterOption<Self, C>;
+}
+
+/// A `DateTime<FixedOffset>` loaded from a `Timestamptz` column
+///
+/// Diesel only loads `Timestamptz` values as `DateTime<Utc>`, so those
+/// are converted into a `DateTime<FixedOffset>` with the offset `+00:00`
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct LoadedFixedOffset(DateTime<FixedOffset>);
+
+impl<DB> FromSql<Timestamptz, DB> for LoadedFixedOffset
+where
+    DB: Backend,
+    DateTime<Utc>: FromSql<Timestamptz, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        let utc = DateTime::<Utc>::from_sql(bytes)?;
+        Ok(LoadedFixedOffset(utc.with_timezone(&FixedOffset::east(0))))
+    }
+}
+
+impl Into<Option<DateTime<FixedOffset>>> for PlaceHolder<LoadedFixedOffset> {
+    fn into(self) -> Option<DateTime<FixedOffset>> {
+        self.into_inner().map(|v| v.0)
+    }
+}
+
+impl Into<Option<Option<DateTime<FixedOffset>>>> for PlaceHolder<LoadedFixedOffset> {
+    fn into(self) -> Option<Option<DateTime<FixedOffset>>> {
+        Some(self.into_inner().map(|v| v.0))
+    }
+}
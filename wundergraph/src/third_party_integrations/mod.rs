@@ -1,4 +1,10 @@
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "postgres")]
+mod pg_interval;
 #[cfg(feature = "uuid")]
 mod uuid;
+#[cfg(feature = "serde_json")]
+mod serde_json;
@@ -0,0 +1,268 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{ComparisonFilter, FilterOption};
+use crate::query_builder::types::{PlaceHolder, WundergraphValue};
+use crate::scalar::WundergraphScalarValue;
+use diesel::pg::data_types::PgInterval;
+use diesel::sql_types::{Interval, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, LookAheadValue, ParseScalarResult, Value};
+use std::fmt::{self, Display};
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_MINUTE: i64 = 60 * MICROSECONDS_PER_SECOND;
+const MICROSECONDS_PER_HOUR: i64 = 60 * MICROSECONDS_PER_MINUTE;
+
+/// Formats a interval as ISO 8601 duration
+///
+/// Like the `iso_8601` interval style of postgres each component
+/// carries its own sign, for example `P1Y-2M3DT-4H5.5S`.
+struct IsoDuration<'a>(&'a PgInterval);
+
+impl<'a> Display for IsoDuration<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let PgInterval {
+            microseconds,
+            days,
+            months,
+        } = *self.0;
+        if microseconds == 0 && days == 0 && months == 0 {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        if months / 12 != 0 {
+            write!(f, "{}Y", months / 12)?;
+        }
+        if months % 12 != 0 {
+            write!(f, "{}M", months % 12)?;
+        }
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+        if microseconds == 0 {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        let hours = microseconds / MICROSECONDS_PER_HOUR;
+        let minutes = microseconds % MICROSECONDS_PER_HOUR / MICROSECONDS_PER_MINUTE;
+        let seconds = microseconds % MICROSECONDS_PER_MINUTE;
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+        if seconds != 0 {
+            let sign = if seconds < 0 { "-" } else { "" };
+            let seconds = seconds.abs();
+            let fraction = seconds % MICROSECONDS_PER_SECOND;
+            write!(f, "{}{}", sign, seconds / MICROSECONDS_PER_SECOND)?;
+            if fraction != 0 {
+                let fraction = format!("{:06}", fraction);
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+            write!(f, "S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a ISO 8601 duration like `P1Y2M3W4DT5H6M7.5S`
+///
+/// Each component may be signed, only seconds could have a fraction
+/// with at most 6 digits. Returns `None` for invalid durations
+/// and durations not representable as `PgInterval`.
+fn parse_iso_duration(s: &str) -> Option<PgInterval> {
+    if !s.starts_with('P') || s.ends_with('T') {
+        return None;
+    }
+    let mut rest = &s[1..];
+    let mut in_time = false;
+    let mut has_component = false;
+    let (mut microseconds, mut days, mut months) = (0_i64, 0_i32, 0_i32);
+    while !rest.is_empty() {
+        if rest.starts_with('T') {
+            if in_time {
+                return None;
+            }
+            in_time = true;
+            rest = &rest[1..];
+            continue;
+        }
+        let end = rest.find(|c: char| c.is_ascii_alphabetic())?;
+        let number = &rest[..end];
+        let designator = rest[end..].chars().next()?;
+        rest = &rest[end + designator.len_utf8()..];
+        match (in_time, designator) {
+            (false, 'Y') => {
+                months = months.checked_add(number.parse::<i32>().ok()?.checked_mul(12)?)?
+            }
+            (false, 'M') => months = months.checked_add(number.parse().ok()?)?,
+            (false, 'W') => days = days.checked_add(number.parse::<i32>().ok()?.checked_mul(7)?)?,
+            (false, 'D') => days = days.checked_add(number.parse().ok()?)?,
+            (true, 'H') => {
+                let hours = number.parse::<i64>().ok()?;
+                microseconds =
+                    microseconds.checked_add(hours.checked_mul(MICROSECONDS_PER_HOUR)?)?
+            }
+            (true, 'M') => {
+                let minutes = number.parse::<i64>().ok()?;
+                microseconds =
+                    microseconds.checked_add(minutes.checked_mul(MICROSECONDS_PER_MINUTE)?)?
+            }
+            (true, 'S') => microseconds = microseconds.checked_add(parse_seconds(number)?)?,
+            _ => return None,
+        }
+        has_component = true;
+    }
+    if has_component {
+        Some(PgInterval::new(microseconds, days, months))
+    } else {
+        None
+    }
+}
+
+fn parse_seconds(number: &str) -> Option<i64> {
+    let (negative, number) = if number.starts_with('-') {
+        (true, &number[1..])
+    } else if number.starts_with('+') {
+        (false, &number[1..])
+    } else {
+        (false, number)
+    };
+    let mut parts = number.splitn(2, |c| c == '.' || c == ',');
+    let seconds = parts.next()?;
+    let fraction = parts.next().unwrap_or("");
+    if seconds.is_empty()
+        || fraction.len() > 6
+        || !seconds.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let microseconds = seconds
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(MICROSECONDS_PER_SECOND)?
+        .checked_add(format!("{:0<6}", fraction).parse().ok()?)?;
+    if negative {
+        Some(-microseconds)
+    } else {
+        Some(microseconds)
+    }
+}
+
+graphql_scalar!(PgInterval as "Interval" where Scalar = WundergraphScalarValue {
+    description: "A time interval in the ISO 8601 duration format, for example `P1Y2M3DT4H5M6.5S`"
+
+    resolve(&self) -> Value {
+        Value::scalar(IsoDuration(self).to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<PgInterval> {
+        v.as_string_value().and_then(parse_iso_duration)
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(s) = value {
+            Ok(WundergraphScalarValue::String(s.into()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+impl Nameable for PgInterval {
+    fn name() -> String {
+        String::from("Interval")
+    }
+}
+
+impl FromLookAheadValue for PgInterval {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
+            parse_iso_duration(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl WundergraphValue for PgInterval {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Interval>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for PgInterval {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for PgInterval {
+    type RawValue = Self;
+    type AdditionalFilter = ComparisonFilter<Self, C>;
+}
+
+#[test]
+fn parse_iso_duration_components() {
+    let parse = |s| parse_iso_duration(s).map(|i| (i.microseconds, i.days, i.months));
+
+    assert_eq!(parse("P1Y"), Some((0, 0, 12)));
+    assert_eq!(parse("P1Y2M"), Some((0, 0, 14)));
+    assert_eq!(parse("P2W3D"), Some((0, 17, 0)));
+    assert_eq!(parse("PT1H2M3S"), Some((3_723_000_000, 0, 0)));
+    assert_eq!(parse("PT0.5S"), Some((500_000, 0, 0)));
+    assert_eq!(parse("PT1,000001S"), Some((1_000_001, 0, 0)));
+    assert_eq!(parse("P1Y2M3W4DT5H6M7.5S"), Some((18_367_500_000, 25, 14)));
+    assert_eq!(parse("PT0S"), Some((0, 0, 0)));
+}
+
+#[test]
+fn parse_iso_duration_signed_components() {
+    let parse = |s| parse_iso_duration(s).map(|i| (i.microseconds, i.days, i.months));
+
+    assert_eq!(parse("P1Y-2M3DT-4H5.5S"), Some((-14_394_500_000, 3, 10)));
+    assert_eq!(parse("PT-1.5S"), Some((-1_500_000, 0, 0)));
+    assert_eq!(parse("P+1D"), Some((0, 1, 0)));
+}
+
+#[test]
+fn parse_iso_duration_rejects_invalid_durations() {
+    for invalid in &[
+        "",
+        "P",
+        "PT",
+        "1Y",
+        "P1",
+        "P1H",
+        "PT1D",
+        "P1YT",
+        "PT1HT1M",
+        "PT1.5M",
+        "PT1.1234567S",
+        "PT.5S",
+        "PT1.S5",
+        "P1.5Y",
+        "PxY",
+        "P2147483647Y",
+        "PT9223372036854775807H",
+    ] {
+        assert_eq!(None, parse_iso_duration(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn iso_duration_round_trip() {
+    for duration in &[
+        "PT0S",
+        "P1Y",
+        "P1Y2M3DT4H5M6.5S",
+        "P-1Y-2M-3DT-4H-5M-6.5S",
+        "PT-0.000001S",
+        "P-10D",
+        "PT25H",
+    ] {
+        let interval = parse_iso_duration(duration).unwrap();
+        assert_eq!(*duration, IsoDuration(&interval).to_string());
+    }
+}
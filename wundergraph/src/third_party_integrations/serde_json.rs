@@ -0,0 +1,172 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::{FilterOption, JsonFilter};
+#[cfg(feature = "postgres")]
+use crate::query_builder::types::{PlaceHolder, WundergraphValue};
+use crate::scalar::WundergraphScalarValue;
+#[cfg(feature = "postgres")]
+use diesel::sql_types::{Jsonb, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    graphql_scalar, InputValue, LookAheadValue, Object, ParseScalarResult, ParseScalarValue, Value,
+};
+use serde_json_internal::{Number, Value as JsonValue};
+
+/// Json input values are given as json text, numbers and booleans
+/// are accepted as they are
+fn json_from_scalar(s: &WundergraphScalarValue) -> Option<JsonValue> {
+    match *s {
+        WundergraphScalarValue::String(ref s) => serde_json_internal::from_str(s).ok(),
+        ref s => Some(json_from_nested_scalar(s)),
+    }
+}
+
+/// Scalars nested in structured input are taken literally, so strings
+/// are not parsed as json text there
+fn json_from_nested_scalar(s: &WundergraphScalarValue) -> JsonValue {
+    match *s {
+        WundergraphScalarValue::SmallInt(i) => JsonValue::from(i),
+        WundergraphScalarValue::Int(i) => JsonValue::from(i),
+        WundergraphScalarValue::BigInt(i) => JsonValue::from(i),
+        WundergraphScalarValue::Float(f) => JsonValue::from(f),
+        WundergraphScalarValue::Double(f) => JsonValue::from(f),
+        WundergraphScalarValue::String(ref s) => JsonValue::from(s.clone()),
+        WundergraphScalarValue::Boolean(b) => JsonValue::from(b),
+    }
+}
+
+fn scalar_from_number(n: &Number) -> WundergraphScalarValue {
+    match n.as_i64() {
+        Some(i) if i >= i64::from(i32::min_value()) && i <= i64::from(i32::max_value()) => {
+            WundergraphScalarValue::Int(i as i32)
+        }
+        Some(i) => WundergraphScalarValue::BigInt(i),
+        None => WundergraphScalarValue::Double(n.as_f64().unwrap_or_default()),
+    }
+}
+
+fn json_from_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<JsonValue> {
+    match *v {
+        // A graphql null is the absence of a value, not the json value `null`
+        InputValue::Null | InputValue::Variable(_) => None,
+        InputValue::Scalar(ref s) => json_from_scalar(s),
+        InputValue::Enum(_) | InputValue::List(_) | InputValue::Object(_) => {
+            json_from_nested_input_value(v)
+        }
+    }
+}
+
+fn json_from_nested_input_value(v: &InputValue<WundergraphScalarValue>) -> Option<JsonValue> {
+    match *v {
+        InputValue::Null => Some(JsonValue::Null),
+        InputValue::Scalar(ref s) => Some(json_from_nested_scalar(s)),
+        InputValue::Enum(ref e) => Some(JsonValue::from(e.clone())),
+        InputValue::List(ref l) => l
+            .iter()
+            .map(|v| json_from_nested_input_value(&v.item))
+            .collect::<Option<_>>()
+            .map(JsonValue::Array),
+        InputValue::Object(ref o) => o
+            .iter()
+            .map(|(k, v)| Some((k.item.clone(), json_from_nested_input_value(&v.item)?)))
+            .collect::<Option<_>>()
+            .map(JsonValue::Object),
+        InputValue::Variable(_) => None,
+    }
+}
+
+fn json_from_nested_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> JsonValue {
+    match *v {
+        LookAheadValue::Null => JsonValue::Null,
+        LookAheadValue::Scalar(s) => json_from_nested_scalar(s),
+        LookAheadValue::Enum(e) => JsonValue::from(e),
+        LookAheadValue::List(ref l) => l.iter().map(json_from_nested_look_ahead).collect(),
+        LookAheadValue::Object(ref o) => JsonValue::Object(
+            o.iter()
+                .map(|&(k, ref v)| (k.to_owned(), json_from_nested_look_ahead(v)))
+                .collect(),
+        ),
+    }
+}
+
+fn json_to_value(v: &JsonValue) -> Value<WundergraphScalarValue> {
+    match *v {
+        JsonValue::Null => Value::null(),
+        JsonValue::Bool(b) => Value::scalar(b),
+        JsonValue::Number(ref n) => Value::Scalar(scalar_from_number(n)),
+        JsonValue::String(ref s) => Value::scalar(s.clone()),
+        JsonValue::Array(ref a) => Value::list(a.iter().map(json_to_value).collect()),
+        JsonValue::Object(ref o) => {
+            let mut obj = Object::with_capacity(o.len());
+            for (k, v) in o {
+                obj.add_field(k.as_str(), json_to_value(v));
+            }
+            Value::object(obj)
+        }
+    }
+}
+
+graphql_scalar!(JsonValue as "JSON" where Scalar = WundergraphScalarValue {
+    description: "An arbitrary json value. Inputs are passed as json encoded string, \
+                  numbers, booleans, lists and objects may also be passed as they are"
+
+    resolve(&self) -> Value {
+        json_to_value(self)
+    }
+
+    from_input_value(v: &InputValue) -> Option<JsonValue> {
+        json_from_input_value(v)
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        match value {
+            // Json text contains quotes, so escape sequences need to be resolved
+            ScalarToken::String(_) => {
+                <String as ParseScalarValue<WundergraphScalarValue>>::from_str(value)
+            }
+            ScalarToken::Int(i) => i
+                .parse::<i32>()
+                .map(WundergraphScalarValue::Int)
+                .or_else(|_| i.parse::<i64>().map(WundergraphScalarValue::BigInt))
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value))),
+            ScalarToken::Float(f) => f
+                .parse::<f64>()
+                .map(WundergraphScalarValue::Double)
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+});
+
+impl Nameable for JsonValue {
+    fn name() -> String {
+        String::from("JSON")
+    }
+}
+
+impl FromLookAheadValue for JsonValue {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        match *v {
+            LookAheadValue::Null => None,
+            LookAheadValue::Scalar(s) => json_from_scalar(s),
+            LookAheadValue::Enum(_) | LookAheadValue::List(_) | LookAheadValue::Object(_) => {
+                Some(json_from_nested_look_ahead(v))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl WundergraphValue for JsonValue {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Jsonb>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for JsonValue {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for JsonValue {
+    type RawValue = Self;
+    type AdditionalFilter = JsonFilter<C>;
+}
@@ -0,0 +1,347 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::GraphQLInputObject;
+use serde_json::json;
+use wundergraph::query_builder::mutations::ArrayUpdate;
+use wundergraph::query_builder::types::PgArray;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::MyContext;
+
+table! {
+    posts {
+        id -> Integer,
+        tags -> Array<Text>,
+        scores -> Nullable<Array<Integer>>,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "posts"]
+pub struct Post {
+    id: i32,
+    tags: PgArray<String>,
+    scores: Option<PgArray<i32>>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "posts"]
+pub struct NewPost {
+    tags: PgArray<String>,
+    scores: Option<PgArray<i32>>,
+}
+
+#[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "posts"]
+pub struct PostChangeset {
+    id: i32,
+    tags: Option<ArrayUpdate<posts::tags, String>>,
+    scores: Option<ArrayUpdate<posts::scores, i32>>,
+}
+
+wundergraph::query_object! {
+    Query {
+        Post,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Post(insert = NewPost, update = PostChangeset,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_array_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    get_test_schema(
+        "
+CREATE TABLE posts(id SERIAL PRIMARY KEY, tags TEXT[] NOT NULL, scores INTEGER[]);
+INSERT INTO posts(tags, scores) VALUES
+    ('{rust, graphql}', '{1, 2, 3}'),
+    ('{rust}', '{}'),
+    ('{}', NULL);
+",
+    )
+}
+
+fn post_ids(
+    schema: &Schema,
+    ctx: &MyContext<DbConnection>,
+    filter: serde_json::Value,
+) -> serde_json::Value {
+    let res = execute_query_with_variables(
+        schema,
+        ctx,
+        r#"
+query Posts($filter: PostFilter) {
+    Posts(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("filter", filter)],
+    );
+    assert!(res.is_ok());
+    res.as_json()[0]["Posts"].clone()
+}
+
+#[test]
+fn array_round_trip() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewPost {
+    CreatePost(NewPost: {tags: ["diesel", "juniper"], scores: [4, 5]}) {
+        tags
+        scores
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreatePost": {
+      "scores": [
+        4,
+        5
+      ],
+      "tags": [
+        "diesel",
+        "juniper"
+      ]
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Posts(order: [{column: id}]) {
+        tags
+        scores
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Posts": [
+      {
+        "scores": [
+          1,
+          2,
+          3
+        ],
+        "tags": [
+          "rust",
+          "graphql"
+        ]
+      },
+      {
+        "scores": [],
+        "tags": [
+          "rust"
+        ]
+      },
+      {
+        "scores": null,
+        "tags": []
+      },
+      {
+        "scores": [
+          4,
+          5
+        ],
+        "tags": [
+          "diesel",
+          "juniper"
+        ]
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn array_contains() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Posts(filter: {tags: {contains: ["rust", "graphql"]}}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Posts": [
+      {
+        "id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let contains = |tags| post_ids(&schema, &ctx, json!({"tags": {"contains": tags}}));
+    assert_eq!(contains(json!(["rust"])), json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(
+        contains(json!([])),
+        json!([{"id": 1}, {"id": 2}, {"id": 3}])
+    );
+    assert_eq!(contains(json!(["diesel"])), json!([]));
+
+    let scores = post_ids(&schema, &ctx, json!({"scores": {"contains": [2]}}));
+    assert_eq!(scores, json!([{"id": 1}]));
+}
+
+#[test]
+fn array_contained_by() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let contained_by = |tags| post_ids(&schema, &ctx, json!({"tags": {"contained_by": tags}}));
+    assert_eq!(contained_by(json!(["rust"])), json!([{"id": 2}, {"id": 3}]));
+    assert_eq!(
+        contained_by(json!(["rust", "graphql", "diesel"])),
+        json!([{"id": 1}, {"id": 2}, {"id": 3}])
+    );
+    assert_eq!(contained_by(json!([])), json!([{"id": 3}]));
+
+    // Null never matches
+    let scores = post_ids(
+        &schema,
+        &ctx,
+        json!({"scores": {"contained_by": [1, 2, 3]}}),
+    );
+    assert_eq!(scores, json!([{"id": 1}, {"id": 2}]));
+}
+
+#[test]
+fn array_overlaps() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let overlaps = |tags| post_ids(&schema, &ctx, json!({"tags": {"overlaps": tags}}));
+    assert_eq!(overlaps(json!(["graphql", "diesel"])), json!([{"id": 1}]));
+    assert_eq!(overlaps(json!(["rust"])), json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(overlaps(json!([])), json!([]));
+}
+
+#[test]
+fn array_length() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let length = |length| post_ids(&schema, &ctx, json!({"tags": {"length": length}}));
+    assert_eq!(length(json!({"eq": 0})), json!([{"id": 3}]));
+    assert_eq!(length(json!({"gt": 0})), json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(length(json!({"ge": 1, "lt": 2})), json!([{"id": 2}]));
+    assert_eq!(length(json!({"not_eq": 1})), json!([{"id": 1}, {"id": 3}]));
+    assert_eq!(
+        length(json!({"le": 2})),
+        json!([{"id": 1}, {"id": 2}, {"id": 3}])
+    );
+
+    let scores = post_ids(&schema, &ctx, json!({"scores": {"length": {"lt": 3}}}));
+    assert_eq!(scores, json!([{"id": 2}]));
+}
+
+#[test]
+fn array_atomic_update() {
+    let (schema, pool) = get_array_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation UpdatePost {
+    UpdatePost(UpdatePost: {id: 1, tags: {append: ["diesel", "rust"]}}) {
+        tags
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"UpdatePost": {"tags": ["rust", "graphql", "diesel", "rust"]}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation UpdatePost {
+    UpdatePost(UpdatePost: {id: 1, tags: {remove: ["rust", "juniper"]}}) {
+        tags
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"UpdatePost": {"tags": ["graphql", "diesel"]}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation UpdatePost {
+    UpdatePost(UpdatePost: {id: 1, scores: {remove: [2]}}) {
+        scores
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"UpdatePost": {"scores": [1, 3]}}, []])
+    );
+
+    // Postgres treats a NULL array like an empty one when appending
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation UpdatePost {
+    UpdatePost(UpdatePost: {id: 3, scores: {append: [1]}}) {
+        scores
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"UpdatePost": {"scores": [1]}}, []]));
+}
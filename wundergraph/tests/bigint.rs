@@ -0,0 +1,374 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::{GraphQLInputObject, LookAheadSelection};
+use serde_json::json;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+// Larger than the largest integer a javascript number represents exactly
+const LARGE: i64 = 9_007_199_254_740_993;
+
+table! {
+    accounts {
+        id -> BigInt,
+        balance -> Nullable<BigInt>,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "accounts"]
+pub struct Account {
+    id: i64,
+    balance: Option<i64>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "accounts"]
+pub struct NewAccount {
+    id: i64,
+    balance: Option<i64>,
+}
+
+wundergraph::query_object! {
+    Query {
+        Account,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Account(insert = NewAccount,),
+    }
+}
+
+type Schema<Ctx> = juniper::RootNode<'static, Query<Ctx>, Mutation<Ctx>, WundergraphScalarValue>;
+
+struct AccountContext<S> {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+    p: std::marker::PhantomData<S>,
+}
+
+trait BigIntSetting: 'static {
+    const AS_STRING: bool;
+}
+
+struct AsNumber;
+
+impl BigIntSetting for AsNumber {
+    const AS_STRING: bool = false;
+}
+
+struct AsString;
+
+impl BigIntSetting for AsString {
+    const AS_STRING: bool = true;
+}
+
+impl<S: BigIntSetting> WundergraphContext for AccountContext<S> {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    const BIGINT_AS_STRING: bool = S::AS_STRING;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+}
+
+impl<S, T> QueryModifier<T, DbBackend> for AccountContext<S>
+where
+    S: BigIntSetting,
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+fn get_account_context<S: BigIntSetting>() -> (Schema<AccountContext<S>>, AccountContext<S>) {
+    let (_, pool) = get_example_schema();
+    let conn = pool.get().unwrap();
+    conn.batch_execute(&format!(
+        "
+CREATE TABLE accounts(id BIGINT PRIMARY KEY NOT NULL, balance BIGINT);
+INSERT INTO accounts(id, balance) VALUES (1, {}), ({}, -{}), (3, NULL);
+",
+        LARGE, LARGE, LARGE
+    ))
+    .unwrap();
+    let ctx = AccountContext {
+        conn,
+        p: std::marker::PhantomData,
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+#[test]
+fn bigint_is_returned_as_number_by_default() {
+    let (schema, ctx) = get_account_context::<AsNumber>();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Accounts(order: [{column: id}]) {
+        id
+        balance
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Accounts": [
+      {
+        "balance": 9007199254740993,
+        "id": 1
+      },
+      {
+        "balance": null,
+        "id": 3
+      },
+      {
+        "balance": -9007199254740993,
+        "id": 9007199254740993
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn bigint_is_returned_as_string() {
+    let (schema, ctx) = get_account_context::<AsString>();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Accounts(order: [{column: id}]) {
+        id
+        balance
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Accounts": [
+      {
+        "balance": "9007199254740993",
+        "id": "1"
+      },
+      {
+        "balance": null,
+        "id": "3"
+      },
+      {
+        "balance": "-9007199254740993",
+        "id": "9007199254740993"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn bigint_primary_key_accepts_strings() {
+    let (schema, ctx) = get_account_context::<AsString>();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Account(primaryKey: {id: "9007199254740993"}) {
+        id
+        balance
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Account": {
+      "balance": "-9007199254740993",
+      "id": "9007199254740993"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query Account($id: BigInt!) {
+    Account(primaryKey: {id: $id}) {
+        id
+    }
+}
+"#,
+        &[("id", json!(1))],
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["Account"], json!({"id": "1"}));
+}
+
+#[test]
+fn bigint_filter_accepts_strings_and_numbers() {
+    let (schema, ctx) = get_account_context::<AsString>();
+
+    let account_ids = |filter| {
+        let res = execute_query_with_variables(
+            &schema,
+            &ctx,
+            r#"
+query Accounts($filter: AccountFilter) {
+    Accounts(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+            &[("filter", filter)],
+        );
+        assert!(res.is_ok());
+        res.as_json()[0]["Accounts"].clone()
+    };
+
+    assert_eq!(
+        account_ids(json!({"id": {"eq_any": [1, LARGE.to_string()]}})),
+        json!([{"id": "1"}, {"id": LARGE.to_string()}])
+    );
+    assert_eq!(
+        account_ids(json!({"balance": {"eq": format!("-{}", LARGE)}})),
+        json!([{"id": LARGE.to_string()}])
+    );
+    assert_eq!(
+        account_ids(json!({"balance": {"eq": LARGE}})),
+        json!([{"id": "1"}])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Accounts(filter: {id: {eq_any: ["3", 1]}}, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Accounts": [
+      {
+        "id": "1"
+      },
+      {
+        "id": "3"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Accounts(filter: {id: {eq: "not a number"}}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn bigint_mutation_input_accepts_strings() {
+    let (schema, ctx) = get_account_context::<AsString>();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewAccount {
+    CreateAccount(NewAccount: {id: "9007199254740995", balance: 42}) {
+        id
+        balance
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateAccount": {
+      "balance": "42",
+      "id": "9007199254740995"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+mutation NewAccount($account: NewAccount!) {
+    CreateAccount(NewAccount: $account) {
+        id
+        balance
+    }
+}
+"#,
+        &[("account", json!({"id": 4, "balance": "-9007199254740995"}))],
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateAccount": {
+      "balance": "-9007199254740995",
+      "id": "4"
+    }
+  },
+  []
+]"###
+    );
+}
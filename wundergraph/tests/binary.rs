@@ -0,0 +1,236 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::{FromInputValue, GraphQLInputObject, InputValue};
+use serde_json::json;
+use wundergraph::query_builder::types::Base64;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::MyContext;
+
+table! {
+    files {
+        id -> Integer,
+        data -> Binary,
+        thumbnail -> Nullable<Binary>,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "files"]
+pub struct File {
+    id: i32,
+    data: Base64,
+    thumbnail: Option<Base64>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "files"]
+pub struct NewFile {
+    data: Base64,
+    thumbnail: Option<Base64>,
+}
+
+wundergraph::query_object! {
+    Query {
+        File,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        File(insert = NewFile,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_binary_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    let setup = if cfg!(feature = "postgres") {
+        r"
+CREATE TABLE files(id SERIAL PRIMARY KEY, data BYTEA NOT NULL, thumbnail BYTEA);
+INSERT INTO files(data, thumbnail) VALUES ('\x00ff10', '\x0102'), ('\xaa', NULL);
+"
+    } else {
+        "
+CREATE TABLE files(id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, data BLOB NOT NULL, thumbnail BLOB);
+INSERT INTO files(data, thumbnail) VALUES (X'00ff10', X'0102'), (X'aa', NULL);
+"
+    };
+    get_test_schema(setup)
+}
+
+#[test]
+fn base64_from_input_value() {
+    let decode = |v| Base64::from_input_value(&InputValue::scalar(v));
+
+    assert_eq!(decode("AP8Q"), Some(Base64(vec![0, 255, 16])));
+    assert_eq!(decode(""), Some(Base64(Vec::new())));
+    assert_eq!(decode("not base64!"), None);
+    assert_eq!(Base64::from_input_value(&InputValue::scalar(42)), None);
+}
+
+#[test]
+fn binary_round_trip() {
+    let (schema, pool) = get_binary_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewFile {
+    CreateFile(NewFile: {data: "aGVsbG8=", thumbnail: null}) {
+        data
+        thumbnail
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateFile": {
+      "data": "aGVsbG8=",
+      "thumbnail": null
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Files(order: [{column: id}]) {
+        id
+        data
+        thumbnail
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Files": [
+      {
+        "data": "AP8Q",
+        "id": 1,
+        "thumbnail": "AQI="
+      },
+      {
+        "data": "qg==",
+        "id": 2,
+        "thumbnail": null
+      },
+      {
+        "data": "aGVsbG8=",
+        "id": 3,
+        "thumbnail": null
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+fn file_ids(
+    schema: &Schema,
+    ctx: &MyContext<DbConnection>,
+    filter: serde_json::Value,
+) -> serde_json::Value {
+    let res = execute_query_with_variables(
+        schema,
+        ctx,
+        r#"
+query Files($filter: FileFilter) {
+    Files(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("filter", filter)],
+    );
+    assert!(res.is_ok());
+    res.as_json()[0]["Files"].clone()
+}
+
+#[test]
+fn binary_filter() {
+    let (schema, pool) = get_binary_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Files(filter: {data: {eq: "AP8Q"}}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Files": [
+      {
+        "id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let filter = |filter| file_ids(&schema, &ctx, filter);
+    assert_eq!(filter(json!({"data": {"eq": "qg=="}})), json!([{"id": 2}]));
+    assert_eq!(
+        filter(json!({"data": {"not_eq": "AP8Q"}})),
+        json!([{"id": 2}])
+    );
+    assert_eq!(
+        filter(json!({"data": {"eq_any": ["AP8Q", "qg=="]}})),
+        json!([{"id": 1}, {"id": 2}])
+    );
+    assert_eq!(
+        filter(json!({"thumbnail": {"eq": "AQI="}})),
+        json!([{"id": 1}])
+    );
+    assert_eq!(
+        filter(json!({"thumbnail": {"is_null": true}})),
+        json!([{"id": 2}])
+    );
+    assert_eq!(
+        filter(json!({"thumbnail": {"is_null": false}})),
+        json!([{"id": 1}])
+    );
+}
+
+#[test]
+fn invalid_base64_is_rejected() {
+    let (schema, pool) = get_binary_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewFile {
+    CreateFile(NewFile: {data: "not base64!"}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(!res.is_ok());
+}
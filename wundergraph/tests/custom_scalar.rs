@@ -0,0 +1,256 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Text;
+use juniper::GraphQLInputObject;
+use serde_json::json;
+use std::io::Write;
+use wundergraph::scalar::{CustomScalar, WundergraphScalarValue};
+use wundergraph::{WundergraphEntity, WundergraphScalar};
+use wundergraph_example::MyContext;
+
+#[derive(Debug, Clone, AsExpression, FromSqlRow, WundergraphScalar)]
+#[sql_type = "Text"]
+/// A ip address in the textual representation
+pub struct IpAddress(std::net::IpAddr);
+
+impl CustomScalar for IpAddress {
+    fn to_scalar_value(&self) -> WundergraphScalarValue {
+        WundergraphScalarValue::String(self.0.to_string())
+    }
+
+    fn from_scalar_value(value: &WundergraphScalarValue) -> Option<Self> {
+        match *value {
+            WundergraphScalarValue::String(ref s) => s.parse().ok().map(IpAddress),
+            _ => None,
+        }
+    }
+}
+
+impl<DB> ToSql<Text, DB> for IpAddress
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<'_, W, DB>) -> serialize::Result {
+        self.0.to_string().as_str().to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for IpAddress
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        Ok(IpAddress(String::from_sql(bytes)?.parse()?))
+    }
+}
+
+table! {
+    servers {
+        id -> Integer,
+        address -> Text,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "servers"]
+pub struct Server {
+    id: i32,
+    address: IpAddress,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "servers"]
+pub struct NewServer {
+    address: IpAddress,
+}
+
+wundergraph::query_object! {
+    Query {
+        Server,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Server(insert = NewServer,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_server_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    let create_table = if cfg!(feature = "postgres") {
+        "CREATE TABLE servers(id SERIAL PRIMARY KEY, address TEXT NOT NULL);"
+    } else {
+        "CREATE TABLE servers(id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, address TEXT NOT NULL);"
+    };
+    get_test_schema(&format!(
+        "{}\nINSERT INTO servers(address) VALUES ('127.0.0.1'), ('::1');",
+        create_table
+    ))
+}
+
+#[test]
+fn custom_scalar_is_registered() {
+    let (schema, pool) = get_server_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    __type(name: "IpAddress") {
+        name
+        kind
+        description
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "__type": {
+      "description": "A ip address in the textual representation",
+      "kind": "SCALAR",
+      "name": "IpAddress"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn custom_scalar_round_trip() {
+    let (schema, pool) = get_server_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewServer {
+    CreateServer(NewServer: {address: "192.168.0.1"}) {
+        id
+        address
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateServer": {
+      "address": "192.168.0.1",
+      "id": 3
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Servers(order: [{column: id}]) {
+        address
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Servers": [
+      {
+        "address": "127.0.0.1"
+      },
+      {
+        "address": "::1"
+      },
+      {
+        "address": "192.168.0.1"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn custom_scalar_filter() {
+    let (schema, pool) = get_server_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let server_ids = |filter| {
+        let res = execute_query_with_variables(
+            &schema,
+            &ctx,
+            r#"
+query Servers($filter: ServerFilter) {
+    Servers(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+            &[("filter", json!({ "address": filter }))],
+        );
+        assert!(res.is_ok());
+        res.as_json()[0]["Servers"].clone()
+    };
+
+    assert_eq!(server_ids(json!({"eq": "::1"})), json!([{"id": 2}]));
+    assert_eq!(server_ids(json!({"not_eq": "::1"})), json!([{"id": 1}]));
+    assert_eq!(
+        server_ids(json!({"eq_any": ["127.0.0.1", "::1"]})),
+        json!([{"id": 1}, {"id": 2}])
+    );
+}
+
+#[test]
+fn invalid_custom_scalar_is_rejected() {
+    let (schema, pool) = get_server_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+mutation NewServer($address: IpAddress!) {
+    CreateServer(NewServer: {address: $address}) {
+        id
+    }
+}
+"#,
+        &[("address", json!("not an address"))],
+    );
+    assert!(res.is_err());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewServer {
+    CreateServer(NewServer: {address: 42}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
@@ -0,0 +1,357 @@
+use crate::helper::*;
+use crate::DbConnection;
+use chrono::{NaiveDate, NaiveTime};
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::{FromInputValue, GraphQLInputObject, InputValue};
+use serde_json::json;
+use wundergraph::query_builder::types::IsoNaiveDateTime;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::MyContext;
+
+#[cfg(feature = "postgres")]
+table! {
+    events {
+        id -> Integer,
+        day -> Date,
+        starts_at -> Time,
+        created_at -> Timestamp,
+        updated_at -> Timestamptz,
+        published_at -> Timestamptz,
+        duration -> Interval,
+    }
+}
+
+#[cfg(feature = "sqlite")]
+table! {
+    events {
+        id -> Integer,
+        day -> Date,
+        starts_at -> Time,
+        created_at -> Timestamp,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "events"]
+pub struct Event {
+    id: i32,
+    day: NaiveDate,
+    starts_at: NaiveTime,
+    created_at: IsoNaiveDateTime,
+    #[cfg(feature = "postgres")]
+    updated_at: chrono::DateTime<chrono::Utc>,
+    #[cfg(feature = "postgres")]
+    published_at: chrono::DateTime<chrono::FixedOffset>,
+    #[cfg(feature = "postgres")]
+    duration: diesel::pg::data_types::PgInterval,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "events"]
+pub struct NewEvent {
+    day: NaiveDate,
+    starts_at: NaiveTime,
+    created_at: IsoNaiveDateTime,
+    #[cfg(feature = "postgres")]
+    updated_at: chrono::DateTime<chrono::Utc>,
+    #[cfg(feature = "postgres")]
+    published_at: chrono::DateTime<chrono::FixedOffset>,
+    #[cfg(feature = "postgres")]
+    duration: diesel::pg::data_types::PgInterval,
+}
+
+wundergraph::query_object! {
+    Query {
+        Event,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Event(insert = NewEvent,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_chrono_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    let setup = if cfg!(feature = "postgres") {
+        "
+CREATE TABLE events(
+    id SERIAL PRIMARY KEY,
+    day DATE NOT NULL,
+    starts_at TIME NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    published_at TIMESTAMPTZ NOT NULL,
+    duration INTERVAL NOT NULL
+);
+INSERT INTO events(day, starts_at, created_at, updated_at, published_at, duration) VALUES
+    ('2019-01-01', '08:00:00', '2019-01-01 08:00:00', '2019-01-01 08:00:00+00', '2019-01-02 08:00:00+00', '1 hour'),
+    ('2019-06-15', '12:30:00.5', '2019-06-15 12:30:00', '2019-06-15 12:30:00+02', '2019-06-16 12:30:00+02', '1 day'),
+    ('2020-02-29', '23:59:59', '2020-02-29 23:59:59', '2020-02-29 23:59:59-05', '2020-03-01 23:59:59-05', '1 mon 30 min');
+"
+    } else {
+        "
+CREATE TABLE events(
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    -- Sqlite compares the values as text, so use the format written by diesel
+    day TEXT NOT NULL,
+    starts_at TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+INSERT INTO events(day, starts_at, created_at) VALUES
+    ('2019-01-01', '08:00:00', '2019-01-01 08:00:00'),
+    ('2019-06-15', '12:30:00.500', '2019-06-15 12:30:00'),
+    ('2020-02-29', '23:59:59', '2020-02-29 23:59:59');
+"
+    };
+    get_test_schema(setup)
+}
+
+fn event_ids(
+    schema: &Schema,
+    ctx: &MyContext<DbConnection>,
+    filter: serde_json::Value,
+) -> serde_json::Value {
+    let res = execute_query_with_variables(
+        schema,
+        ctx,
+        r#"
+query Events($filter: EventFilter) {
+    Events(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("filter", filter)],
+    );
+    assert!(res.is_ok());
+    res.as_json()[0]["Events"].clone()
+}
+
+#[test]
+fn iso_naive_date_time_from_input_value() {
+    let parse = |v| IsoNaiveDateTime::from_input_value(&InputValue::scalar(v));
+    let expected = NaiveDate::from_ymd(2019, 6, 15).and_hms(12, 30, 0);
+
+    assert_eq!(
+        parse("2019-06-15T12:30:00"),
+        Some(IsoNaiveDateTime(expected))
+    );
+    assert_eq!(
+        parse("2019-06-15T12:30:00.5"),
+        Some(IsoNaiveDateTime(
+            expected + chrono::Duration::milliseconds(500)
+        ))
+    );
+    assert_eq!(parse("2019-06-15T12:30:00+02:00"), None);
+    assert_eq!(
+        IsoNaiveDateTime::from_input_value(&InputValue::scalar(1_560_601_800.0)),
+        None
+    );
+}
+
+#[test]
+fn chrono_output_format() {
+    let (schema, pool) = get_chrono_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Event(primaryKey: {id: 2}) {
+        day
+        starts_at
+        created_at
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Event": {
+      "created_at": "2019-06-15T12:30:00",
+      "day": "2019-06-15",
+      "starts_at": "12:30:00.500"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn date_and_time_comparison_filter() {
+    let (schema, pool) = get_chrono_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Events(filter: {day: {gt: "2019-01-01", lt: "2020-01-01"}}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Events": [
+      {
+        "id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let filter = |filter| event_ids(&schema, &ctx, filter);
+    assert_eq!(
+        filter(json!({"day": {"ge": "2019-06-15"}})),
+        json!([{"id": 2}, {"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"day": {"eq": "2020-02-29"}})),
+        json!([{"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"starts_at": {"lt": "12:30:00.5"}})),
+        json!([{"id": 1}])
+    );
+    assert_eq!(
+        filter(json!({"starts_at": {"le": "12:30:00.5"}})),
+        json!([{"id": 1}, {"id": 2}])
+    );
+    assert_eq!(
+        filter(json!({"created_at": {"ge": "2019-06-15T12:30:00"}})),
+        json!([{"id": 2}, {"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"created_at": {"lt": "2019-06-15T12:30:00"}})),
+        json!([{"id": 1}])
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn timestamptz_and_interval_comparison_filter() {
+    let (schema, pool) = get_chrono_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Events(order: [{column: id}]) {
+        updated_at
+        duration
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Events": [
+      {
+        "duration": "PT1H",
+        "updated_at": "2019-01-01T08:00:00+00:00"
+      },
+      {
+        "duration": "P1D",
+        "updated_at": "2019-06-15T10:30:00+00:00"
+      },
+      {
+        "duration": "P1MT30M",
+        "updated_at": "2020-03-01T04:59:59+00:00"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let filter = |filter| event_ids(&schema, &ctx, filter);
+    assert_eq!(
+        filter(json!({"updated_at": {"gt": "2019-06-15T11:00:00Z"}})),
+        json!([{"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"updated_at": {"le": "2019-06-15T12:30:00+02:00"}})),
+        json!([{"id": 1}, {"id": 2}])
+    );
+    assert_eq!(
+        filter(json!({"duration": {"gt": "PT1H"}})),
+        json!([{"id": 2}, {"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"duration": {"eq": "P1MT30M"}})),
+        json!([{"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"duration": {"lt": "P1D"}})),
+        json!([{"id": 1}])
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn fixed_offset_date_time() {
+    let (schema, pool) = get_chrono_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Events(order: [{column: id}]) {
+        published_at
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Events": [
+      {
+        "published_at": "2019-01-02T08:00:00+00:00"
+      },
+      {
+        "published_at": "2019-06-16T10:30:00+00:00"
+      },
+      {
+        "published_at": "2020-03-02T04:59:59+00:00"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let filter = |filter| event_ids(&schema, &ctx, filter);
+    assert_eq!(
+        filter(json!({"published_at": {"ge": "2019-06-16T12:30:00+02:00"}})),
+        json!([{"id": 2}, {"id": 3}])
+    );
+    assert_eq!(
+        filter(json!({"published_at": {"lt": "2019-06-16T10:30:00Z"}})),
+        json!([{"id": 1}])
+    );
+}
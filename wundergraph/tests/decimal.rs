@@ -0,0 +1,228 @@
+use crate::helper::*;
+use crate::DbConnection;
+use bigdecimal::BigDecimal;
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::{FromInputValue, GraphQLInputObject, InputValue};
+use serde_json::json;
+use std::str::FromStr;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::MyContext;
+
+table! {
+    products {
+        id -> Integer,
+        price -> Numeric,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "products"]
+pub struct Product {
+    id: i32,
+    price: BigDecimal,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "products"]
+pub struct NewProduct {
+    price: BigDecimal,
+}
+
+wundergraph::query_object! {
+    Query {
+        Product,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Product(insert = NewProduct,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_decimal_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    get_test_schema(
+        "
+CREATE TABLE products(id SERIAL PRIMARY KEY, price NUMERIC NOT NULL);
+INSERT INTO products(price) VALUES (0.1), (19.99), (42);
+",
+    )
+}
+
+fn decimal(value: InputValue<WundergraphScalarValue>) -> Option<BigDecimal> {
+    BigDecimal::from_input_value(&value)
+}
+
+#[test]
+fn decimal_from_input_value() {
+    let expected = |s| Some(BigDecimal::from_str(s).unwrap());
+
+    assert_eq!(decimal(InputValue::scalar("19.99")), expected("19.99"));
+    assert_eq!(
+        decimal(InputValue::scalar("12345678901234567890.123")),
+        expected("12345678901234567890.123")
+    );
+    assert_eq!(decimal(InputValue::scalar(42)), expected("42"));
+    assert_eq!(
+        decimal(InputValue::scalar(WundergraphScalarValue::BigInt(1 << 40))),
+        expected("1099511627776")
+    );
+    assert_eq!(decimal(InputValue::scalar(0.1f64)), expected("0.1"));
+    assert_eq!(
+        decimal(InputValue::scalar(WundergraphScalarValue::Float(2.5))),
+        expected("2.5")
+    );
+    assert_eq!(decimal(InputValue::scalar(std::f64::NAN)), None);
+    assert_eq!(decimal(InputValue::scalar("abc")), None);
+    assert_eq!(decimal(InputValue::scalar(true)), None);
+    assert_eq!(decimal(InputValue::null()), None);
+}
+
+#[test]
+fn decimal_round_trip() {
+    let (schema, pool) = get_decimal_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewProduct {
+    CreateProduct(NewProduct: {price: 1.25}) {
+        price
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateProduct": {
+      "price": "1.25"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+mutation NewProduct($price: Decimal!) {
+    CreateProduct(NewProduct: {price: $price}) {
+        price
+    }
+}
+"#,
+        &[("price", json!(0.3))],
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateProduct": {
+      "price": "0.3"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn decimal_keeps_precision() {
+    let (schema, pool) = get_decimal_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewProduct {
+    CreateProduct(NewProduct: {price: "12345678901234567890.123"}) {
+        price
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateProduct": {
+      "price": "12345678901234567890.123"
+    }
+  },
+  []
+]"###
+    );
+}
+
+fn product_ids(
+    schema: &Schema,
+    ctx: &MyContext<DbConnection>,
+    filter: serde_json::Value,
+) -> serde_json::Value {
+    let res = execute_query_with_variables(
+        schema,
+        ctx,
+        r#"
+query Products($filter: ProductFilter) {
+    Products(filter: $filter, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("filter", filter)],
+    );
+    assert!(res.is_ok());
+    res.as_json()[0]["Products"].clone()
+}
+
+#[test]
+fn decimal_comparison_filter() {
+    let (schema, pool) = get_decimal_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Products(filter: {price: {gt: "0.1", lt: 42}}) {
+        price
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Products": [
+      {
+        "price": "19.99"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let price = |filter| product_ids(&schema, &ctx, json!({ "price": filter }));
+    assert_eq!(price(json!({"eq": 0.1})), json!([{"id": 1}]));
+    assert_eq!(price(json!({"eq": "19.99"})), json!([{"id": 2}]));
+    assert_eq!(price(json!({"not_eq": 42})), json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(price(json!({"ge": 19.99})), json!([{"id": 2}, {"id": 3}]));
+    assert_eq!(price(json!({"le": "19.99"})), json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(
+        price(json!({"eq_any": [0.1, "42"]})),
+        json!([{"id": 1}, {"id": 3}])
+    );
+}
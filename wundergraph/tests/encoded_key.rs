@@ -0,0 +1,494 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::{GraphQLInputObject, LookAheadSelection};
+use serde_json::json;
+use std::any::Any;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::query_builder::types::{EncodedKey, HasMany, HasOne, KeyCodec};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+table! {
+    heros {
+        id -> Integer,
+        name -> Text,
+        species -> Integer,
+        home_world -> Nullable<Integer>,
+    }
+}
+
+table! {
+    home_worlds {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+/// The secret used to encode keys, differs between contexts
+pub struct KeySecret(i32);
+
+pub struct HeroCodec;
+
+impl KeyCodec<i32> for HeroCodec {
+    const NAME: &'static str = "HeroKey";
+    type Context = KeySecret;
+
+    fn encode(secret: &KeySecret, key: &i32) -> String {
+        format!("h{:x}", key ^ secret.0)
+    }
+
+    fn decode(secret: &KeySecret, value: &str) -> Option<i32> {
+        if value.starts_with('h') {
+            i32::from_str_radix(&value[1..], 16)
+                .ok()
+                .map(|key| key ^ secret.0)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct HomeWorldCodec;
+
+impl KeyCodec<i32> for HomeWorldCodec {
+    const NAME: &'static str = "HomeWorldKey";
+    type Context = KeySecret;
+
+    fn encode(secret: &KeySecret, key: &i32) -> String {
+        format!("w{:x}", key ^ secret.0)
+    }
+
+    fn decode(secret: &KeySecret, value: &str) -> Option<i32> {
+        if value.starts_with('w') {
+            i32::from_str_radix(&value[1..], 16)
+                .ok()
+                .map(|key| key ^ secret.0)
+        } else {
+            None
+        }
+    }
+}
+
+type HeroKey = EncodedKey<i32, HeroCodec>;
+type HomeWorldKey = EncodedKey<i32, HomeWorldCodec>;
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "heros"]
+#[wundergraph(key_codec = "HeroCodec")]
+pub struct Hero {
+    id: HeroKey,
+    name: String,
+    home_world: Option<HasOne<HomeWorldKey, HomeWorld>>,
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "home_worlds"]
+#[wundergraph(key_codec = "HomeWorldCodec")]
+pub struct HomeWorld {
+    id: HomeWorldKey,
+    name: String,
+    heros: HasMany<Hero, heros::home_world>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "heros"]
+pub struct NewHero {
+    name: String,
+    species: i32,
+    home_world: Option<HomeWorldKey>,
+}
+
+#[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "heros"]
+pub struct HeroChangeset {
+    id: HeroKey,
+    name: Option<String>,
+    home_world: Option<HomeWorldKey>,
+}
+
+wundergraph::query_object! {
+    Query {
+        Hero,
+        HomeWorld,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        Hero(insert = NewHero, update = HeroChangeset,),
+    }
+}
+
+type Schema =
+    juniper::RootNode<'static, Query<KeyContext>, Mutation<KeyContext>, WundergraphScalarValue>;
+
+struct KeyContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+    secret: KeySecret,
+}
+
+impl WundergraphContext for KeyContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+
+    fn key_codec_context(&self) -> &dyn Any {
+        &self.secret
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for KeyContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+const SECRET: i32 = 0x5a5a;
+
+fn get_key_context(secret: i32) -> (Schema, KeyContext) {
+    let (_, pool) = get_example_schema();
+    let ctx = KeyContext {
+        conn: pool.get().unwrap(),
+        secret: KeySecret(secret),
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+fn hero_key(key: i32) -> String {
+    format!("h{:x}", key ^ SECRET)
+}
+
+fn home_world_key(key: i32) -> String {
+    format!("w{:x}", key ^ SECRET)
+}
+
+#[test]
+fn keys_are_encoded_in_results() {
+    let (schema, ctx) = get_key_context(SECRET);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: [{column: id}], limit: 2) {
+        id
+        name
+        home_world {
+            id
+            name
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "Heros": [
+                    {
+                        "id": hero_key(1),
+                        "name": "Luke Skywalker",
+                        "home_world": {"id": home_world_key(1), "name": "Tatooine"},
+                    },
+                    {
+                        "id": hero_key(2),
+                        "name": "Darth Vader",
+                        "home_world": {"id": home_world_key(1), "name": "Tatooine"},
+                    },
+                ],
+            },
+            [],
+        ])
+    );
+
+    // Each context uses its own secret
+    let (schema, ctx) = get_key_context(0x1234);
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Hero(primaryKey: {id: \"h1235\"}) {
+        id
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"Hero": {"id": "h1235", "name": "Luke Skywalker"}}, []])
+    );
+}
+
+#[test]
+fn keys_are_decoded_in_arguments_and_filters() {
+    let (schema, ctx) = get_key_context(SECRET);
+
+    let query = format!(
+        r#"
+{{
+    Hero(primaryKey: {{id: "{}"}}) {{
+        name
+    }}
+}}
+"#,
+        hero_key(4)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"Hero": {"name": "Leia Organa"}}, []])
+    );
+
+    let query = format!(
+        r#"
+{{
+    Heros(filter: {{home_world: {{id: {{eq: "{}"}}}}}}, order: [{{column: id}}]) {{
+        name
+    }}
+}}
+"#,
+        home_world_key(1)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"Heros": [{"name": "Luke Skywalker"}, {"name": "Darth Vader"}]}, []])
+    );
+
+    let query = format!(
+        r#"
+{{
+    HomeWorlds(filter: {{id: {{eq_any: ["{}"]}}}}) {{
+        name
+    }}
+}}
+"#,
+        home_world_key(2)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"HomeWorlds": [{"name": "Alderaan"}]}, []])
+    );
+
+    // Plain keys are not accepted
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {eq: \"1\"}}) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  null,
+  [
+    {
+      "locations": [
+        {
+          "column": 5,
+          "line": 3
+        }
+      ],
+      "message": "Could not build filter from argument `Heros.filter.id.eq`: value does not match the type of the field",
+      "path": [
+        "Heros"
+      ]
+    }
+  ]
+]"###
+    );
+}
+
+#[test]
+fn keys_are_decoded_in_variables() {
+    let (schema, ctx) = get_key_context(SECRET);
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        "
+query HeroByKey($key: HeroKey!) {
+    Hero(primaryKey: {id: $key}) {
+        id
+        name
+    }
+}
+",
+        &[("key", json!(hero_key(3)))],
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"Hero": {"id": hero_key(3), "name": "Han Solo"}}, []])
+    );
+}
+
+#[test]
+fn keys_are_decoded_in_mutations() {
+    let (schema, ctx) = get_key_context(SECRET);
+
+    let query = format!(
+        r#"
+mutation {{
+    CreateHero(NewHero: {{name: "Obi-Wan Kenobi", species: 1, homeWorld: "{}"}}) {{
+        id
+        home_world {{
+            name
+        }}
+    }}
+}}
+"#,
+        home_world_key(1)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[0]["CreateHero"]["home_world"],
+        json!({"name": "Tatooine"})
+    );
+    // The sequence is not reset by the test transaction
+    let id = res[0]["CreateHero"]["id"].as_str().unwrap().to_owned();
+    assert!(i32::from_str_radix(&id[1..], 16).unwrap() ^ SECRET > 5);
+
+    let query = format!(
+        r#"
+mutation {{
+    UpdateHero(UpdateHero: {{id: "{}", homeWorld: "{}"}}) {{
+        name
+        home_world {{
+            id
+        }}
+    }}
+}}
+"#,
+        id,
+        home_world_key(2)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "UpdateHero": {
+                    "name": "Obi-Wan Kenobi",
+                    "home_world": {"id": home_world_key(2)},
+                },
+            },
+            [],
+        ])
+    );
+
+    let query = format!(
+        r#"
+mutation {{
+    DeleteHero(DeleteHero: {{id: "{}"}}) {{
+        count
+    }}
+}}
+"#,
+        id
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"DeleteHero": {"count": 1}}, []]));
+
+    let query = format!(
+        r#"
+{{
+    Hero(primaryKey: {{id: "{}"}}) {{
+        name
+    }}
+}}
+"#,
+        id
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"Hero": null}, []]));
+}
+
+#[test]
+fn keys_of_other_contexts_are_not_decoded_to_the_same_row() {
+    let (schema, ctx) = get_key_context(0x1234);
+
+    let query = format!(
+        r#"
+{{
+    Heros(filter: {{id: {{eq: "{}"}}}}) {{
+        name
+    }}
+}}
+"#,
+        hero_key(1)
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"Heros": []}, []]));
+}
+
+#[test]
+fn keys_are_compared_by_their_plain_value() {
+    use juniper::{FromInputValue, InputValue, ToInputValue};
+    use std::collections::HashSet;
+    use wundergraph::query_builder::types::with_key_codec_context;
+
+    // Keys received while no codec context is available stay encoded
+    let encoded = HeroKey::from_input_value(&InputValue::scalar(hero_key(1))).unwrap();
+    assert_eq!(encoded.key(), None);
+    // Without a codec context keys could not be encoded
+    assert_eq!(HeroKey::new(1).to_input_value(), InputValue::null());
+
+    with_key_codec_context(&KeySecret(SECRET), || {
+        assert_eq!(encoded, HeroKey::new(1));
+        assert_ne!(encoded, HeroKey::new(2));
+        let keys = vec![encoded.clone(), HeroKey::new(1), HeroKey::new(2)]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(
+            HeroKey::new(1).to_input_value(),
+            InputValue::scalar(hero_key(1))
+        );
+    });
+
+    // A context of the wrong type is reported as error instead of a panic
+    with_key_codec_context(&42, || {
+        assert_eq!(HeroKey::new(1).to_input_value(), InputValue::null());
+        assert_ne!(encoded, HeroKey::new(1));
+    });
+}
@@ -0,0 +1,268 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::{GraphQLEnum, GraphQLInputObject};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::{WundergraphEntity, WundergraphValue};
+use wundergraph_example::MyContext;
+
+#[cfg(feature = "postgres")]
+pub mod sql_types {
+    #[derive(Debug, Clone, Copy, QueryId, SqlType)]
+    #[postgres(type_name = "weather")]
+    pub struct Weather;
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, AsExpression, FromSqlRow, GraphQLEnum, WundergraphValue,
+)]
+#[sql_type = "diesel::sql_types::Text"]
+#[wundergraph(sql_conversions)]
+pub enum Mood {
+    Happy,
+    #[wundergraph(sql_name = "so-so")]
+    SoSo,
+}
+
+#[cfg(feature = "postgres")]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, AsExpression, FromSqlRow, GraphQLEnum, WundergraphValue,
+)]
+#[sql_type = "sql_types::Weather"]
+#[wundergraph(pg_enum)]
+pub enum Weather {
+    Sunny,
+    #[wundergraph(sql_name = "partly cloudy")]
+    PartlyCloudy,
+}
+
+#[cfg(feature = "postgres")]
+table! {
+    use diesel::sql_types::*;
+    use crate::enums::sql_types::Weather;
+
+    diary_entries {
+        id -> Integer,
+        mood -> Text,
+        weather -> Nullable<Weather>,
+    }
+}
+
+#[cfg(feature = "sqlite")]
+table! {
+    diary_entries {
+        id -> Integer,
+        mood -> Text,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "diary_entries"]
+pub struct DiaryEntry {
+    id: i32,
+    mood: Mood,
+    #[cfg(feature = "postgres")]
+    weather: Option<Weather>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "diary_entries"]
+pub struct NewDiaryEntry {
+    mood: Mood,
+    #[cfg(feature = "postgres")]
+    weather: Option<Weather>,
+}
+
+wundergraph::query_object! {
+    Query {
+        DiaryEntry,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        DiaryEntry(insert = NewDiaryEntry,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_enum_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    let setup = if cfg!(feature = "postgres") {
+        "
+CREATE TYPE weather AS ENUM ('sunny', 'partly cloudy');
+CREATE TABLE diary_entries(id SERIAL PRIMARY KEY, mood TEXT NOT NULL, weather weather);
+INSERT INTO diary_entries(mood, weather) VALUES ('happy', 'sunny'), ('so-so', 'partly cloudy');
+"
+    } else {
+        "
+CREATE TABLE diary_entries(id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, mood TEXT NOT NULL);
+INSERT INTO diary_entries(mood) VALUES ('happy'), ('so-so');
+"
+    };
+    get_test_schema(setup)
+}
+
+#[test]
+fn text_enum_round_trip() {
+    let (schema, pool) = get_enum_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewDiaryEntry {
+    CreateDiaryEntry(NewDiaryEntry: {mood: SO_SO}) {
+        mood
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateDiaryEntry": {
+      "mood": "SO_SO"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    DiaryEntrys(filter: {mood: {eq: SO_SO}}, order: [{column: id}]) {
+        id
+        mood
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DiaryEntrys": [
+      {
+        "id": 2,
+        "mood": "SO_SO"
+      },
+      {
+        "id": 3,
+        "mood": "SO_SO"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn unknown_enum_value_returns_an_error() {
+    let (schema, pool) = get_enum_schema();
+    let conn = pool.get().unwrap();
+    conn.batch_execute("INSERT INTO diary_entries(mood) VALUES ('angry');")
+        .unwrap();
+    let ctx = MyContext::new(conn);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    DiaryEntrys {
+        mood
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  null,
+  [
+    {
+      "locations": [
+        {
+          "column": 5,
+          "line": 3
+        }
+      ],
+      "message": "Unrecognized value `angry` for enum `Mood`",
+      "path": [
+        "DiaryEntrys"
+      ]
+    }
+  ]
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn native_postgres_enum_round_trip() {
+    let (schema, pool) = get_enum_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewDiaryEntry {
+    CreateDiaryEntry(NewDiaryEntry: {mood: HAPPY, weather: PARTLY_CLOUDY}) {
+        weather
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateDiaryEntry": {
+      "weather": "PARTLY_CLOUDY"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    DiaryEntrys(filter: {weather: {eq: PARTLY_CLOUDY}}, order: [{column: id}]) {
+        id
+        weather
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DiaryEntrys": [
+      {
+        "id": 2,
+        "weather": "PARTLY_CLOUDY"
+      },
+      {
+        "id": 3,
+        "weather": "PARTLY_CLOUDY"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
@@ -0,0 +1,251 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn full_text_matches() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {matches: "skywalker"}}) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn full_text_matches_all_terms() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let heros = |query: &str| {
+        let res = execute_query_with_variables(
+            &schema,
+            &ctx,
+            r#"
+query Heros($query: String!) {
+    Heros(filter: {heroName: {matches: $query}}) {
+        heroName
+    }
+}
+"#,
+            &[("query", serde_json::json!(query))],
+        );
+        assert!(res.is_ok());
+        res.as_json()
+    };
+
+    assert_json_snapshot_matches!(
+        heros("Vader, Darth"), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Darth Vader"
+      }
+    ]
+  },
+  []
+]"###
+    );
+    assert_json_snapshot_matches!(
+        heros("Darth Skywalker"), @r###"[
+  {
+    "Heros": []
+  },
+  []
+]"###
+    );
+    assert_json_snapshot_matches!(
+        heros(""), @r###"[
+  {
+    "Heros": []
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn full_text_matches_combined_with_other_filters() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {heroName: {matches: "skywalker", like: "%Skywalker"}, species: {name: {eq: "Human"}}}) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn full_text_order_by_relevance() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "Luke", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+
+    let heros = |query: &str| {
+        let res = execute_query(&schema, &ctx, query);
+        assert!(res.is_ok());
+        res.as_json()
+    };
+
+    assert_json_snapshot_matches!(
+        heros(
+            r#"
+{
+    Heros(
+        filter: {heroName: {matches: "luke"}},
+        order: [{column: heroName, direction: DESC, relevance: "luke skywalker"}]
+    ) {
+        heroName
+    }
+}
+"#
+        ), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      },
+      {
+        "heroName": "Luke"
+      }
+    ]
+  },
+  []
+]"###
+    );
+    assert_json_snapshot_matches!(
+        heros(
+            r#"
+{
+    Heros(
+        filter: {heroName: {matches: "luke"}},
+        order: [{column: heroName, direction: ASC, relevance: "luke skywalker"}]
+    ) {
+        heroName
+    }
+}
+"#
+        ), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke"
+      },
+      {
+        "heroName": "Luke Skywalker"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn full_text_order_by_relevance_only_for_full_text_columns() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(order: [{column: hair_color, relevance: "blond"}]) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["Heros"], serde_json::Value::Null);
+    assert_json_snapshot_matches!(
+        res[1][0]["message"].clone(), @r###""Could not build filter from argument `Heros.order[0].relevance`: hair_color is not a full text column""###
+    );
+
+    // Entities without full text columns do not provide the argument
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess(order: [{column: name, relevance: "human"}]) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn full_text_matches_only_for_opted_in_columns() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Species(filter: {name: {matches: "Human"}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 use crate::DbConnection;
+use diesel::connection::SimpleConnection;
 use diesel::r2d2::CustomizeConnection;
 use diesel::r2d2::*;
 use diesel::Connection;
@@ -54,6 +55,23 @@ pub fn get_example_schema() -> (
     (ExampleSchema::new(query, mutation), pool)
 }
 
+/// A schema built from the query and mutation objects of a single test
+pub type TestSchema<Q, M> = RootNode<'static, Q, M, WundergraphScalarValue>;
+
+/// Execute `setup` to create the tables used by a test in the example
+/// database and build a schema from the given query and mutation objects
+pub fn get_test_schema<Q, M>(
+    setup: &str,
+) -> (TestSchema<Q, M>, Pool<ConnectionManager<DbConnection>>)
+where
+    Q: GraphQLType<WundergraphScalarValue, TypeInfo = ()> + Default,
+    M: GraphQLType<WundergraphScalarValue, TypeInfo = ()> + Default,
+{
+    let (_, pool) = get_example_schema();
+    pool.get().unwrap().batch_execute(setup).unwrap();
+    (TestSchema::new(Q::default(), M::default()), pool)
+}
+
 pub fn get_bench_schema() -> (
     BenchSchema<DbConnection>,
     Pool<ConnectionManager<DbConnection>>,
@@ -0,0 +1,263 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::LookAheadSelection;
+use wundergraph::context::WundergraphContext;
+use wundergraph::instrumentation::{ApolloTracing, Instrumentation, SqlDebug};
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{Query, Schema};
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+struct InstrumentedContext<I> {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+    instrumentation: I,
+}
+
+impl<I> WundergraphContext for InstrumentedContext<I>
+where
+    I: Instrumentation + 'static,
+{
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+
+    fn instrumentation(&self) -> Option<&dyn Instrumentation> {
+        Some(&self.instrumentation)
+    }
+}
+
+impl<I, T> QueryModifier<T, DbBackend> for InstrumentedContext<I>
+where
+    I: Instrumentation + 'static,
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+fn get_instrumented_context<I>(
+    instrumentation: I,
+) -> (Schema<InstrumentedContext<I>>, InstrumentedContext<I>)
+where
+    I: Instrumentation + 'static,
+{
+    let (_, pool) = get_example_schema();
+    let ctx = InstrumentedContext {
+        conn: pool.get().unwrap(),
+        instrumentation,
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+// Durations and the sql text itself depend on the used backend
+fn traced_statements(ctx: &InstrumentedContext<ApolloTracing>) -> Vec<serde_json::Value> {
+    let extensions = serde_json::to_value(ctx.instrumentation.extensions()).unwrap();
+    assert_eq!(extensions["tracing"]["version"], 1);
+    extensions["tracing"]["execution"]["statements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| {
+            assert!(s["sql"].as_str().is_some());
+            assert!(s["duration"].as_i64().is_some());
+            assert!(s["startOffset"].as_i64().is_some());
+            serde_json::json!({
+                "path": s["path"],
+                "typeName": s["typeName"],
+                "rows": s["rows"],
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn query_statements_are_traced() {
+    let (schema, ctx) = get_instrumented_context(ApolloTracing::new());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {eq: 1}}) {
+        heroName
+        species {
+            name
+        }
+        appears_in {
+            episode
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+
+    let statements = traced_statements(&ctx);
+    let extensions = serde_json::to_value(ctx.instrumentation.extensions()).unwrap();
+    let root = &extensions["tracing"]["execution"]["statements"][0];
+    assert!(root["sql"].as_str().unwrap().starts_with("SELECT"));
+    assert_eq!(root["bindCount"], 1);
+
+    assert_json_snapshot_matches!(
+        statements, @r###"[
+  {
+    "path": [
+      "Heros"
+    ],
+    "rows": 1,
+    "typeName": "Hero"
+  },
+  {
+    "path": [
+      "Heros",
+      "appears_in"
+    ],
+    "rows": 3,
+    "typeName": "AppearsIn"
+  },
+  {
+    "path": [
+      "Heros",
+      "species"
+    ],
+    "rows": 1,
+    "typeName": "Species"
+  }
+]"###
+    );
+}
+
+#[test]
+fn bind_count_includes_all_binds() {
+    let (schema, ctx) = get_instrumented_context(ApolloTracing::new());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {or: [{heroName: {like: "Leia %"}}, {id: {eq: 1}}, {id: {eq: 2}}]}) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+
+    let extensions = serde_json::to_value(ctx.instrumentation.extensions()).unwrap();
+    let root = &extensions["tracing"]["execution"]["statements"][0];
+    assert_eq!(root["rows"], 3);
+    assert_eq!(root["bindCount"], 3);
+}
+
+#[test]
+fn mutation_statements_are_traced() {
+    let (schema, ctx) = get_instrumented_context(ApolloTracing::new());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHomeWorld {
+  CreateHomeWorld(NewHomeWorld: {name: "Naboo"}) {
+    name
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+
+    let statements = traced_statements(&ctx);
+    // The insert is followed by loading the created entity
+    assert!(statements.len() >= 2);
+    assert_eq!(statements[0]["rows"], 1);
+    for statement in &statements {
+        assert_eq!(statement["path"], serde_json::json!(["CreateHomeWorld"]));
+        assert_eq!(statement["typeName"], "HomeWorld");
+    }
+}
+
+#[test]
+fn sql_debug_requires_privileged_requests() {
+    assert!(SqlDebug::for_request(false).is_none());
+    assert!(SqlDebug::for_request(true).is_some());
+}
+
+#[test]
+fn sql_debug_groups_statements_by_path() {
+    let debug = SqlDebug::for_request(true).unwrap().with_explain(true);
+    let (schema, ctx) = get_instrumented_context(debug);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {eq: 1}}) {
+        heroName
+        species {
+            name
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+
+    let extensions = serde_json::to_value(ctx.instrumentation.extensions()).unwrap();
+    let sql = extensions["wundergraph"]["sql"].as_object().unwrap();
+    assert_eq!(
+        sql.keys().collect::<Vec<_>>(),
+        vec!["Heros", "Heros.species"]
+    );
+    for statements in sql.values() {
+        let statements = statements.as_array().unwrap();
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0]["sql"].as_str().unwrap().starts_with("SELECT"));
+        assert_eq!(statements[0]["rows"], 1);
+        assert!(!statements[0]["explain"].as_array().unwrap().is_empty());
+    }
+}
+
+#[test]
+fn sql_debug_explains_mutations() {
+    let debug = SqlDebug::for_request(true).unwrap().with_explain(true);
+    let (schema, ctx) = get_instrumented_context(debug);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: "Luke"}) {
+    heroName
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["UpdateHero"]["heroName"], "Luke");
+
+    let extensions = serde_json::to_value(ctx.instrumentation.extensions()).unwrap();
+    let statements = extensions["wundergraph"]["sql"]["UpdateHero"]
+        .as_array()
+        .unwrap();
+    assert!(statements.len() >= 2);
+    for statement in statements {
+        assert!(!statement["explain"].as_array().unwrap().is_empty());
+    }
+}
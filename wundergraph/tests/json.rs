@@ -0,0 +1,446 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, Pool};
+use juniper::GraphQLInputObject;
+use serde_json::json;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::MyContext;
+
+#[cfg(feature = "postgres")]
+type Document = serde_json::Value;
+
+#[cfg(feature = "sqlite")]
+type Document = wundergraph::query_builder::types::JsonText;
+
+#[cfg(feature = "postgres")]
+table! {
+    json_documents {
+        id -> Integer,
+        document -> Nullable<Jsonb>,
+    }
+}
+
+#[cfg(feature = "sqlite")]
+table! {
+    json_documents {
+        id -> Integer,
+        document -> Nullable<Text>,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "json_documents"]
+pub struct JsonDocument {
+    id: i32,
+    document: Option<Document>,
+}
+
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
+#[table_name = "json_documents"]
+pub struct NewJsonDocument {
+    document: Option<Document>,
+}
+
+wundergraph::query_object! {
+    Query {
+        JsonDocument,
+    }
+}
+
+wundergraph::mutation_object! {
+    Mutation {
+        JsonDocument(insert = NewJsonDocument,),
+    }
+}
+
+type Schema = TestSchema<Query<MyContext<DbConnection>>, Mutation<MyContext<DbConnection>>>;
+
+fn get_json_schema() -> (Schema, Pool<ConnectionManager<DbConnection>>) {
+    let create_table = if cfg!(feature = "postgres") {
+        "CREATE TABLE json_documents(id SERIAL PRIMARY KEY, document JSONB);"
+    } else {
+        "CREATE TABLE json_documents(id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, document TEXT);"
+    };
+    let insert = r#"
+INSERT INTO json_documents(document) VALUES
+    ('{"a": 1, "b": {"c": "d"}, "e.f": true}'),
+    ('{"b": [1, 2, {"g": null}], "h\"i": "j"}'),
+    ('[1, "a"]'),
+    (NULL);
+"#;
+    get_test_schema(&format!("{}{}", create_table, insert))
+}
+
+#[test]
+fn json_round_trip() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+    let document = json!({
+        "string": "foo",
+        "int": 42,
+        "big_int": 9_007_199_254_740_993i64,
+        "float": 1.5,
+        "bool": false,
+        "null": null,
+        "list": [1, "two", {"three": [3]}],
+    });
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+mutation NewJsonDocument($document: JSON) {
+    CreateJsonDocument(NewJsonDocument: {document: $document}) {
+        id
+        document
+    }
+}
+"#,
+        &[("document", json!(document.to_string()))],
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["CreateJsonDocument"]["document"], document);
+    assert_eq!(res[1], json!([]));
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query JsonDocument($id: Int!) {
+    JsonDocument(primaryKey: {id: $id}) {
+        document
+    }
+}
+"#,
+        &[("id", res[0]["CreateJsonDocument"]["id"].clone())],
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["JsonDocument"]["document"], document);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    JsonDocuments(filter: {id: {eq: 1}}) {
+        document
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "document": {
+          "a": 1,
+          "b": {
+            "c": "d"
+          },
+          "e.f": true
+        }
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+fn document_ids(
+    schema: &Schema,
+    ctx: &MyContext<DbConnection>,
+    filter: serde_json::Value,
+) -> serde_json::Value {
+    let res = execute_query_with_variables(
+        schema,
+        ctx,
+        r#"
+query JsonDocuments($filter: Filter_Nullable_JSON__) {
+    JsonDocuments(filter: {document: $filter}, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("filter", filter)],
+    );
+    assert!(res.is_ok());
+    res.as_json()[0]["JsonDocuments"].clone()
+}
+
+#[test]
+fn json_contains() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query JsonDocuments($value: JSON) {
+    JsonDocuments(filter: {document: {contains: $value}}, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+        &[("value", json!(r#"{"b": {"c": "d"}}"#))],
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let contains = |value: serde_json::Value| {
+        document_ids(&schema, &ctx, json!({ "contains": value.to_string() }))
+    };
+
+    assert_eq!(contains(json!({"b": [2]})), json!([{"id": 2}]));
+    assert_eq!(contains(json!({"b": [{"g": null}]})), json!([{"id": 2}]));
+    assert_eq!(contains(json!({"b": [3]})), json!([]));
+    assert_eq!(contains(json!({"a": 1, "b": {}})), json!([{"id": 1}]));
+    assert_eq!(contains(json!({"a": "1"})), json!([]));
+    assert_eq!(contains(json!(["a"])), json!([{"id": 3}]));
+    assert_eq!(contains(json!({})), json!([{"id": 1}, {"id": 2}]));
+}
+
+#[test]
+fn json_has_key() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    JsonDocuments(filter: {document: {has_key: "b"}}, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "id": 1
+      },
+      {
+        "id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let has_key = |key: &str| document_ids(&schema, &ctx, json!({ "has_key": key }));
+
+    assert_eq!(has_key("c"), json!([]));
+    assert_eq!(has_key("j"), json!([]));
+    assert_eq!(has_key("a"), json!([{"id": 1}, {"id": 3}]));
+    // Keys are not interpreted as paths
+    assert_eq!(has_key("e.f"), json!([{"id": 1}]));
+    assert_eq!(has_key("h\"i"), json!([{"id": 2}]));
+}
+
+#[test]
+fn json_has_any_keys() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    JsonDocuments(filter: {document: {has_any_keys: ["a", "x"]}}, order: [{column: id}]) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    // Like on postgres string elements of arrays match as well
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "id": 1
+      },
+      {
+        "id": 3
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let has_any_keys =
+        |keys: serde_json::Value| document_ids(&schema, &ctx, json!({ "has_any_keys": keys }));
+
+    assert_eq!(
+        has_any_keys(json!(["b", "h\"i"])),
+        json!([{"id": 1}, {"id": 2}])
+    );
+    assert_eq!(has_any_keys(json!(["x", "y"])), json!([]));
+    assert_eq!(has_any_keys(json!([])), json!([]));
+}
+
+#[test]
+fn json_path_eq() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    JsonDocuments(filter: {document: {path_eq: {path: ["b", "c"], value: "\"d\""}}}) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let path_eq = |path: serde_json::Value, value: serde_json::Value| {
+        document_ids(
+            &schema,
+            &ctx,
+            json!({"path_eq": {"path": path, "value": value.to_string()}}),
+        )
+    };
+
+    assert_eq!(path_eq(json!(["b", "1"]), json!(2)), json!([{"id": 2}]));
+    assert_eq!(
+        path_eq(json!(["b", "2", "g"]), json!(null)),
+        json!([{"id": 2}])
+    );
+    assert_eq!(path_eq(json!(["b"]), json!({"c": "d"})), json!([{"id": 1}]));
+    assert_eq!(path_eq(json!(["e.f"]), json!(true)), json!([{"id": 1}]));
+    assert_eq!(path_eq(json!(["h\"i"]), json!("j")), json!([{"id": 2}]));
+    assert_eq!(path_eq(json!(["1"]), json!("a")), json!([{"id": 3}]));
+    assert_eq!(path_eq(json!(["a"]), json!("1")), json!([]));
+}
+
+#[test]
+fn json_from_structured_input() {
+    use juniper::{FromInputValue, InputValue, LookAheadValue};
+    use wundergraph::juniper_ext::FromLookAheadValue;
+
+    let input = InputValue::<WundergraphScalarValue>::object(
+        vec![
+            ("a", InputValue::scalar(1)),
+            ("b", InputValue::scalar("{\"c\": 1}")),
+            (
+                "list",
+                InputValue::list(vec![InputValue::null(), InputValue::enum_value("RED")]),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    assert_eq!(
+        Document::from_input_value(&input).map(serde_json::Value::from),
+        Some(json!({"a": 1, "b": "{\"c\": 1}", "list": [null, "RED"]}))
+    );
+    assert_eq!(
+        Document::from_input_value(&InputValue::scalar("{\"c\": 1}")).map(serde_json::Value::from),
+        Some(json!({"c": 1}))
+    );
+    assert_eq!(Document::from_input_value(&InputValue::null()), None);
+
+    let one = WundergraphScalarValue::Int(1);
+    let text = WundergraphScalarValue::String(String::from("d"));
+    let look_ahead = LookAheadValue::Object(vec![
+        ("a", LookAheadValue::Scalar(&one)),
+        (
+            "b",
+            LookAheadValue::List(vec![LookAheadValue::Scalar(&text), LookAheadValue::Null]),
+        ),
+    ]);
+    assert_eq!(
+        Document::from_look_ahead(&look_ahead).map(serde_json::Value::from),
+        Some(json!({"a": 1, "b": ["d", null]}))
+    );
+    assert_eq!(Document::from_look_ahead(&LookAheadValue::Null), None);
+    assert_eq!(
+        Option::<Document>::from_look_ahead(&LookAheadValue::Null),
+        Some(None)
+    );
+}
+
+#[test]
+fn json_eq_null_is_no_json_null() {
+    let (schema, pool) = get_json_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // Like for any other nullable column `eq: null` does not filter,
+    // instead of comparing with the json value `null`
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    JsonDocuments(filter: {document: {eq: null}}, order: [{column: id}]) {
+        id
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "JsonDocuments": [
+      {
+        "id": 1
+      },
+      {
+        "id": 2
+      },
+      {
+        "id": 3
+      },
+      {
+        "id": 4
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
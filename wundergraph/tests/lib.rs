@@ -1,7 +1,12 @@
+#[macro_use]
 extern crate diesel;
 extern crate diesel_migrations;
 extern crate juniper;
-extern crate serde_json;
+extern crate serde_json_internal as serde_json;
+#[cfg(feature = "bigdecimal")]
+extern crate bigdecimal_internal as bigdecimal;
+#[cfg(feature = "chrono")]
+extern crate chrono_internal as chrono;
 extern crate wundergraph;
 extern crate wundergraph_bench;
 extern crate wundergraph_example;
@@ -20,6 +25,29 @@ mod limit_offset;
 mod type_checking;
 mod alias;
 mod mutations;
+mod read_write;
+mod validation;
+mod instrumentation;
+mod full_text_search;
+mod enums;
+mod custom_scalar;
+mod bigint;
+mod node;
+mod encoded_key;
+mod unique;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "base64")]
+mod binary;
+#[cfg(feature = "chrono")]
+mod date_time;
+// Diesel only supports writing numeric values on postgres
+#[cfg(all(feature = "bigdecimal", feature = "postgres"))]
+mod decimal;
+#[cfg(feature = "postgres")]
+mod array;
+#[cfg(feature = "postgres")]
+mod tenant;
 
 #[cfg(feature = "postgres")]
 type DbConnection = diesel::pg::PgConnection;
@@ -105,3 +105,51 @@ fn limit_offset() {
 ]"###
     );
 }
+
+#[test]
+fn negative_limit() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(limit: -1) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not build filter from argument `Heros.limit`: expected a non-negative integer"
+    );
+}
+
+#[test]
+fn negative_offset() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(offset: -2) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not build filter from argument `Heros.offset`: expected a non-negative integer"
+    );
+}
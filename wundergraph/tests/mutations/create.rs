@@ -241,3 +241,81 @@ mutation NewHeros {
 ]"###
     );
 }
+
+#[test]
+fn create_invalid() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHeros {
+  CreateHeros(NewHeros: [{name: "Obi-Wan Kenobi", species: 1}, {name: "", species: 1}]) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[1][0]["message"], "Input validation failed");
+    assert_json_snapshot_matches!(
+        res[1][0]["extensions"].clone(), @r###"{
+  "validation": [
+    {
+      "field": "name",
+      "index": 1,
+      "message": "must not be empty"
+    }
+  ]
+}"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros {
+        heroName
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["Heros"].as_array().map(Vec::len), Some(5));
+}
+
+#[test]
+fn create_calls_mutation_hooks() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "  Obi-Wan Kenobi  ", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateHero": {
+      "heroName": "Obi-Wan Kenobi"
+    }
+  },
+  []
+]"###
+    );
+}
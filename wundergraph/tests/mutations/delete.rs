@@ -1,5 +1,49 @@
 use crate::helper::*;
-use wundergraph_example::MyContext;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::sql_types::Bool;
+use diesel::{Connection, QueryDsl, RunQueryDsl};
+use failure::Error;
+use juniper::LookAheadSelection;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{MyContext, Query, Schema};
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+/// Hides the hero with the id 5 and the home world with the id 2 from
+/// all queries
+struct HidingContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+}
+
+impl WundergraphContext for HidingContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for HidingContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        match T::TYPE_NAME {
+            "Hero" => Ok(query.filter(diesel::dsl::sql::<Bool>("heros.id <> 5"))),
+            "HomeWorld" => Ok(query.filter(diesel::dsl::sql::<Bool>("home_worlds.id <> 2"))),
+            _ => Ok(query),
+        }
+    }
+}
 
 #[test]
 fn delete_existing() {
@@ -232,3 +276,485 @@ mutation DeleteHero {
 ]"###
     );
 }
+
+#[test]
+fn soft_delete_and_restore() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHomeWorld {
+  DeleteHomeWorld(DeleteHomeWorld: {id: 2}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteHomeWorld": {
+      "count": 1
+    }
+  },
+  []
+]"###
+    );
+
+    // Juniper only looks ahead into the first root field of a query,
+    // so each root field is requested on its own
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds {
+        id
+        name
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorlds": [
+      {
+        "id": 1,
+        "name": "Tatooine"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds(onlyDeleted: true) {
+        id
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorlds": [
+      {
+        "id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds(includeDeleted: true) {
+        id
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorlds": [
+      {
+        "id": 1
+      },
+      {
+        "id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorld(primaryKey: {id: 2}) {
+        id
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorld": null
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Hero(primaryKey: {id: 4}) {
+        heroName
+        home_world {
+            id
+        }
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Hero": {
+      "heroName": "Leia Organa",
+      "home_world": null
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation RestoreHomeWorld {
+  RestoreHomeWorld(RestoreHomeWorld: {id: 2}) {
+    id
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "RestoreHomeWorld": {
+      "id": 2,
+      "name": "Alderaan"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds {
+        id
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorlds": [
+      {
+        "id": 1
+      },
+      {
+        "id": 2
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn delete_with_outdated_version() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteSpecies {
+  DeleteSpecies(DeleteSpecies: {id: 2, version: 3}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["DeleteSpecies"], serde_json::Value::Null);
+    assert_json_snapshot_matches!(
+        res[1][0]["extensions"].clone(), @r###"{
+  "code": "CONFLICT",
+  "entity": "Species"
+}"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteSpecies {
+  DeleteSpecies(DeleteSpecies: {id: 2, version: 1}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteSpecies": {
+      "count": 1
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn delete_versioned_non_existing() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteSpecies {
+  DeleteSpecies(DeleteSpecies: {id: 42, version: 1}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteSpecies": {
+      "count": 0
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn delete_returning() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHeroReturning(DeleteHero: {id: 5}) {
+    id
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteHeroReturning": {
+      "heroName": "Wilhuff Tarkin",
+      "id": 5
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHeroReturning(DeleteHero: {id: 5}) {
+    id
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteHeroReturning": null
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn delete_returning_hidden_by_query_modifier() {
+    let (_, pool) = get_example_schema();
+    let schema = Schema::<HidingContext>::new(Query::default(), Mutation::default());
+    let ctx = HidingContext {
+        conn: pool.get().unwrap(),
+    };
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHeroReturning(DeleteHero: {id: 5}) {
+    id
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "DeleteHeroReturning": null
+  },
+  []
+]"###
+    );
+
+    // The hidden hero is not deleted
+    let schema = Schema::<MyContext<DbConnection>>::new(Query::default(), Mutation::default());
+    let ctx = MyContext::new(ctx.conn);
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+  Hero(primaryKey: {id: 5}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Hero": {
+      "heroName": "Wilhuff Tarkin"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn restore_hidden_by_query_modifier() {
+    let (_, pool) = get_example_schema();
+    let schema = Schema::<HidingContext>::new(Query::default(), Mutation::default());
+    let ctx = HidingContext {
+        conn: pool.get().unwrap(),
+    };
+
+    // The home world is hidden from the context, so it is deleted directly
+    diesel::sql_query("UPDATE home_worlds SET deleted_at = CURRENT_TIMESTAMP WHERE id = 2")
+        .execute(&*ctx.conn)
+        .unwrap();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation RestoreHomeWorld {
+  RestoreHomeWorld(RestoreHomeWorld: {id: 2}) {
+    id
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "RestoreHomeWorld": null
+  },
+  []
+]"###
+    );
+
+    // The hidden home world is not restored
+    let schema = Schema::<MyContext<DbConnection>>::new(Query::default(), Mutation::default());
+    let ctx = MyContext::new(ctx.conn);
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    HomeWorlds {
+        id
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "HomeWorlds": [
+      {
+        "id": 1
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
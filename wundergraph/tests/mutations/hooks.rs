@@ -0,0 +1,588 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::LookAheadSelection;
+use std::any::Any;
+use std::cell::RefCell;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::mutations::{MutationHooks, RelationChange};
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::{HeroChangeset, Mutation, NewHero};
+use wundergraph_example::{MyContext, Query, Schema};
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+/// Records each called hook and fails the hook named by `abort`
+struct HookContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+    abort: Option<&'static str>,
+    calls: RefCell<Vec<String>>,
+}
+
+impl HookContext {
+    fn record(&self, hook: &'static str, entity: &str, args: String) -> Result<(), Error> {
+        self.calls
+            .borrow_mut()
+            .push(format!("{} {} {}", hook, entity, args));
+        if self.abort == Some(hook) {
+            Err(failure::format_err!("Aborted by {}", hook))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl WundergraphContext for HookContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+
+    fn mutation_hooks(&self) -> Option<&dyn MutationHooks> {
+        Some(self)
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for HookContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+fn keys(keys: &[&dyn Any]) -> String {
+    let keys = keys
+        .iter()
+        .map(|k| {
+            k.downcast_ref::<i32>()
+                .expect("Example entities use integer keys")
+        })
+        .collect::<Vec<_>>();
+    format!("{:?}", keys)
+}
+
+fn input(input: &dyn Any) -> String {
+    if let Some(hero) = input.downcast_ref::<NewHero>() {
+        format!("{:?}", hero)
+    } else if let Some(change_set) = input.downcast_ref::<HeroChangeset>() {
+        format!("{:?}", change_set)
+    } else if let Some(change) = input.downcast_ref::<RelationChange<i32, i32>>() {
+        format!("{:?}", change)
+    } else {
+        String::from("unknown input")
+    }
+}
+
+impl MutationHooks for HookContext {
+    fn before_insert(&self, entity: &'static str, insertable: &mut dyn Any) -> Result<(), Error> {
+        self.record("before_insert", entity, input(insertable))
+    }
+
+    fn after_insert(&self, entity: &'static str, keys: &[&dyn Any]) -> Result<(), Error> {
+        // Inserted ids depend on the state of the sequence
+        self.record("after_insert", entity, format!("{} rows", keys.len()))
+    }
+
+    fn before_update(&self, entity: &'static str, change_set: &mut dyn Any) -> Result<(), Error> {
+        self.record("before_update", entity, input(change_set))
+    }
+
+    fn after_update(&self, entity: &'static str, ids: &[&dyn Any]) -> Result<(), Error> {
+        self.record("after_update", entity, keys(ids))
+    }
+
+    fn before_delete(&self, entity: &'static str, ids: &[&dyn Any]) -> Result<(), Error> {
+        self.record("before_delete", entity, keys(ids))
+    }
+
+    fn after_delete(&self, entity: &'static str, ids: &[&dyn Any]) -> Result<(), Error> {
+        self.record("after_delete", entity, keys(ids))
+    }
+
+    fn before_restore(&self, entity: &'static str, ids: &[&dyn Any]) -> Result<(), Error> {
+        self.record("before_restore", entity, keys(ids))
+    }
+
+    fn after_restore(&self, entity: &'static str, ids: &[&dyn Any]) -> Result<(), Error> {
+        self.record("after_restore", entity, keys(ids))
+    }
+}
+
+fn get_hook_context(abort: Option<&'static str>) -> (Schema<HookContext>, HookContext) {
+    let (_, pool) = get_example_schema();
+    let ctx = HookContext {
+        conn: pool.get().unwrap(),
+        abort,
+        calls: RefCell::new(Vec::new()),
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+fn hero_names(schema: &Schema<HookContext>, ctx: &HookContext) -> serde_json::Value {
+    let res = execute_query(schema, ctx, "{ Heros { heroName } }");
+    assert!(res.is_ok());
+    res.as_json()[0]["Heros"].clone()
+}
+
+#[test]
+fn insert_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "Yoda", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["CreateHero"]["heroName"], "Yoda");
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from(
+                "before_insert Hero NewHero { name: \"Yoda\", hair_color: None, species: 1, home_world: None }"
+            ),
+            String::from("after_insert Hero 1 rows"),
+        ]
+    );
+}
+
+#[test]
+fn abort_in_before_insert() {
+    let (schema, ctx) = get_hook_context(Some("before_insert"));
+    let before = hero_names(&schema, &ctx);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHeros {
+  CreateHeros(NewHeros: [{name: "Yoda", species: 1}, {name: "Boba Fett", species: 1}]) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["CreateHeros"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by before_insert");
+    // The batch stops at the first failing hook
+    assert_eq!(ctx.calls.borrow().len(), 1);
+    assert_eq!(hero_names(&schema, &ctx), before);
+}
+
+#[test]
+fn abort_in_after_insert() {
+    let (schema, ctx) = get_hook_context(Some("after_insert"));
+    let before = hero_names(&schema, &ctx);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "Yoda", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["CreateHero"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by after_insert");
+    // The insert is rolled back
+    assert_eq!(hero_names(&schema, &ctx), before);
+}
+
+#[test]
+fn update_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: "Luke"}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["UpdateHero"]["heroName"], "Luke");
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from(
+                "before_update Hero HeroChangeset { id: 1, name: Some(\"Luke\"), hair_color: None, species: None, home_world: None }"
+            ),
+            String::from("after_update Hero [1]"),
+        ]
+    );
+}
+
+#[test]
+fn abort_in_before_update() {
+    let (schema, ctx) = get_hook_context(Some("before_update"));
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: "Luke"}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["UpdateHero"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by before_update");
+    assert_eq!(hero_names(&schema, &ctx)[0]["heroName"], "Luke Skywalker");
+}
+
+#[test]
+fn abort_in_after_update() {
+    let (schema, ctx) = get_hook_context(Some("after_update"));
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: "Luke"}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["UpdateHero"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by after_update");
+    // The update is rolled back
+    assert_eq!(hero_names(&schema, &ctx)[0]["heroName"], "Luke Skywalker");
+}
+
+#[test]
+fn delete_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHero(DeleteHero: {id: 5}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["DeleteHero"]["count"], 1);
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from("before_delete Hero [5]"),
+            String::from("after_delete Hero [5]"),
+        ]
+    );
+}
+
+#[test]
+fn delete_returning_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHeroReturning(DeleteHero: {id: 5}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["DeleteHeroReturning"]["heroName"],
+        "Wilhuff Tarkin"
+    );
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from("before_delete Hero [5]"),
+            String::from("after_delete Hero [5]"),
+        ]
+    );
+}
+
+#[test]
+fn relation_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectHeros {
+  ConnectHomeWorldHeros(id: 2, ids: [3, 5]) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["ConnectHomeWorldHeros"]["name"],
+        "Alderaan"
+    );
+    // Hooks are called for the changed children
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from(
+                "before_update Hero RelationChange { mutation: \"ConnectHomeWorldHeros\", operation: Connect, argument: \"ids\", key: 2, targets: [3, 5] }"
+            ),
+            String::from("after_update Hero [3, 5]"),
+        ]
+    );
+}
+
+#[test]
+fn abort_in_before_relation_update() {
+    let (schema, ctx) = get_hook_context(Some("before_update"));
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation disconnectHomeWorld {
+  DisconnectHeroHomeWorld(id: 1) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["DisconnectHeroHomeWorld"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by before_update");
+    assert_eq!(ctx.calls.borrow().len(), 1);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "{ Hero(primaryKey: {id: 1}) { home_world { name } } }",
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["Hero"]["home_world"]["name"], "Tatooine");
+}
+
+#[test]
+fn abort_in_after_delete() {
+    let (schema, ctx) = get_hook_context(Some("after_delete"));
+    let before = hero_names(&schema, &ctx);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHero {
+  DeleteHero(DeleteHero: {id: 5}) {
+    count
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["DeleteHero"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by after_delete");
+    // The delete is rolled back
+    assert_eq!(hero_names(&schema, &ctx), before);
+}
+
+#[test]
+fn hooks_run_before_validation() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // The example context trims names before the non_empty rule is checked
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "   ", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[1][0]["message"], "Input validation failed");
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "  Yoda  ", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["CreateHero"]["heroName"], "Yoda");
+}
+
+#[test]
+fn context_hooks_run_before_entity_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    // The context sees the input before the example entity hooks trim it
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewHero {
+  CreateHero(NewHero: {name: "  Yoda  ", species: 1}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["CreateHero"]["heroName"], "Yoda");
+    assert_eq!(
+        ctx.calls.borrow()[0],
+        "before_insert Hero NewHero { name: \"  Yoda  \", hair_color: None, species: 1, home_world: None }"
+    );
+}
+
+#[test]
+fn restore_hooks() {
+    let (schema, ctx) = get_hook_context(None);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHomeWorld {
+  DeleteHomeWorld(DeleteHomeWorld: {id: 2}) {
+    count
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["DeleteHomeWorld"]["count"], 1);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation RestoreHomeWorld {
+  RestoreHomeWorld(RestoreHomeWorld: {id: 2}) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["RestoreHomeWorld"]["name"], "Alderaan");
+    assert_eq!(
+        *ctx.calls.borrow(),
+        vec![
+            String::from("before_delete HomeWorld [2]"),
+            String::from("after_delete HomeWorld [2]"),
+            String::from("before_restore HomeWorld [2]"),
+            String::from("after_restore HomeWorld [2]"),
+        ]
+    );
+}
+
+#[test]
+fn abort_in_before_restore() {
+    let (schema, ctx) = get_hook_context(Some("before_restore"));
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation DeleteHomeWorld {
+  DeleteHomeWorld(DeleteHomeWorld: {id: 2}) {
+    count
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json()[0]["DeleteHomeWorld"]["count"], 1);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation RestoreHomeWorld {
+  RestoreHomeWorld(RestoreHomeWorld: {id: 2}) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["RestoreHomeWorld"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Aborted by before_restore");
+
+    // The home world is still deleted
+    let res = execute_query(&schema, &ctx, "{ HomeWorlds { id } }");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["HomeWorlds"],
+        serde_json::json!([{"id": 1}])
+    );
+}
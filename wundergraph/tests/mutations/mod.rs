@@ -1,4 +1,5 @@
-
 mod create;
 mod update;
 mod delete;
+mod hooks;
+mod relation;
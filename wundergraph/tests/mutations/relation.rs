@@ -0,0 +1,329 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::sql_types::Bool;
+use diesel::{Connection, QueryDsl};
+use failure::Error;
+use juniper::LookAheadSelection;
+use serde_json::json;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{MyContext, Query, Schema};
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+/// Hides the hero with the id 2 from all queries
+struct HidingContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+}
+
+impl WundergraphContext for HidingContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for HidingContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        if T::TYPE_NAME == "Hero" {
+            Ok(query.filter(diesel::dsl::sql::<Bool>("heros.id <> 2")))
+        } else {
+            Ok(query)
+        }
+    }
+}
+
+#[test]
+fn relation_mutations() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation setHeros {
+  SetHomeWorldHeros(id: 2, ids: [3, 5]) {
+    name
+    heros {
+      id
+      heroName
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "SetHomeWorldHeros": {
+      "heros": [
+        {
+          "heroName": "Han Solo",
+          "id": 3
+        },
+        {
+          "heroName": "Wilhuff Tarkin",
+          "id": 5
+        }
+      ],
+      "name": "Alderaan"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectSpecies {
+  ConnectHeroSpecies(id: 1, to: 42) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["ConnectHeroSpecies"], serde_json::Value::Null);
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not execute `ConnectHeroSpecies`: `to` references a Species \
+         that does not exist or is not visible"
+    );
+}
+
+#[test]
+fn connect_has_one() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectSpecies {
+  ConnectHeroSpecies(id: 1, to: 2) {
+    heroName
+    species {
+      name
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "ConnectHeroSpecies": {
+                    "heroName": "Luke Skywalker",
+                    "species": {"name": "Robot"},
+                },
+            },
+            [],
+        ])
+    );
+}
+
+#[test]
+fn disconnect_has_one() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation disconnectHomeWorld {
+  DisconnectHeroHomeWorld(id: 1) {
+    heroName
+    home_world {
+      name
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "DisconnectHeroHomeWorld": {
+                    "heroName": "Luke Skywalker",
+                    "home_world": null,
+                },
+            },
+            [],
+        ])
+    );
+}
+
+#[test]
+fn disconnect_has_many() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation disconnectHeros {
+  DisconnectHomeWorldHeros(id: 1, ids: [2, 4]) {
+    heros {
+      id
+    }
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    // Hero 4 belongs to another world and stays unchanged
+    assert_eq!(
+        res.as_json(),
+        json!([{"DisconnectHomeWorldHeros": {"heros": [{"id": 1}]}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "{ HomeWorld(primaryKey: {id: 2}) { heros { id } } }",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"HomeWorld": {"heros": [{"id": 4}]}}, []])
+    );
+}
+
+#[test]
+fn invalid_relation_input() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectHeros {
+  ConnectHomeWorldHeros(id: 1, ids: [3, 3]) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["ConnectHomeWorldHeros"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Input validation failed");
+    assert_eq!(
+        res[1][0]["extensions"]["validation"],
+        json!([{"field": "ids", "message": "must not contain duplicates"}])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectHeros {
+  ConnectHomeWorldHeros(id: 1, ids: []) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["ConnectHomeWorldHeros"], serde_json::Value::Null);
+    assert_eq!(
+        res[1][0]["extensions"]["validation"],
+        json!([{"field": "ids", "message": "must not be empty"}])
+    );
+}
+
+#[test]
+fn children_hidden_by_query_modifier_stay_unchanged() {
+    let (_, pool) = get_example_schema();
+    let schema = Schema::new(Query::default(), Mutation::default());
+    let ctx = HidingContext {
+        conn: pool.get().unwrap(),
+    };
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation setHeros {
+  SetHomeWorldHeros(id: 1, ids: [3]) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"SetHomeWorldHeros": {"name": "Tatooine"}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation connectHeros {
+  ConnectHomeWorldHeros(id: 2, ids: [2]) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["ConnectHomeWorldHeros"], serde_json::Value::Null);
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not execute `ConnectHomeWorldHeros`: `ids` references a Hero \
+         that does not exist or is not visible"
+    );
+
+    // Luke is disconnected, while the hidden hero still belongs to the
+    // first world
+    let (schema, _) = get_example_schema();
+    let ctx = MyContext::new(ctx.conn);
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "{ Heros(filter: {home_world: {id: {eq: 1}}}, order: [{column: id}]) { id } }",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"Heros": [{"id": 2}, {"id": 3}]}, []])
+    );
+}
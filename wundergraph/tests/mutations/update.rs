@@ -1,4 +1,5 @@
 use crate::helper::*;
+use serde_json::json;
 use wundergraph_example::MyContext;
 
 #[test]
@@ -261,3 +262,431 @@ mutation updateHero {
 ]"###
     );
 }
+
+#[test]
+fn update_invalid() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: ""}) {
+    heroName
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[1][0]["message"], "Input validation failed");
+    assert_json_snapshot_matches!(
+        res[1][0]["extensions"].clone(), @r###"{
+  "validation": [
+    {
+      "field": "name",
+      "message": "must not be empty"
+    }
+  ]
+}"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Hero(primaryKey: {id: 1}) {
+        heroName
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Hero": {
+      "heroName": "Luke Skywalker"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn update_with_outdated_version() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateSpecies {
+  UpdateSpecies(UpdateSpecies: {id: 1, name: "Humans", version: 1}) {
+    name
+    version
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateSpecies": {
+      "name": "Humans",
+      "version": 2
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateSpecies {
+  UpdateSpecies(UpdateSpecies: {id: 1, name: "Human", version: 1}) {
+    name
+    version
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["UpdateSpecies"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["message"], "Species was modified concurrently");
+    assert_json_snapshot_matches!(
+        res[1][0]["extensions"].clone(), @r###"{
+  "code": "CONFLICT",
+  "entity": "Species"
+}"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Species(primaryKey: {id: 1}) {
+        name
+        version
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Species": {
+      "name": "Humans",
+      "version": 2
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn update_versioned_non_existing() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // There is nothing that could conflict, so this is no error
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateSpecies {
+  UpdateSpecies(UpdateSpecies: {id: 42, name: "Ewoks", version: 1}) {
+    name
+    version
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateSpecies": null
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn update_explicit_null() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, name: "Luke"}) {
+    heroName
+    hair_color
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateHero": {
+      "hair_color": "blond",
+      "heroName": "Luke"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHero {
+  UpdateHero(UpdateHero: {id: 1, hairColor: null}) {
+    heroName
+    hair_color
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateHero": {
+      "hair_color": null,
+      "heroName": "Luke"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn update_atomic() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHomeWorld {
+  UpdateHomeWorld(UpdateHomeWorld: {id: 1, name: {append: " Prime"}}) {
+    id
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateHomeWorld": {
+      "id": 1,
+      "name": "Tatooine Prime"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHomeWorld {
+  UpdateHomeWorld(UpdateHomeWorld: {id: 1, name: {prepend: "New "}}) {
+    id
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "UpdateHomeWorld": {
+      "id": 1,
+      "name": "New Tatooine Prime"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHomeWorld {
+  UpdateHomeWorld(UpdateHomeWorld: {id: 1, name: {set: "Tatooine", append: "!"}}) {
+    id
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["UpdateHomeWorld"], serde_json::Value::Null);
+    assert_eq!(
+        res[1][0]["message"],
+        "Invalid update of column `name`: expected one operator, got `set`, `append`"
+    );
+}
+
+#[test]
+fn update_atomic_numeric() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    for (operator, expected) in &[("inc: 5", 5), ("dec: 2", 3), ("mul: 4", 12)] {
+        let query = format!(
+            "mutation {{ UpdateHomeWorld(UpdateHomeWorld: {{id: 1, viewCount: {{{}}}}}) {{ view_count }} }}",
+            operator
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_ok());
+        assert_eq!(
+            res.as_json(),
+            json!([{"UpdateHomeWorld": {"view_count": expected}}, []])
+        );
+    }
+
+    // The other world is not changed
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "{ HomeWorld(primaryKey: {id: 2}) { view_count } }",
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"HomeWorld": {"view_count": 0}}, []]));
+}
+
+#[test]
+fn update_atomic_timestamp() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHomeWorld {
+  UpdateHomeWorld(UpdateHomeWorld: {id: 1, lastVisitedAt: {now: true}}) {
+    last_visited_at
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert!(res[0]["UpdateHomeWorld"]["last_visited_at"].is_number());
+    assert_eq!(res[1], json!([]));
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation updateHomeWorld {
+  UpdateHomeWorld(UpdateHomeWorld: {id: 1, lastVisitedAt: {set: null}}) {
+    last_visited_at
+  }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"UpdateHomeWorld": {"last_visited_at": null}}, []])
+    );
+}
+
+#[test]
+fn update_atomic_invalid() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let cases = [
+        (
+            "name: {set: null}",
+            "Invalid update of column `name`: the column is not nullable",
+        ),
+        (
+            "viewCount: {}",
+            "Invalid update of column `view_count`: expected one operator, got none",
+        ),
+        (
+            "viewCount: {inc: null}",
+            "Invalid update of column `view_count`: `inc` must not be null",
+        ),
+        (
+            "lastVisitedAt: {now: false}",
+            "Invalid update of column `last_visited_at`: invalid value for `now`",
+        ),
+    ];
+    for (input, message) in &cases {
+        let query = format!(
+            "mutation {{ UpdateHomeWorld(UpdateHomeWorld: {{id: 1, {}}}) {{ name }} }}",
+            input
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_ok());
+        let res = res.as_json();
+        assert_eq!(res[0]["UpdateHomeWorld"], serde_json::Value::Null);
+        assert_eq!(res[1][0]["message"], *message);
+    }
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "{ HomeWorld(primaryKey: {id: 1}) { name view_count last_visited_at } }",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "HomeWorld": {
+                    "name": "Tatooine",
+                    "view_count": 0,
+                    "last_visited_at": null,
+                },
+            },
+            [],
+        ])
+    );
+}
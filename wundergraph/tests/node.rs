@@ -0,0 +1,482 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::LookAheadSelection;
+use serde_json::json;
+use wundergraph::context::WundergraphContext;
+use wundergraph::instrumentation::{ApolloTracing, Instrumentation};
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{AppearsIn, Hero, HomeWorld, Species};
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+wundergraph::query_object! {
+    Query {
+        Hero,
+        Species,
+        HomeWorld,
+        AppearsIn,
+    }
+}
+
+type Schema =
+    juniper::RootNode<'static, Query<NodeContext>, Mutation<NodeContext>, WundergraphScalarValue>;
+
+struct NodeContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+    tracing: ApolloTracing,
+}
+
+impl WundergraphContext for NodeContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    const NODE_INTERFACE: bool = true;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+
+    fn instrumentation(&self) -> Option<&dyn Instrumentation> {
+        Some(&self.tracing)
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for NodeContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+fn get_node_context() -> (Schema, NodeContext) {
+    let (_, pool) = get_example_schema();
+    let ctx = NodeContext {
+        conn: pool.get().unwrap(),
+        tracing: ApolloTracing::new(),
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+fn node_id(raw: &str) -> String {
+    raw.bytes().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn traced_types(ctx: &NodeContext) -> Vec<serde_json::Value> {
+    let extensions = serde_json::to_value(ctx.tracing.extensions()).unwrap();
+    extensions["tracing"]["execution"]["statements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["typeName"].clone())
+        .collect()
+}
+
+#[test]
+fn id_field_returns_node_id() {
+    let (schema, ctx) = get_node_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {eq: 1}}) {
+        id
+        heroName
+        species {
+            id
+            name
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "Heros": [{
+                    "id": node_id("Hero:id=i1"),
+                    "heroName": "Luke Skywalker",
+                    "species": {
+                        "id": node_id("Species:id=i1"),
+                        "name": "Human",
+                    },
+                }],
+            },
+            [],
+        ])
+    );
+
+    // The column is still accessible through the primary key argument
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Hero(primaryKey: {id: 2}) {
+        id
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["Hero"],
+        json!({"id": node_id("Hero:id=i2"), "heroName": "Darth Vader"})
+    );
+}
+
+#[test]
+fn raw_id_field_returns_column_value() {
+    let (schema, ctx) = get_node_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(filter: {id: {eq_any: [1, 2]}}, order: [{column: id}]) {
+        raw_id
+        species {
+            key: raw_id
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["Heros"],
+        json!([
+            {"raw_id": 1, "species": {"key": 1}},
+            {"raw_id": 2, "species": {"key": 1}},
+        ])
+    );
+
+    let query = format!(
+        r#"
+{{
+    node(id: "{}") {{
+        id
+        ... on Hero {{
+            raw_id
+        }}
+    }}
+}}
+"#,
+        node_id("Hero:id=i2")
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[0]["node"],
+        json!({"id": node_id("Hero:id=i2"), "raw_id": 2})
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    __type(name: "Hero") {
+        fields {
+            name
+            type {
+                ofType {
+                    name
+                }
+            }
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let field_type = |name: &str| {
+        res[0]["__type"]["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["name"] == name)
+            .map(|f| f["type"]["ofType"]["name"].clone())
+    };
+    assert_eq!(field_type("id"), Some(json!("ID")));
+    assert_eq!(field_type("raw_id"), Some(json!("Int")));
+}
+
+#[test]
+fn node_loads_entity_by_id() {
+    let (schema, ctx) = get_node_context();
+
+    let query = format!(
+        r#"
+{{
+    node(id: "{}") {{
+        id
+        __typename
+        ... on Hero {{
+            heroName
+        }}
+    }}
+}}
+"#,
+        node_id("Hero:id=i3")
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "node": {
+                    "id": node_id("Hero:id=i3"),
+                    "__typename": "Hero",
+                    "heroName": "Han Solo",
+                },
+            },
+            [],
+        ])
+    );
+
+    let query = format!(
+        r#"
+{{
+    node(id: "{}") {{
+        id
+    }}
+}}
+"#,
+        node_id("Hero:id=i42")
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"node": null}, []]));
+}
+
+#[test]
+fn nodes_are_loaded_with_one_query_per_type() {
+    let (schema, ctx) = get_node_context();
+    let ids = [
+        node_id("Hero:id=i2"),
+        node_id("Species:id=i2"),
+        node_id("Hero:id=i42"),
+        node_id("Hero:id=i1"),
+        node_id("Unknown:id=i1"),
+    ];
+
+    let query = format!(
+        r#"
+{{
+    nodes(ids: [{}]) {{
+        __typename
+        ... on Hero {{
+            heroName
+        }}
+        ... on Species {{
+            name
+        }}
+    }}
+}}
+"#,
+        ids.iter()
+            .map(|id| format!("{:?}", id))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "nodes": [
+                    {"__typename": "Hero", "heroName": "Darth Vader"},
+                    {"__typename": "Species", "name": "Robot"},
+                    null,
+                    {"__typename": "Hero", "heroName": "Luke Skywalker"},
+                    null,
+                ],
+            },
+            [],
+        ])
+    );
+    assert_eq!(traced_types(&ctx), vec![json!("Hero"), json!("Species")]);
+}
+
+#[test]
+fn composite_primary_keys_are_encoded() {
+    let (schema, ctx) = get_node_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    AppearsIns(filter: {episode: {eq: JEDI}}, limit: 1) {
+        id
+        episode
+        hero_id {
+            heroName
+        }
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let appears_in = res[0]["AppearsIns"][0].clone();
+    let id = appears_in["id"].as_str().unwrap().to_owned();
+    assert_eq!(
+        appears_in,
+        json!({
+            "id": node_id("AppearsIn:hero_id=i1:episode=sJEDI"),
+            "episode": "JEDI",
+            "hero_id": {"heroName": "Luke Skywalker"},
+        })
+    );
+
+    let query = format!(
+        r#"
+{{
+    node(id: "{}") {{
+        ... on AppearsIn {{
+            id
+            episode
+            hero_id {{
+                heroName
+            }}
+        }}
+    }}
+}}
+"#,
+        id
+    );
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{ "node": appears_in }, []]));
+}
+
+#[test]
+fn invalid_node_id_returns_an_error() {
+    let (schema, ctx) = get_node_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    node(id: "no hex") {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "node": null
+  },
+  [
+    {
+      "locations": [
+        {
+          "column": 5,
+          "line": 3
+        }
+      ],
+      "message": "Invalid node id `no hex`",
+      "path": [
+        "node"
+      ]
+    }
+  ]
+]"###
+    );
+}
+
+#[test]
+fn entities_implement_the_node_interface() {
+    let (schema, ctx) = get_node_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    __type(name: "Node") {
+        kind
+        fields {
+            name
+        }
+        possibleTypes {
+            name
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let node = &res[0]["__type"];
+    assert_eq!(node["kind"], "INTERFACE");
+    assert_eq!(node["fields"], json!([{"name": "id"}]));
+    let mut possible_types = node["possibleTypes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    possible_types.sort();
+    assert_eq!(
+        possible_types,
+        vec!["AppearsIn", "Friend", "Hero", "HomeWorld", "Species"]
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    __type(name: "AppearsIn") {
+        fields {
+            name
+            type {
+                kind
+                ofType {
+                    name
+                }
+            }
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let id = res[0]["__type"]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == "id")
+        .cloned();
+    assert_eq!(
+        id,
+        Some(json!({"name": "id", "type": {"kind": "NON_NULL", "ofType": {"name": "ID"}}}))
+    );
+}
@@ -86,3 +86,27 @@ fn order_desc() {
 ]"###
     );
 }
+
+#[test]
+fn order_without_list() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(order: {column: heroName, direction: DESC}) {
+        heroName
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not build filter from argument `Heros.order`: expected a list of order clauses"
+    );
+}
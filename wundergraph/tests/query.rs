@@ -438,3 +438,50 @@ fn filter_on_field() {
 ]"###
     );
 }
+
+#[test]
+fn malformed_filter() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Heros(filter: {or: [{heroName: {eq: "Luke Skywalker"}}, {heroName: {eq_any: "Leia Organa"}}]}) {
+        heroName
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0], serde_json::Value::Null);
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not build filter from argument `Heros.filter.or[1].heroName.eq_any`: \
+         value does not match the type of the field"
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Speciess {
+        members: heros(filter: {heroName: {eq_any: "Luke Skywalker"}}) {
+            heroName
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[1][0]["message"],
+        "Could not build filter from argument `Speciess.members.filter.heroName.eq_any`: \
+         value does not match the type of the field"
+    );
+}
@@ -0,0 +1,129 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use wundergraph::context::ReadWriteConnections;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{Query, Schema};
+
+type ReadWriteContext = ReadWriteConnections<PooledConnection<ConnectionManager<DbConnection>>>;
+
+// Both pools wrap each connection in a test transaction, so changes made
+// using the primary pool are not visible to the replica pool. This
+// behaves like a replica that did not receive those changes yet.
+fn get_read_write_context(read_your_writes: bool) -> (Schema<ReadWriteContext>, ReadWriteContext) {
+    let (_, primary) = get_example_schema();
+    let (_, replica) = get_example_schema();
+    let ctx = ReadWriteConnections::new(primary.get().unwrap(), replica.get().unwrap())
+        .read_your_writes(read_your_writes);
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+#[test]
+fn queries_use_replica() {
+    let (schema, ctx) = get_read_write_context(false);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewSpecies {
+  CreateSpecies(NewSpecies: {name: "Jawa"}) {
+    name
+  }
+}
+"#,
+    );
+
+    // The created entity is loaded from the primary
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateSpecies": {
+      "name": "Jawa"
+    }
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Human"
+      },
+      {
+        "name": "Robot"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn read_your_writes_uses_primary_after_mutation() {
+    let (schema, ctx) = get_read_write_context(true);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewSpecies {
+  CreateSpecies(NewSpecies: {name: "Jawa"}) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Human"
+      },
+      {
+        "name": "Robot"
+      },
+      {
+        "name": "Jawa"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
@@ -0,0 +1,292 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
+use wundergraph::context::TenantConnection;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::{MyContext, Query, Schema};
+
+type TenantContext = TenantConnection<PooledConnection<ConnectionManager<DbConnection>>>;
+
+fn get_tenant_schema() -> (Schema<TenantContext>, Pool<ConnectionManager<DbConnection>>) {
+    let (_, pool) = get_example_schema();
+    pool.get()
+        .unwrap()
+        .batch_execute(
+            "
+CREATE SCHEMA tenant_a;
+CREATE TABLE tenant_a.species (LIKE public.species INCLUDING ALL);
+INSERT INTO tenant_a.species(id, name) VALUES (1, 'Wookiee');
+CREATE SCHEMA tenant_b;
+CREATE TABLE tenant_b.species (LIKE public.species INCLUDING ALL);
+INSERT INTO tenant_b.species(id, name) VALUES (1, 'Ewok');
+",
+        )
+        .unwrap();
+    let schema = Schema::new(Query::default(), Mutation::default());
+    (schema, pool)
+}
+
+#[test]
+fn query_only_returns_rows_of_tenant() {
+    let (schema, pool) = get_tenant_schema();
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_a").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Wookiee"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_b").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Ewok"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn mutation_only_changes_rows_of_tenant() {
+    let (schema, pool) = get_tenant_schema();
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_a").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation NewSpecies {
+  CreateSpecies(NewSpecies: {name: "Jawa"}) {
+    name
+  }
+}
+"#,
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "CreateSpecies": {
+      "name": "Jawa"
+    }
+  },
+  []
+]"###
+    );
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_b").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Ewok"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    // The connection went back to the pool without a tenant
+    let schema = Schema::<MyContext<DbConnection>>::new(Query::default(), Mutation::default());
+    let ctx = MyContext::new(pool.get().unwrap());
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Human"
+      },
+      {
+        "name": "Robot"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn failed_mutation_keeps_tenant() {
+    let (schema, pool) = get_tenant_schema();
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_a").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation UpdateSpecies {
+  UpdateSpecies(UpdateSpecies: {id: 1, name: "Ewok", version: 5}) {
+    name
+  }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["UpdateSpecies"], serde_json::Value::Null);
+    assert_eq!(res[1][0]["extensions"]["code"], "CONFLICT");
+
+    // The rolled back transaction does not reset the tenant
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Wookiee"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn batch_execute_only_changes_rows_of_tenant() {
+    let (schema, pool) = get_tenant_schema();
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_a").unwrap();
+    ctx.batch_execute("UPDATE species SET name = 'Jawa'").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Jawa"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let ctx = TenantConnection::new(pool.get().unwrap(), "tenant_b").unwrap();
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Speciess {
+        name
+    }
+}
+",
+    );
+    drop(ctx);
+
+    assert!(res.is_ok());
+    assert_json_snapshot_matches!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Ewok"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
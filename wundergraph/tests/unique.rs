@@ -0,0 +1,252 @@
+use crate::helper::*;
+use crate::DbConnection;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use failure::Error;
+use juniper::LookAheadSelection;
+use serde_json::json;
+use wundergraph::context::WundergraphContext;
+use wundergraph::query_builder::selection::query_modifier::QueryModifier;
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::WundergraphEntity;
+use wundergraph_example::mutations::Mutation;
+use wundergraph_example::AppearsIn;
+
+type DbBackend = <DbConnection as Connection>::Backend;
+
+table! {
+    heros {
+        id -> Integer,
+        name -> Text,
+        species -> Integer,
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "heros"]
+#[wundergraph(unique(name), unique(name, species))]
+pub struct Character {
+    id: i32,
+    name: String,
+    species: i32,
+}
+
+wundergraph::query_object! {
+    Query {
+        Character,
+        AppearsIn,
+    }
+}
+
+type Schema = juniper::RootNode<
+    'static,
+    Query<UniqueContext>,
+    Mutation<UniqueContext>,
+    WundergraphScalarValue,
+>;
+
+struct UniqueContext {
+    conn: PooledConnection<ConnectionManager<DbConnection>>,
+}
+
+impl WundergraphContext for UniqueContext {
+    type Connection = PooledConnection<ConnectionManager<DbConnection>>;
+
+    fn get_connection(&self) -> &Self::Connection {
+        &self.conn
+    }
+}
+
+impl<T> QueryModifier<T, DbBackend> for UniqueContext
+where
+    T: LoadingHandler<DbBackend, Self>,
+{
+    fn modify_query<'a>(
+        &self,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        query: BoxedQuery<'a, T, DbBackend, Self>,
+    ) -> Result<BoxedQuery<'a, T, DbBackend, Self>, Error> {
+        Ok(query)
+    }
+}
+
+fn get_unique_context() -> (Schema, UniqueContext) {
+    let (_, pool) = get_example_schema();
+    let ctx = UniqueContext {
+        conn: pool.get().unwrap(),
+    };
+    (Schema::new(Query::default(), Mutation::default()), ctx)
+}
+
+#[test]
+fn lookup_by_unique_column() {
+    let (schema, ctx) = get_unique_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    CharacterByName(name: "Leia Organa") {
+        id
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"CharacterByName": {"id": 4, "name": "Leia Organa"}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    CharacterByName(name: "Obi-Wan Kenobi") {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(res.as_json(), json!([{"CharacterByName": null}, []]));
+}
+
+#[test]
+fn lookup_by_unique_column_set() {
+    let (schema, ctx) = get_unique_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    CharacterByNameAndSpecies(name: "Luke Skywalker", species: 1) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"CharacterByNameAndSpecies": {"id": 1}}, []])
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    CharacterByNameAndSpecies(name: "Luke Skywalker", species: 2) {
+        id
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([{"CharacterByNameAndSpecies": null}, []])
+    );
+}
+
+#[test]
+fn load_by_ids_in_the_given_order() {
+    let (schema, ctx) = get_unique_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    CharactersByIds(ids: [{id: 3}, {id: 42}, {id: 1}, {id: 3}]) {
+        name
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "CharactersByIds": [
+                    {"name": "Han Solo"},
+                    null,
+                    {"name": "Luke Skywalker"},
+                    {"name": "Han Solo"},
+                ],
+            },
+            [],
+        ])
+    );
+}
+
+#[test]
+fn load_by_composite_ids() {
+    let (schema, ctx) = get_unique_context();
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    AppearsInsByIds(ids: [
+        {hero_id: 5, episode: JEDI},
+        {hero_id: 5, episode: NEWHOPE},
+        {hero_id: 1, episode: EMPIRE},
+    ]) {
+        episode
+    }
+}
+",
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json(),
+        json!([
+            {
+                "AppearsInsByIds": [
+                    {"episode": "JEDI"},
+                    null,
+                    {"episode": "EMPIRE"},
+                ],
+            },
+            [],
+        ])
+    );
+}
+
+#[test]
+fn load_by_many_ids() {
+    let (schema, ctx) = get_unique_context();
+
+    // Stays below the bind parameter limit of sqlite
+    let ids = (1..=900)
+        .rev()
+        .map(|id| format!("{{id: {}}}", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("{{ CharactersByIds(ids: [{}]) {{ id }} }}", ids);
+    let res = execute_query(&schema, &ctx, &query);
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let items = res[0]["CharactersByIds"].as_array().unwrap();
+    assert_eq!(items.len(), 900);
+    assert!(items[..895].iter().all(|item| item.is_null()));
+    assert_eq!(
+        items[895..],
+        [
+            json!({"id": 5}),
+            json!({"id": 4}),
+            json!({"id": 3}),
+            json!({"id": 2}),
+            json!({"id": 1}),
+        ]
+    );
+}
@@ -0,0 +1,168 @@
+use wundergraph::query_builder::mutations::ValidationErrors;
+use wundergraph::ValidateInput;
+
+fn no_admin(name: &String) -> Result<(), String> {
+    if name == "admin" {
+        Err(String::from("is reserved"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(ValidateInput)]
+struct NewUser {
+    #[wundergraph(validate(non_empty, length(max = 5), custom = "no_admin"))]
+    name: String,
+    #[wundergraph(validate(range(min = "18", max = "99")))]
+    age: i32,
+    #[wundergraph(validate(length(min = 1)))]
+    tags: Vec<String>,
+    #[wundergraph(validate(range(min = "0.5")))]
+    score: Option<f64>,
+}
+
+#[derive(juniper::GraphQLInputObject, ValidateInput)]
+struct UserChangeset {
+    #[graphql(name = "displayName")]
+    #[wundergraph(validate(non_empty))]
+    display_name: Option<String>,
+    #[wundergraph(validate(length(min = 2)))]
+    nick_name: Option<Option<String>>,
+}
+
+fn messages(errors: &ValidationErrors) -> Vec<(Option<usize>, &'static str, String)> {
+    errors
+        .errors()
+        .iter()
+        .map(|e| (e.index(), e.field(), e.message().to_owned()))
+        .collect()
+}
+
+fn valid_user() -> NewUser {
+    NewUser {
+        name: String::from("Luke"),
+        age: 19,
+        tags: vec![String::from("jedi")],
+        score: None,
+    }
+}
+
+#[test]
+fn valid_input() {
+    assert_eq!(ValidationErrors::check(&valid_user()), Ok(()));
+    let changeset = UserChangeset {
+        display_name: None,
+        nick_name: Some(None),
+    };
+    assert_eq!(ValidationErrors::check(&changeset), Ok(()));
+}
+
+#[test]
+fn collect_all_violations() {
+    let user = NewUser {
+        name: String::new(),
+        age: 100,
+        tags: Vec::new(),
+        score: Some(0.1),
+    };
+    let errors = ValidationErrors::check(&user).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![
+            (None, "name", String::from("must not be empty")),
+            (None, "age", String::from("must be at most 99")),
+            (
+                None,
+                "tags",
+                String::from("must have a length of at least 1")
+            ),
+            (None, "score", String::from("must be at least 0.5")),
+        ]
+    );
+}
+
+#[test]
+fn length_counts_characters() {
+    let mut user = valid_user();
+    user.name = String::from("Ÿödæß");
+    assert_eq!(ValidationErrors::check(&user), Ok(()));
+    user.name = String::from("Anakin");
+    let errors = ValidationErrors::check(&user).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![(
+            None,
+            "name",
+            String::from("must have a length of at most 5")
+        )]
+    );
+}
+
+#[test]
+fn custom_rule() {
+    let mut user = valid_user();
+    user.name = String::from("admin");
+    let errors = ValidationErrors::check(&user).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![(None, "name", String::from("is reserved"))]
+    );
+}
+
+#[test]
+fn optional_fields_use_graphql_names() {
+    let changeset = UserChangeset {
+        display_name: Some(String::new()),
+        nick_name: Some(Some(String::from("x"))),
+    };
+    let errors = ValidationErrors::check(&changeset).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![
+            (None, "displayName", String::from("must not be empty")),
+            (
+                None,
+                "nickName",
+                String::from("must have a length of at least 2")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn batch_reports_indices() {
+    let mut invalid = valid_user();
+    invalid.age = 3;
+    let errors = ValidationErrors::check_batch(&[valid_user(), invalid]).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![(Some(1), "age", String::from("must be at least 18"))]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_rule() {
+    #[derive(ValidateInput)]
+    struct NewPlanet {
+        #[wundergraph(validate(regex = "^[A-Z][a-z]+$"))]
+        name: String,
+    }
+
+    let planet = NewPlanet {
+        name: String::from("Tatooine"),
+    };
+    assert_eq!(ValidationErrors::check(&planet), Ok(()));
+    let planet = NewPlanet {
+        name: String::from("tatooine"),
+    };
+    let errors = ValidationErrors::check(&planet).unwrap_err();
+    assert_eq!(
+        messages(&errors),
+        vec![(
+            None,
+            "name",
+            String::from("must match the pattern `^[A-Z][a-z]+$`")
+        )]
+    );
+}
@@ -15,6 +15,7 @@ pub struct ColumnInformation {
     pub type_name: String,
     pub nullable: bool,
     pub has_default: bool,
+    pub max_length: Option<u32>,
 }
 
 #[derive(Debug)]
@@ -23,6 +24,7 @@ pub struct ColumnType {
     pub is_array: bool,
     pub is_nullable: bool,
     pub is_unsigned: bool,
+    pub is_enum: bool,
 }
 
 use std::fmt;
@@ -38,6 +40,9 @@ impl fmt::Display for ColumnType {
         if self.is_unsigned {
             write!(out, "Unsigned<")?;
         }
+        if self.is_enum {
+            write!(out, "sql_types::")?;
+        }
         write!(out, "{}", self.rust_name)?;
         if self.is_unsigned {
             write!(out, ">")?;
@@ -52,6 +57,41 @@ impl fmt::Display for ColumnType {
     }
 }
 
+/// A native postgres enum type, created by `CREATE TYPE ... AS ENUM`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumType {
+    pub type_name: String,
+    pub labels: Vec<String>,
+}
+
+impl EnumType {
+    /// Name of the generated rust enum and of the corresponding sql type
+    pub fn rust_name(&self) -> String {
+        upper_camel_case(&self.type_name)
+    }
+
+    /// Pairs of rust variant name and enum label
+    pub fn variants(&self) -> impl Iterator<Item = (String, &str)> {
+        self.labels.iter().map(|l| (upper_camel_case(l), l as &str))
+    }
+}
+
+fn upper_camel_case(name: &str) -> String {
+    let name = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let p = p.to_lowercase();
+            p[..1].to_uppercase() + &p[1..]
+        })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_alphabetic()) {
+        name
+    } else {
+        format!("_{}", name)
+    }
+}
+
 #[derive(Debug)]
 pub struct ColumnDefinition {
     pub sql_name: String,
@@ -59,10 +99,17 @@ pub struct ColumnDefinition {
     pub docs: String,
     pub rust_name: Option<String>,
     pub has_default: bool,
+    pub max_length: Option<u32>,
 }
 
 impl ColumnInformation {
-    pub fn new<T, U>(column_name: T, type_name: U, nullable: bool, has_default: bool) -> Self
+    pub fn new<T, U>(
+        column_name: T,
+        type_name: U,
+        nullable: bool,
+        has_default: bool,
+        max_length: Option<u32>,
+    ) -> Self
     where
         T: Into<String>,
         U: Into<String>,
@@ -72,6 +119,7 @@ impl ColumnInformation {
             type_name: type_name.into(),
             nullable,
             has_default,
+            max_length,
         }
     }
 }
@@ -80,12 +128,13 @@ impl ColumnInformation {
 impl<ST, DB> Queryable<ST, DB> for ColumnInformation
 where
     DB: Backend + UsesInformationSchema,
-    (String, String, String, Option<String>): FromSqlRow<ST, DB>,
+    (String, String, String, Option<String>, Option<i32>): FromSqlRow<ST, DB>,
 {
-    type Row = (String, String, String, Option<String>);
+    type Row = (String, String, String, Option<String>, Option<i32>);
 
     fn build(row: Self::Row) -> Self {
-        Self::new(row.0, row.1, row.2 == "YES", row.3.is_some())
+        let max_length = row.4.map(|l| l as u32);
+        Self::new(row.0, row.1, row.2 == "YES", row.3.is_some(), max_length)
     }
 }
 
@@ -97,8 +146,22 @@ where
     type Row = (i32, String, String, bool, Option<String>, bool);
 
     fn build(row: Self::Row) -> Self {
-        Self::new(row.1, row.2, !row.3, row.4.is_some())
+        let max_length = declared_max_length(&row.2);
+        Self::new(row.1, row.2, !row.3, row.4.is_some(), max_length)
+    }
+}
+
+/// Sqlite does not enforce length limits, but keeps the declared type
+/// (for example `VARCHAR(255)`) around
+#[cfg(feature = "sqlite")]
+fn declared_max_length(type_name: &str) -> Option<u32> {
+    let type_name = type_name.to_lowercase();
+    if !type_name.contains("char") {
+        return None;
     }
+    let start = type_name.find('(')?;
+    let end = type_name[start..].find(')')? + start;
+    type_name[start + 1..end].trim().parse().ok()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -49,15 +49,36 @@ fn get_column_information(
     }
 }
 
+#[cfg_attr(not(feature = "postgres"), allow(unused_variables))]
+pub fn load_enum_types(
+    database_url: &str,
+    schema_name: Option<&str>,
+) -> Result<Vec<EnumType>, Box<dyn Error>> {
+    let connection = InferConnection::establish(database_url)?;
+
+    match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => Ok(Vec::new()),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(c) => {
+            super::pg::load_enum_types(&c, schema_name).map_err(Into::into)
+        }
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(_) => Ok(Vec::new()),
+    }
+}
+
+#[cfg_attr(not(feature = "postgres"), allow(unused_variables))]
 fn determine_column_type(
     attr: &ColumnInformation,
     conn: &InferConnection,
+    enum_types: &[EnumType],
 ) -> Result<ColumnType, Box<dyn Error>> {
     match *conn {
         #[cfg(feature = "sqlite")]
         InferConnection::Sqlite(_) => super::sqlite::determine_column_type(attr),
         #[cfg(feature = "postgres")]
-        InferConnection::Pg(_) => super::pg::determine_column_type(attr),
+        InferConnection::Pg(_) => super::pg::determine_column_type(attr, enum_types),
         #[cfg(feature = "mysql")]
         InferConnection::Mysql(_) => super::mysql::determine_column_type(attr),
     }
@@ -122,7 +143,11 @@ macro_rules! doc_comment {
     };
 }
 
-pub fn load_table_data(database_url: &str, name: TableName) -> Result<TableData, Box<dyn Error>> {
+pub fn load_table_data(
+    database_url: &str,
+    name: TableName,
+    enum_types: &[EnumType],
+) -> Result<TableData, Box<dyn Error>> {
     let connection = InferConnection::establish(database_url)?;
     let docs = doc_comment!(
         "Representation of the `{}` table.
@@ -144,7 +169,7 @@ pub fn load_table_data(database_url: &str, name: TableName) -> Result<TableData,
     let column_data = get_column_information(&connection, &name)?
         .into_iter()
         .map(|c| {
-            let ty = determine_column_type(&c, &connection)?;
+            let ty = determine_column_type(&c, &connection, enum_types)?;
             let rust_name = if RESERVED_NAMES.contains(&c.column_name.as_str()) {
                 Some(format!("{}_", c.column_name))
             } else {
@@ -166,6 +191,7 @@ pub fn load_table_data(database_url: &str, name: TableName) -> Result<TableData,
                 ty,
                 rust_name,
                 has_default: c.has_default,
+                max_length: c.max_length,
             })
         }).collect::<Result<_, Box<dyn Error>>>()?;
 
@@ -79,6 +79,7 @@ mod information_schema {
             udt_name -> VarChar,
             column_type -> VarChar,
             column_default -> Nullable<VarChar>,
+            character_maximum_length -> Nullable<Integer>,
         }
     }
 
@@ -121,6 +122,7 @@ where
     Conn: Connection,
     Conn::Backend: UsesInformationSchema,
     String: FromSql<sql_types::Text, Conn::Backend>,
+    i32: FromSql<sql_types::Integer, Conn::Backend>,
 {
     use self::information_schema::columns::dsl::*;
 
@@ -131,7 +133,13 @@ where
 
     let type_column = Conn::Backend::type_column();
     columns
-        .select((column_name, type_column, is_nullable, column_default))
+        .select((
+            column_name,
+            type_column,
+            is_nullable,
+            column_default,
+            character_maximum_length,
+        ))
         .filter(table_name.eq(&table.name))
         .filter(table_schema.eq(schema_name))
         .order(ordinal_position)
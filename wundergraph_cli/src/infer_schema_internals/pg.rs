@@ -1,9 +1,73 @@
 use std::error::Error;
 use std::io::{stderr, Write};
 
+use diesel::pg::PgConnection;
+use diesel::*;
+
 use super::data_structures::*;
 
-pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box<dyn Error>> {
+mod pg_catalog {
+    table! {
+        pg_catalog.pg_type (oid) {
+            oid -> Oid,
+            typname -> VarChar,
+            typnamespace -> Oid,
+        }
+    }
+
+    table! {
+        pg_catalog.pg_enum (oid) {
+            oid -> Oid,
+            enumtypid -> Oid,
+            enumsortorder -> Float,
+            enumlabel -> VarChar,
+        }
+    }
+
+    table! {
+        pg_catalog.pg_namespace (oid) {
+            oid -> Oid,
+            nspname -> VarChar,
+        }
+    }
+
+    joinable!(pg_enum -> pg_type (enumtypid));
+    joinable!(pg_type -> pg_namespace (typnamespace));
+    allow_tables_to_appear_in_same_query!(pg_enum, pg_type, pg_namespace);
+}
+
+pub fn load_enum_types(
+    conn: &PgConnection,
+    schema_name: Option<&str>,
+) -> QueryResult<Vec<EnumType>> {
+    use self::pg_catalog::{pg_enum, pg_namespace, pg_type};
+
+    let labels = pg_enum::table
+        .inner_join(pg_type::table.inner_join(pg_namespace::table))
+        .filter(pg_namespace::nspname.eq(schema_name.unwrap_or("public")))
+        .order((pg_type::typname, pg_enum::enumsortorder))
+        .select((pg_type::typname, pg_enum::enumlabel))
+        .load::<(String, String)>(conn)?;
+
+    let mut enum_types = Vec::<EnumType>::new();
+    for (type_name, label) in labels {
+        if enum_types.last().map_or(true, |e| e.type_name != type_name) {
+            enum_types.push(EnumType {
+                type_name,
+                labels: Vec::new(),
+            });
+        }
+        if let Some(e) = enum_types.last_mut() {
+            e.labels.push(label);
+        }
+    }
+    Ok(enum_types)
+}
+
+pub fn determine_column_type(
+    attr: &ColumnInformation,
+    enum_types: &[EnumType],
+) -> Result<ColumnType, Box<dyn Error>> {
     let is_array = attr.type_name.starts_with('_');
     let tpe = if is_array {
         &attr.type_name[1..]
@@ -27,14 +91,101 @@ pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box
         )?;
     }
 
+    if let Some(enum_type) = enum_types.iter().find(|e| e.type_name == tpe) {
+        return Ok(ColumnType {
+            rust_name: enum_type.rust_name(),
+            is_array,
+            is_nullable: attr.nullable,
+            is_unsigned: false,
+            is_enum: true,
+        });
+    }
+
     Ok(ColumnType {
         rust_name: capitalize(tpe),
         is_array,
         is_nullable: attr.nullable,
         is_unsigned: false,
+        is_enum: false,
     })
 }
 
 fn capitalize(name: &str) -> String {
     name[..1].to_uppercase() + &name[1..]
 }
+
+#[cfg(test)]
+fn weather_enum() -> EnumType {
+    EnumType {
+        type_name: "weather".into(),
+        labels: vec!["sunny".into(), "partly cloudy".into()],
+    }
+}
+
+#[test]
+fn enum_columns_are_detected() {
+    let enum_types = [weather_enum()];
+    let column = ColumnInformation::new("weather", "weather", true, false, None);
+    let ty = determine_column_type(&column, &enum_types).unwrap();
+    assert!(ty.is_enum);
+    assert!(ty.is_nullable);
+    assert!(!ty.is_array);
+    assert_eq!("Weather", ty.rust_name);
+    assert_eq!("Nullable<sql_types::Weather>", ty.to_string());
+
+    let column = ColumnInformation::new("forecast", "_weather", false, false, None);
+    let ty = determine_column_type(&column, &enum_types).unwrap();
+    assert!(ty.is_enum);
+    assert!(ty.is_array);
+    assert_eq!("Array<sql_types::Weather>", ty.to_string());
+
+    let column = ColumnInformation::new("name", "text", false, false, None);
+    let ty = determine_column_type(&column, &enum_types).unwrap();
+    assert!(!ty.is_enum);
+    assert_eq!("Text", ty.rust_name);
+}
+
+#[test]
+fn enum_variants_are_camel_cased() {
+    let variants = weather_enum()
+        .variants()
+        .map(|(v, l)| (v, l.to_owned()))
+        .collect::<Vec<_>>();
+    assert_eq!("Weather", weather_enum().rust_name());
+    assert_eq!(
+        vec![
+            ("Sunny".to_owned(), "sunny".to_owned()),
+            ("PartlyCloudy".to_owned(), "partly cloudy".to_owned()),
+        ],
+        variants
+    );
+}
+
+#[test]
+fn load_enum_types_returns_labels_in_sort_order() {
+    use diesel::connection::SimpleConnection;
+
+    let database_url = ::std::env::var("PG_DATABASE_URL")
+        .or_else(|_| ::std::env::var("DATABASE_URL"))
+        .expect("You need to set `PG_DATABASE_URL` as environment variable");
+    let conn = PgConnection::establish(&database_url).unwrap();
+    conn.begin_test_transaction().unwrap();
+    conn.batch_execute(
+        "CREATE SCHEMA enum_test;
+         CREATE TYPE enum_test.weather AS ENUM ('sunny', 'rainy');
+         ALTER TYPE enum_test.weather ADD VALUE 'partly cloudy' BEFORE 'rainy';",
+    )
+    .unwrap();
+
+    let enum_types = load_enum_types(&conn, Some("enum_test")).unwrap();
+    assert_eq!(
+        vec![EnumType {
+            type_name: "weather".into(),
+            labels: vec!["sunny".into(), "partly cloudy".into(), "rainy".into()],
+        }],
+        enum_types
+    );
+    assert!(load_enum_types(&conn, Some("not_existing"))
+        .unwrap()
+        .is_empty());
+}
@@ -188,6 +188,7 @@ pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box
         is_array: false,
         is_nullable: attr.nullable,
         is_unsigned: false,
+        is_enum: false,
     })
 }
 
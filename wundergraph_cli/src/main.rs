@@ -44,6 +44,9 @@ enum Wundergraph {
     PrintSchema {
         database_url: String,
         schema: Option<String>,
+        /// Allow to set nullable columns to null in update mutations
+        #[structopt(long = "explicit-nulls")]
+        explicit_nulls: bool,
     },
 }
 
@@ -52,7 +55,12 @@ fn main() {
         Wundergraph::PrintSchema {
             database_url,
             schema,
-        } => print_schema::print(&database_url, schema.as_ref().map(|s| s as &str))
-            .expect("Failed to infer the schema"),
+            explicit_nulls,
+        } => print_schema::print(
+            &database_url,
+            schema.as_ref().map(|s| s as &str),
+            explicit_nulls,
+        )
+        .expect("Failed to infer the schema"),
     }
 }
@@ -4,15 +4,20 @@ use std::error::Error;
 mod print_helper;
 use self::print_helper::*;
 
-pub fn print(database_url: &str, schema_name: Option<&str>) -> Result<(), Box<dyn Error>> {
+pub fn print(
+    database_url: &str,
+    schema_name: Option<&str>,
+    explicit_nulls: bool,
+) -> Result<(), Box<dyn Error>> {
     let table_names = load_table_names(database_url, schema_name)?;
     let foreign_keys = load_foreign_key_constraints(database_url, schema_name)?;
     let foreign_keys =
         remove_unsafe_foreign_keys_for_codegen(database_url, &foreign_keys, &table_names);
+    let enum_types = load_enum_types(database_url, schema_name)?;
 
     let table_data = table_names
         .into_iter()
-        .map(|t| load_table_data(database_url, t))
+        .map(|t| load_table_data(database_url, t, &enum_types))
         .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
     let definitions = TableDefinitions {
         tables: &table_data,
@@ -26,11 +31,40 @@ pub fn print(database_url: &str, schema_name: Option<&str>) -> Result<(), Box<dy
 
     let mutations = GraphqlMutations {
         tables: &table_data,
+        explicit_nulls,
     };
-    println!("use wundergraph::query_builder::types::{{HasMany, HasOne}};");
+    if table_data
+        .iter()
+        .flat_map(|t| &t.column_data)
+        .any(|c| is_binary(&c.ty))
+    {
+        println!("use wundergraph::query_builder::types::{{Base64, HasMany, HasOne}};");
+    } else {
+        println!("use wundergraph::query_builder::types::{{HasMany, HasOne}};");
+    }
     println!("use wundergraph::scalar::WundergraphScalarValue;");
-    println!("use wundergraph::WundergraphEntity;");
+    let mut derives = Vec::new();
+    if explicit_nulls {
+        derives.push("ExplicitNulls");
+    }
+    if uses_validate_input(&table_data) {
+        derives.push("ValidateInput");
+    }
+    derives.push("WundergraphEntity");
+    if !enum_types.is_empty() {
+        derives.push("WundergraphValue");
+    }
+    println!("use wundergraph::{{{}}};", derives.join(", "));
     println!();
+    if !enum_types.is_empty() {
+        println!(
+            "{}",
+            EnumDefinitions {
+                enum_types: &enum_types
+            }
+        );
+        println!();
+    }
     println!("{}", definitions);
     println!();
     println!("{}", graphql);
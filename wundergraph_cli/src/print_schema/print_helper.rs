@@ -62,6 +62,10 @@ impl<'a> Display for TableDefinition<'a> {
                     writeln!(out, "use {};", import)?;
                 }
                 writeln!(out)?;
+            } else if self.table.column_data.iter().any(|c| c.ty.is_enum) {
+                writeln!(out, "use diesel::sql_types::*;")?;
+                writeln!(out, "use super::sql_types;")?;
+                writeln!(out)?;
             }
 
             if self.include_docs {
@@ -92,6 +96,51 @@ impl<'a> Display for TableDefinition<'a> {
     }
 }
 
+pub struct EnumDefinitions<'a> {
+    pub enum_types: &'a [EnumType],
+}
+
+impl<'a> Display for EnumDefinitions<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "pub mod sql_types {{")?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for (i, e) in self.enum_types.iter().enumerate() {
+                if i != 0 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "#[derive(Debug, Clone, Copy, QueryId, SqlType)]")?;
+                writeln!(out, "#[postgres(type_name = \"{}\")]", e.type_name)?;
+                writeln!(out, "pub struct {};", e.rust_name())?;
+            }
+        }
+        writeln!(f, "}}")?;
+
+        for e in self.enum_types {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AsExpression, FromSqlRow, \
+                 GraphQLEnum, WundergraphValue)]"
+            )?;
+            writeln!(f, "#[sql_type = \"sql_types::{}\"]", e.rust_name())?;
+            writeln!(f, "#[wundergraph(pg_enum)]")?;
+            write!(f, "pub enum {} {{", e.rust_name())?;
+            {
+                let mut out = PadAdapter::new(f);
+                writeln!(out)?;
+                for (variant, label) in e.variants() {
+                    writeln!(out, "#[wundergraph(sql_name = {:?})]", label)?;
+                    writeln!(out, "{},", variant)?;
+                }
+            }
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
+
 pub struct ColumnDefinitions<'a> {
     columns: &'a [ColumnDefinition],
     include_docs: bool,
@@ -208,6 +257,11 @@ struct GraphqlData<'a> {
     foreign_keys: &'a [ForeignKeyConstraint],
 }
 
+/// Binary columns are mapped to `wundergraph::query_builder::types::Base64`
+pub fn is_binary(ty: &ColumnType) -> bool {
+    ty.rust_name == "Binary" || ty.rust_name == "Bytea" || ty.rust_name == "Blob"
+}
+
 fn uppercase_table_name(name: &str) -> String {
     let mut next_uppercase = true;
     name.to_lowercase()
@@ -236,6 +290,38 @@ fn fix_table_name(name: &str) -> String {
     }
 }
 
+fn validation_rule(column: &ColumnDefinition) -> Option<String> {
+    match column.max_length {
+        Some(max_length) if !column.ty.is_array => Some(format!(
+            "#[wundergraph(validate(length(max = {})))]",
+            max_length
+        )),
+        _ => None,
+    }
+}
+
+fn write_validation_rules<W>(f: &mut W, column: &ColumnDefinition) -> fmt::Result
+where
+    W: Write,
+{
+    if let Some(rule) = validation_rule(column) {
+        writeln!(f, "{}", rule)?;
+    }
+    Ok(())
+}
+
+fn insertable_columns(table: &TableData) -> impl Iterator<Item = &ColumnDefinition> {
+    table.column_data.iter().filter(|c| !c.has_default)
+}
+
+/// Whether any of the generated mutation inputs derives `ValidateInput`
+pub fn uses_validate_input(tables: &[TableData]) -> bool {
+    tables.iter().any(|t| {
+        t.primary_key.len() != t.column_data.len()
+            && t.column_data.iter().any(|c| validation_rule(c).is_some())
+    })
+}
+
 fn write_primary_key_section<W>(f: &mut W, table: &TableData) -> fmt::Result
 where
     W: Write,
@@ -351,22 +437,31 @@ impl<'a> Display for GraphqlType<'a> {
                 is_nullable,
                 ref rust_name,
                 is_unsigned,
+                is_enum,
             } => {
                 let t = ColumnType {
                     is_array: false,
                     is_nullable,
                     rust_name: rust_name.clone(),
                     is_unsigned,
+                    is_enum,
                 };
                 write!(
                     f,
-                    "Vec<{}>",
+                    "wundergraph::query_builder::types::PgArray<{}>",
                     GraphqlType {
                         sql_type: &t,
                         ..self.clone()
                     }
                 )?;
             }
+            ColumnType {
+                ref rust_name,
+                is_enum: true,
+                ..
+            } => {
+                write!(f, "{}", rust_name)?;
+            }
             ColumnType { ref rust_name, .. } if rust_name == "Int2" || rust_name == "SmallInt" => {
                 write!(f, "i16")?;
             }
@@ -392,15 +487,37 @@ impl<'a> Display for GraphqlType<'a> {
             ColumnType { ref rust_name, .. } if rust_name == "Timestamptz" => {
                 write!(f, "DateTime<Utc>")?;
             }
-            ColumnType { ref rust_name, .. } if rust_name == "Timestamp" => {
-                write!(f, "NaiveDateTime")?;
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Timestamp" || rust_name == "Datetime" =>
+            {
+                write!(f, "wundergraph::query_builder::types::IsoNaiveDateTime")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Date" => {
+                write!(f, "NaiveDate")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Time" => {
+                write!(f, "NaiveTime")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Interval" => {
+                write!(f, "diesel::pg::data_types::PgInterval")?;
             }
             ColumnType { ref rust_name, .. } if rust_name == "Uuid" => {
                 write!(f, "Uuid")?;
             }
-            ColumnType { ref rust_name, .. } if rust_name == "Numeric" => {
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Numeric" || rust_name == "Decimal" =>
+            {
                 write!(f, "BigDecimal")?;
             }
+            ref t if is_binary(t) => {
+                write!(f, "Base64")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Jsonb" => {
+                write!(f, "serde_json::Value")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Json" => {
+                write!(f, "wundergraph::query_builder::types::Json")?;
+            }
             ColumnType { ref rust_name, .. } => write!(f, "{}", fix_table_name(rust_name))?,
         }
         Ok(())
@@ -409,13 +526,23 @@ impl<'a> Display for GraphqlType<'a> {
 
 pub struct GraphqlMutations<'a> {
     pub tables: &'a [TableData],
+    /// Derive `ExplicitNulls` for changesets, so that nullable columns
+    /// could be set to null
+    pub explicit_nulls: bool,
 }
 
 impl<'a> Display for GraphqlMutations<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for t in self.tables {
             writeln!(f, "{}", GraphqlInsertable { table: t })?;
-            writeln!(f, "{}", GraphqlChangeSet { table: t })?;
+            writeln!(
+                f,
+                "{}",
+                GraphqlChangeSet {
+                    table: t,
+                    explicit_nulls: self.explicit_nulls,
+                }
+            )?;
         }
 
         write!(f, "wundergraph::mutation_object!{{")?;
@@ -455,19 +582,24 @@ impl<'a> Display for GraphqlInsertable<'a> {
         if self.table.primary_key.len() == self.table.column_data.len() {
             return Ok(());
         }
-        writeln!(f, "#[derive(Insertable, GraphQLInputObject, Clone, Debug)]")?;
+        write!(f, "#[derive(Insertable, GraphQLInputObject, ")?;
+        if insertable_columns(self.table).any(|c| validation_rule(c).is_some()) {
+            write!(f, "ValidateInput, ")?;
+        }
+        writeln!(f, "Clone, Debug)]")?;
         writeln!(f, "#[graphql(scalar = \"WundergraphScalarValue\")]")?;
         writeln!(f, "#[table_name = \"{}\"]", self.table.name)?;
         write!(f, "pub struct New{} {{", fix_table_name(&self.table.name.name))?;
         {
             let mut out = PadAdapter::new(f);
             writeln!(out)?;
-            for c in self.table.column_data.iter().filter(|c| !c.has_default) {
+            for c in insertable_columns(self.table) {
                 let t = GraphqlType {
                     sql_type: &c.ty,
                     allow_option: true,
                 };
                 let name = c.rust_name.as_ref().unwrap_or(&c.sql_name);
+                write_validation_rules(&mut out, c)?;
                 writeln!(out, "{}: {},", name, t)?;
             }
         }
@@ -478,6 +610,7 @@ impl<'a> Display for GraphqlInsertable<'a> {
 
 struct GraphqlChangeSet<'a> {
     table: &'a TableData,
+    explicit_nulls: bool,
 }
 
 impl<'a> Display for GraphqlChangeSet<'a> {
@@ -485,10 +618,22 @@ impl<'a> Display for GraphqlChangeSet<'a> {
         if self.table.primary_key.len() == self.table.column_data.len() {
             return Ok(());
         }
-        writeln!(
+        write!(
             f,
-            "#[derive(AsChangeset, Identifiable, GraphQLInputObject, Clone, Debug)]"
+            "#[derive(AsChangeset, Identifiable, GraphQLInputObject, "
         )?;
+        if self
+            .table
+            .column_data
+            .iter()
+            .any(|c| validation_rule(c).is_some())
+        {
+            write!(f, "ValidateInput, ")?;
+        }
+        if self.explicit_nulls {
+            write!(f, "ExplicitNulls, ")?;
+        }
+        writeln!(f, "Clone, Debug)]")?;
         writeln!(f, "#[graphql(scalar = \"WundergraphScalarValue\")]")?;
         writeln!(f, "#[table_name = \"{}\"]", self.table.name)?;
         write_primary_key_section(f, self.table)?;
@@ -506,10 +651,209 @@ impl<'a> Display for GraphqlChangeSet<'a> {
                     allow_option: true,
                 };
                 let name = c.rust_name.as_ref().unwrap_or(&c.sql_name);
-                writeln!(out, "{}: {},", name, t)?;
+                write_validation_rules(&mut out, c)?;
+                if c.ty.is_nullable && self.explicit_nulls {
+                    // `None` leaves the column unchanged, `Some(None)` sets it to null
+                    writeln!(out, "{}: Option<{}>,", name, t)?;
+                } else {
+                    writeln!(out, "{}: {},", name, t)?;
+                }
             }
         }
         writeln!(f, "}}")?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+fn array_table() -> TableData {
+    let column = |name: &str, rust_name: &str, is_array, is_nullable| ColumnDefinition {
+        sql_name: name.into(),
+        ty: ColumnType {
+            rust_name: rust_name.into(),
+            is_array,
+            is_nullable,
+            is_unsigned: false,
+            is_enum: false,
+        },
+        docs: String::new(),
+        rust_name: None,
+        has_default: false,
+        max_length: None,
+    };
+    TableData {
+        name: TableName::from_name("posts"),
+        primary_key: vec!["id".into()],
+        column_data: vec![
+            column("id", "Int4", false, false),
+            column("tags", "Text", true, false),
+            column("scores", "Int4", true, true),
+        ],
+        docs: String::new(),
+    }
+}
+
+#[test]
+fn array_columns_are_printed_as_arrays() {
+    let tables = [array_table()];
+    let definitions = TableDefinitions {
+        tables: &tables,
+        include_docs: false,
+        import_types: None,
+    }
+    .to_string();
+    assert!(definitions.contains("tags -> Array<Text>,"));
+    assert!(definitions.contains("scores -> Nullable<Array<Int4>>,"));
+}
+
+#[test]
+fn array_columns_are_mapped_to_pg_array() {
+    let tables = [array_table()];
+    let graphql = GraphqlDefinition {
+        tables: &tables,
+        foreign_keys: Vec::new(),
+    }
+    .to_string();
+    assert!(graphql.contains("tags: wundergraph::query_builder::types::PgArray<String>,"));
+    assert!(graphql.contains("scores: Option<wundergraph::query_builder::types::PgArray<i32>>,"));
+
+    let insertable = GraphqlInsertable { table: &tables[0] }.to_string();
+    assert!(insertable.contains("tags: wundergraph::query_builder::types::PgArray<String>,"));
+    assert!(insertable.contains("scores: Option<wundergraph::query_builder::types::PgArray<i32>>,"));
+}
+
+#[test]
+fn timestamp_columns_are_mapped_to_iso_naive_date_time() {
+    let mut table = array_table();
+    table.column_data.push(ColumnDefinition {
+        sql_name: "created_at".into(),
+        ty: ColumnType {
+            rust_name: "Timestamp".into(),
+            is_array: false,
+            is_nullable: false,
+            is_unsigned: false,
+            is_enum: false,
+        },
+        docs: String::new(),
+        rust_name: None,
+        has_default: false,
+        max_length: None,
+    });
+    let graphql = GraphqlInsertable { table: &table }.to_string();
+    assert!(graphql.contains("created_at: wundergraph::query_builder::types::IsoNaiveDateTime,"));
+}
+
+#[test]
+fn enum_types_are_printed_as_pg_enums() {
+    let enum_types = [EnumType {
+        type_name: "weather".into(),
+        labels: vec!["sunny".into(), "partly cloudy".into()],
+    }];
+    let definitions = EnumDefinitions {
+        enum_types: &enum_types,
+    }
+    .to_string();
+    assert!(definitions.contains("#[postgres(type_name = \"weather\")]\n    pub struct Weather;"));
+    assert!(definitions.contains(
+        "#[sql_type = \"sql_types::Weather\"]\n#[wundergraph(pg_enum)]\npub enum Weather {"
+    ));
+    assert!(
+        definitions.contains("    #[wundergraph(sql_name = \"partly cloudy\")]\n    PartlyCloudy,")
+    );
+}
+
+#[test]
+fn binary_columns_are_mapped_to_base64() {
+    for name in &["Binary", "Bytea", "Blob"] {
+        let ty = ColumnType {
+            rust_name: (*name).into(),
+            is_array: false,
+            is_nullable: true,
+            is_unsigned: false,
+            is_enum: false,
+        };
+        assert!(is_binary(&ty));
+        let t = GraphqlType {
+            sql_type: &ty,
+            allow_option: true,
+        };
+        assert_eq!("Option<Base64>", t.to_string());
+    }
+}
+
+#[cfg(test)]
+fn heros_table(name_max_length: Option<u32>) -> TableData {
+    let column = |name: &str, rust_name: &str, is_nullable, max_length| ColumnDefinition {
+        sql_name: name.into(),
+        ty: ColumnType {
+            rust_name: rust_name.into(),
+            is_array: false,
+            is_nullable,
+            is_unsigned: false,
+            is_enum: false,
+        },
+        docs: String::new(),
+        rust_name: None,
+        has_default: false,
+        max_length,
+    };
+    TableData {
+        name: TableName::from_name("heros"),
+        primary_key: vec!["id".into()],
+        column_data: vec![
+            column("id", "Int4", false, None),
+            column("name", "Varchar", false, name_max_length),
+            column("hair_color", "Text", true, None),
+        ],
+        docs: String::new(),
+    }
+}
+
+#[test]
+fn validate_input_is_only_derived_for_validation_rules() {
+    let tables = [heros_table(None)];
+    let mutations = GraphqlMutations {
+        tables: &tables,
+        explicit_nulls: false,
+    }
+    .to_string();
+    assert!(!mutations.contains("ValidateInput"));
+    assert!(!uses_validate_input(&tables));
+
+    let tables = [heros_table(Some(50))];
+    let mutations = GraphqlMutations {
+        tables: &tables,
+        explicit_nulls: false,
+    }
+    .to_string();
+    assert!(mutations
+        .contains("#[derive(Insertable, GraphQLInputObject, ValidateInput, Clone, Debug)]"));
+    assert!(mutations.contains(
+        "#[derive(AsChangeset, Identifiable, GraphQLInputObject, ValidateInput, Clone, Debug)]"
+    ));
+    assert!(mutations.contains("#[wundergraph(validate(length(max = 50)))]\n    name: String,"));
+    assert!(uses_validate_input(&tables));
+}
+
+#[test]
+fn explicit_nulls_are_only_derived_if_requested() {
+    let tables = [heros_table(None)];
+    let changeset = GraphqlChangeSet {
+        table: &tables[0],
+        explicit_nulls: false,
+    }
+    .to_string();
+    assert!(changeset
+        .contains("#[derive(AsChangeset, Identifiable, GraphQLInputObject, Clone, Debug)]"));
+    assert!(changeset.contains("    hair_color: Option<String>,"));
+
+    let changeset = GraphqlChangeSet {
+        table: &tables[0],
+        explicit_nulls: true,
+    }
+    .to_string();
+    assert!(changeset.contains(
+        "#[derive(AsChangeset, Identifiable, GraphQLInputObject, ExplicitNulls, Clone, Debug)]"
+    ));
+    assert!(changeset.contains("    hair_color: Option<Option<String>>,"));
+}
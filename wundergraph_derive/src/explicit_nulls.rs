@@ -0,0 +1,59 @@
+use crate::diagnostic_shim::*;
+use crate::utils::{inner_ty_arg, wrap_in_dummy_mod};
+use crate::validate_input::graphql_name;
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
+    let fields = match item.data {
+        syn::Data::Struct(ref d) => &d.fields,
+        _ => return Err(Span::call_site().error("This derive can only be used on structs")),
+    };
+    let arms = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| {
+            inner_ty_arg(&f.ty, "Option", 0)
+                .and_then(|ty| inner_ty_arg(ty, "Option", 0))
+                .is_some()
+        })
+        .map(|(idx, f)| {
+            let graphql_name = graphql_name(idx, f)?;
+            let access = match f.ident {
+                Some(ref ident) => quote!(#ident),
+                None => {
+                    let index = syn::Index::from(idx);
+                    quote!(#index)
+                }
+            };
+            Ok(quote! {
+                #graphql_name => {
+                    self.#access = std::option::Option::Some(std::option::Option::None);
+                    true
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, Diagnostic>>()?;
+
+    let item_name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(
+        "explicit_nulls",
+        item_name,
+        &quote! {
+            use wundergraph::query_builder::mutations::ExplicitNulls;
+
+            impl #impl_generics ExplicitNulls for #item_name #ty_generics
+                #where_clause
+            {
+                fn set_explicit_null(&mut self, field: &str) -> bool {
+                    match field {
+                        #(#arms)*
+                        _ => false,
+                    }
+                }
+            }
+        },
+    ))
+}
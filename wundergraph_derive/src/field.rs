@@ -81,6 +81,10 @@ impl Field {
     pub fn sql_name(&self) -> &syn::Ident {
         &self.sql_name
     }
+
+    pub fn relation_mutations(&self) -> bool {
+        self.flags.nested_item("relation_mutations").is_ok()
+    }
 }
 
 #[derive(Debug)]
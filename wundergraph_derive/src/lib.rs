@@ -39,14 +39,17 @@ extern crate quote;
 extern crate syn;
 
 mod diagnostic_shim;
+mod explicit_nulls;
 mod field;
 mod meta;
 mod model;
 mod resolved_at_shim;
 mod utils;
+mod validate_input;
 
 mod wundergraph_entity;
 mod wundergraph_filter;
+mod wundergraph_scalar;
 pub(crate) mod wundergraph_value;
 
 use self::diagnostic_shim::Diagnostic;
@@ -57,16 +60,31 @@ pub fn derive_wundergraph_entity(input: TokenStream) -> TokenStream {
     expand_derive(input, wundergraph_entity::derive)
 }
 
-#[proc_macro_derive(WundergraphValue, attributes(sql_type, graphql))]
+#[proc_macro_derive(WundergraphValue, attributes(sql_type, graphql, wundergraph))]
 pub fn derive_wundergraph_value(input: TokenStream) -> TokenStream {
     expand_derive(input, wundergraph_value::derive)
 }
 
+#[proc_macro_derive(WundergraphScalar, attributes(sql_type, graphql))]
+pub fn derive_wundergraph_scalar(input: TokenStream) -> TokenStream {
+    expand_derive(input, wundergraph_scalar::derive)
+}
+
 #[proc_macro_derive(WundergraphFilter, attributes(wundergraph, table_name))]
 pub fn derive_wundergraph_filter(input: TokenStream) -> TokenStream {
     expand_derive(input, wundergraph_filter::derive)
 }
 
+#[proc_macro_derive(ValidateInput, attributes(wundergraph))]
+pub fn derive_validate_input(input: TokenStream) -> TokenStream {
+    expand_derive(input, validate_input::derive)
+}
+
+#[proc_macro_derive(ExplicitNulls, attributes(graphql))]
+pub fn derive_explicit_nulls(input: TokenStream) -> TokenStream {
+    expand_derive(input, explicit_nulls::derive)
+}
+
 fn expand_derive(
     input: TokenStream,
     f: fn(&syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic>,
@@ -1,5 +1,6 @@
 use proc_macro2::{Ident, Span};
 use syn;
+use std::convert::TryFrom;
 use syn::fold::Fold;
 use syn::spanned::Spanned;
 
@@ -162,6 +163,47 @@ impl MetaItem {
         }
     }
 
+    pub fn ty_value(&self) -> Result<syn::Type, Diagnostic> {
+        let value = self.lit_str_value()?;
+        value
+            .parse()
+            .map_err(|_| value.span().error(String::from("Expected a type")))
+    }
+
+    pub fn usize_value(&self) -> Result<usize, Diagnostic> {
+        use syn::Lit::*;
+
+        match *self.lit_value()? {
+            Int(ref i) => usize::try_from(i.value())
+                .map_err(|_| self.value_span().error(String::from("Integer out of range"))),
+            Str(ref s) => s
+                .value()
+                .parse()
+                .map_err(|_| self.value_span().error(String::from("Expected an integer"))),
+            _ => Err(self.span().error(format!(
+                "`{0}` must be in the form `{0} = 42`",
+                self.name()
+            ))),
+        }
+    }
+
+    pub fn expr_value(&self) -> Result<syn::Expr, Diagnostic> {
+        use syn::Lit::*;
+
+        match *self.lit_value()? {
+            Str(ref s) => syn::parse_str(&s.value())
+                .map_err(|_| self.value_span().error(String::from("Expected an expression"))),
+            ref lit @ Int(_) | ref lit @ Float(_) => Ok(syn::Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: lit.clone(),
+            })),
+            _ => Err(self.span().error(format!(
+                "`{0}` must be in the form `{0} = \"value\"`",
+                self.name()
+            ))),
+        }
+    }
+
     fn lit_value(&self) -> Result<&syn::Lit, Diagnostic> {
         use syn::Meta::*;
 
@@ -58,6 +58,26 @@ impl Model {
     pub fn filter_type(&self) -> Option<syn::Path> {
         self.flags.get_flag("filter").ok()
     }
+
+    pub fn soft_delete_column(&self) -> Option<syn::Ident> {
+        self.flags.get_flag("soft_delete").ok()
+    }
+
+    pub fn version_column(&self) -> Option<syn::Ident> {
+        self.flags.get_flag("version_column").ok()
+    }
+
+    pub fn key_codec(&self) -> Option<syn::Path> {
+        self.flags.get_flag("key_codec").ok()
+    }
+
+    pub fn unique_column_sets(&self) -> Result<Vec<Vec<syn::Ident>>, Diagnostic> {
+        self.flags
+            .nested()?
+            .filter(|m| m.name() == "unique")
+            .map(|m| m.nested()?.map(|c| c.word()).collect())
+            .collect()
+    }
 }
 
 fn fields_from_item_data(data: &syn::Data) -> Result<Vec<Field>, Diagnostic> {
@@ -67,6 +67,23 @@ pub fn inner_ty_arg<'a>(ty: &'a Type, type_name: &str, index: usize) -> Option<&
     })
 }
 
+/// Convert a rust field name into the graphql field name used by juniper
+pub fn to_camel_case(s: &str) -> String {
+    let mut dest = String::new();
+    for (i, part) in s.split('_').enumerate() {
+        if i == 0 {
+            dest.push_str(part);
+        } else {
+            let mut chars = part.chars();
+            if let Some(first) = chars.next() {
+                dest.extend(first.to_uppercase());
+                dest.push_str(chars.as_str());
+            }
+        }
+    }
+    dest
+}
+
 pub fn fix_span(maybe_bad_span: Span, fallback: Span) -> Span {
     let bad_span_debug = "Span(Span { lo: BytePos(0), hi: BytePos(0), ctxt: #0 })";
     if format!("{:?}", maybe_bad_span) == bad_span_debug {
@@ -0,0 +1,183 @@
+use crate::diagnostic_shim::*;
+use crate::meta::MetaItem;
+use crate::utils::{inner_ty_arg, to_camel_case, wrap_in_dummy_mod};
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
+    let fields = match item.data {
+        syn::Data::Struct(ref d) => &d.fields,
+        _ => return Err(Span::call_site().error("This derive can only be used on structs")),
+    };
+    let checks = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, f)| field_checks(idx, f).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let item_name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(
+        "validate_input",
+        item_name,
+        &quote! {
+            use wundergraph::query_builder::mutations::validate::{self, ValidateInput, ValidationErrors};
+
+            impl #impl_generics ValidateInput for #item_name #ty_generics
+                #where_clause
+            {
+                #[allow(unused_variables)]
+                fn validate(&self, errors: &mut ValidationErrors) {
+                    #(#checks)*
+                }
+            }
+        },
+    ))
+}
+
+fn field_checks(index: usize, field: &syn::Field) -> Result<Option<TokenStream>, Diagnostic> {
+    let mut rules = Vec::new();
+    for flags in MetaItem::all_with_name(&field.attrs, "wundergraph") {
+        if let Ok(validate) = flags.nested_item("validate") {
+            rules.extend(validate.nested()?);
+        }
+    }
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    let graphql_name = graphql_name(index, field)?;
+    let checks = rules
+        .iter()
+        .map(|r| rule_check(r, &graphql_name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let access = match field.ident {
+        Some(ref ident) => quote!(#ident),
+        None => {
+            let index = syn::Index::from(index);
+            quote!(#index)
+        }
+    };
+
+    let inner_option = inner_ty_arg(&field.ty, "Option", 0);
+    if inner_option.map_or(false, |ty| inner_ty_arg(ty, "Option", 0).is_some()) {
+        Ok(Some(quote! {
+            if let std::option::Option::Some(std::option::Option::Some(ref value)) = self.#access {
+                #(#checks)*
+            }
+        }))
+    } else if inner_option.is_some() {
+        Ok(Some(quote! {
+            if let std::option::Option::Some(ref value) = self.#access {
+                #(#checks)*
+            }
+        }))
+    } else {
+        Ok(Some(quote! {
+            {
+                let value = &self.#access;
+                #(#checks)*
+            }
+        }))
+    }
+}
+
+pub fn graphql_name(index: usize, field: &syn::Field) -> Result<String, Diagnostic> {
+    if let Some(name) = MetaItem::with_name(&field.attrs, "graphql")
+        .and_then(|m| m.nested_item("name").ok())
+    {
+        return name.str_value();
+    }
+    Ok(field
+        .ident
+        .as_ref()
+        .map_or_else(|| index.to_string(), |i| to_camel_case(&i.to_string())))
+}
+
+fn optional_bound<T>(
+    rule: &MetaItem,
+    name: &str,
+    f: impl Fn(&MetaItem) -> Result<T, Diagnostic>,
+) -> Result<Option<T>, Diagnostic> {
+    match rule.nested()?.find(|n| n.name() == name) {
+        Some(bound) => f(&bound).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn quote_option<T: quote::ToTokens>(v: Option<T>) -> TokenStream {
+    match v {
+        Some(v) => quote!(std::option::Option::Some(#v)),
+        None => quote!(std::option::Option::None),
+    }
+}
+
+#[cfg(feature = "regex")]
+fn check_regex(rule: &MetaItem, pattern: &str) -> Result<(), Diagnostic> {
+    regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|e| rule.value_span().error(format!("Invalid regex: {}", e)))
+}
+
+#[cfg(not(feature = "regex"))]
+fn check_regex(rule: &MetaItem, _pattern: &str) -> Result<(), Diagnostic> {
+    Err(rule
+        .span()
+        .error("The `regex` rule requires the `regex` feature of wundergraph"))
+}
+
+fn rule_check(rule: &MetaItem, graphql_name: &str) -> Result<TokenStream, Diagnostic> {
+    let check = match &*rule.name().to_string() {
+        "length" => {
+            rule.warn_if_other_options(&["min", "max"]);
+            let min = optional_bound(rule, "min", MetaItem::usize_value)?;
+            let max = optional_bound(rule, "max", MetaItem::usize_value)?;
+            if min.is_none() && max.is_none() {
+                return Err(rule
+                    .span()
+                    .error("`length` requires at least one of `min` or `max`"));
+            }
+            let min = quote_option(min);
+            let max = quote_option(max);
+            quote!(validate::length(value, #min, #max))
+        }
+        "range" => {
+            rule.warn_if_other_options(&["min", "max"]);
+            let min = optional_bound(rule, "min", MetaItem::expr_value)?;
+            let max = optional_bound(rule, "max", MetaItem::expr_value)?;
+            if min.is_none() && max.is_none() {
+                return Err(rule
+                    .span()
+                    .error("`range` requires at least one of `min` or `max`"));
+            }
+            let min = quote_option(min);
+            let max = quote_option(max);
+            quote!(validate::range(value, #min, #max))
+        }
+        "non_empty" => {
+            rule.word()?;
+            quote!(validate::non_empty(value))
+        }
+        "regex" => {
+            let pattern = rule.lit_str_value()?;
+            check_regex(rule, &pattern.value())?;
+            quote!(validate::regex(value, #pattern))
+        }
+        "custom" => {
+            let function = syn::parse_str::<syn::Path>(&rule.str_value()?)
+                .map_err(|_| rule.value_span().error("Expected a path"))?;
+            quote!(#function(value))
+        }
+        other => {
+            return Err(rule
+                .span()
+                .error(format!("Unknown validation rule `{}`", other)));
+        }
+    };
+    Ok(quote! {
+        if let std::result::Result::Err(e) = #check {
+            errors.add(#graphql_name, e);
+        }
+    })
+}
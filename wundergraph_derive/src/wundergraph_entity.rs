@@ -1,7 +1,9 @@
 use crate::diagnostic_shim::{Diagnostic, DiagnosticShim};
 use crate::field::Field;
 use crate::model::Model;
-use crate::utils::{inner_of_option_ty, inner_ty_args, is_has_many, wrap_in_dummy_mod};
+use crate::utils::{
+    inner_of_option_ty, inner_ty_arg, inner_ty_args, is_has_many, to_camel_case, wrap_in_dummy_mod,
+};
 use proc_macro2::{Span, TokenStream};
 use std::collections::HashMap;
 use syn;
@@ -48,6 +50,28 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
         None
     };
 
+    let key_codec_check = derive_key_codec_check(&model)?;
+
+    let pg_relation_mutations = if cfg!(feature = "postgres") {
+        Some(derive_relation_mutations(
+            &model,
+            item,
+            &quote!(diesel::pg::Pg),
+        )?)
+    } else {
+        None
+    };
+
+    let sqlite_relation_mutations = if cfg!(feature = "sqlite") {
+        Some(derive_relation_mutations(
+            &model,
+            item,
+            &quote!(diesel::sqlite::Sqlite),
+        )?)
+    } else {
+        None
+    };
+
     let belongs_to = model
         .fields()
         .iter()
@@ -114,12 +138,53 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
             #sqlite_loading_handler
             #pg_non_table_field_filter
             #sqlite_non_table_field_filter
+            #key_codec_check
+            #pg_relation_mutations
+            #sqlite_relation_mutations
 
             #(#belongs_to)*
         },
     ))
 }
 
+fn derive_key_codec_check(model: &Model) -> Result<Option<TokenStream>, Diagnostic> {
+    let codec = match model.key_codec() {
+        Some(codec) => codec,
+        None => return Ok(None),
+    };
+    let checks = model
+        .primary_key()
+        .iter()
+        .map(|primary_key| {
+            let field = model
+                .fields()
+                .iter()
+                .find(|f| *f.sql_name() == *primary_key)
+                .ok_or_else(|| {
+                    Span::call_site().error(
+                        "No primary key found, use `#[primary_key(\"column\")]` to specify one",
+                    )
+                })?;
+            let ty = &field.ty;
+            Ok(quote_spanned! {field.span=>
+                check_key_codec::<_, #codec>(std::marker::PhantomData::<#ty>);
+            })
+        })
+        .collect::<Result<Vec<_>, Diagnostic>>()?;
+    Ok(Some(quote! {
+        // All primary key fields need to be encoded by the given codec
+        #[allow(dead_code)]
+        fn check_key_codec_of_primary_keys() {
+            fn check_key_codec<K, C>(
+                _: std::marker::PhantomData<wundergraph::query_builder::types::EncodedKey<K, C>>,
+            ) {
+            }
+
+            #(#checks)*
+        }
+    }))
+}
+
 fn derive_loading_handler(
     model: &Model,
     item: &syn::DeriveInput,
@@ -196,6 +261,42 @@ fn derive_loading_handler(
         |p| quote!(#p),
     );
 
+    let soft_delete = model
+        .soft_delete_column()
+        .map(|column| {
+            model
+                .fields()
+                .iter()
+                .find(|f| *f.sql_name() == column)
+                .map(|f| derive_soft_delete(f, &quote!(#table::#column), backend))
+                .ok_or_else(|| {
+                    column.span().error(
+                        "The soft delete column needs to be a field of the entity, \
+                         otherwise it could not be reset by `Restore<Entity>`",
+                    )
+                })
+        })
+        .transpose()?;
+
+    let version = model
+        .version_column()
+        .map(|column| {
+            model
+                .fields()
+                .iter()
+                .find(|f| *f.sql_name() == column)
+                .map(|f| derive_version(f, &quote!(#table::#column), backend))
+                .ok_or_else(|| {
+                    column.span().error(
+                        "The version column needs to be a field of the entity, \
+                         otherwise clients could not read the current version",
+                    )
+                })
+        })
+        .transpose()?;
+
+    let unique_lookups = derive_unique_lookups(model, &table, backend)?;
+
     let mut generics = item.generics.clone();
     generics
         .params
@@ -229,7 +330,11 @@ fn derive_loading_handler(
                         &std::option::Option::None,
                         &std::default::Default::default(),
                     );
-                field.argument(arg)
+                wundergraph::query_builder::selection::soft_delete::register_soft_delete_arguments::<
+                    Self,
+                    #backend,
+                    __Ctx,
+                >(registry, field.argument(arg))
             }
         }
 
@@ -262,10 +367,235 @@ fn derive_loading_handler(
                     _ => std::option::Option::None,
                 }
             }
+
+            fn primary_keys(
+                placeholder: &[<Self::FieldList as wundergraph::query_builder::selection::fields::WundergraphFieldList<
+                    #backend,
+                    Self::PrimaryKeyIndex,
+                    #table::table,
+                    __Ctx,
+                >>::PlaceHolder],
+            ) -> std::vec::Vec<std::option::Option<wundergraph::juniper::InputValue<wundergraph::scalar::WundergraphScalarValue>>> {
+                wundergraph::helper::primary_keys::primary_key_input_values::<
+                    _,
+                    Self::PrimaryKeyIndex,
+                    #table::table,
+                >(placeholder)
+            }
+
+            #soft_delete
+            #version
+            #unique_lookups
         }
     })
 }
 
+fn derive_unique_lookups(
+    model: &Model,
+    table: &syn::Ident,
+    backend: &TokenStream,
+) -> Result<Option<TokenStream>, Diagnostic> {
+    let column_sets = model.unique_column_sets()?;
+    if column_sets.is_empty() {
+        return Ok(None);
+    }
+    let lookups = column_sets
+        .iter()
+        .enumerate()
+        .map(|(idx, columns)| {
+            let fields = columns
+                .iter()
+                .map(|column| {
+                    model
+                        .fields()
+                        .iter()
+                        .find(|f| f.sql_name() == column)
+                        .ok_or_else(|| {
+                            column
+                                .span()
+                                .error("A unique column needs to be a field of the entity")
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let tys = fields
+                .iter()
+                .map(|f| {
+                    let ty = inner_of_option_ty(&f.ty);
+                    if is_has_many(ty) {
+                        Err(f.span.error("A `HasMany` field could not be unique"))
+                    } else {
+                        Ok(inner_ty_arg(ty, "HasOne", 0).unwrap_or(ty))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            // Prefixed with the graphql name of the entity by the query object
+            let lookup_name = format!(
+                "By{}",
+                fields
+                    .iter()
+                    .map(|f| pascal_case(f.graphql_name()))
+                    .collect::<Vec<_>>()
+                    .join("And")
+            );
+            let arg_names = fields.iter().map(|f| f.graphql_name()).collect::<Vec<_>>();
+            let columns = fields.iter().map(|f| f.sql_name());
+            let tables = std::iter::repeat(table);
+            let (tys, arg_names) = (&tys, &arg_names);
+            let register = quote! {
+                #idx => {
+                    #(
+                        let arg = registry.arg::<#tys>(stringify!(#arg_names), &());
+                        let field = field.argument(arg);
+                    )*
+                    field
+                }
+            };
+            let apply = quote! {
+                #idx => {
+                    #(
+                        let value: #tys = wundergraph::query_builder::selection::unique::unique_argument(
+                            select,
+                            stringify!(#arg_names),
+                        )?;
+                        let query = query.filter(#tables::#columns.eq(value));
+                    )*
+                    std::result::Result::Ok(query)
+                }
+            };
+            Ok((lookup_name, register, apply))
+        })
+        .collect::<Result<Vec<_>, Diagnostic>>()?;
+    let lookup_names = lookups.iter().map(|(name, _, _)| name);
+    let register = lookups.iter().map(|(_, register, _)| register);
+    let apply = lookups.iter().map(|(_, _, apply)| apply);
+
+    Ok(Some(quote! {
+        const UNIQUE_LOOKUPS: &'static [&'static str] = &[#(#lookup_names,)*];
+
+        fn register_unique_lookup_arguments<'r>(
+            idx: usize,
+            registry: &mut wundergraph::juniper::Registry<'r, wundergraph::scalar::WundergraphScalarValue>,
+            field: wundergraph::juniper::meta::Field<'r, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> wundergraph::juniper::meta::Field<'r, wundergraph::scalar::WundergraphScalarValue> {
+            match idx {
+                #(#register,)*
+                _ => field,
+            }
+        }
+
+        fn apply_unique_lookup<'a>(
+            idx: usize,
+            query: wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx>,
+            select: &wundergraph::juniper::LookAheadSelection<'_, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> std::result::Result<
+            wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx>,
+            wundergraph::failure::Error,
+        > {
+            use wundergraph::diesel::{ExpressionMethods, QueryDsl};
+            match idx {
+                #(#apply,)*
+                _ => std::result::Result::Ok(query),
+            }
+        }
+    }))
+}
+
+fn pascal_case(name: &syn::Ident) -> String {
+    let mut name = to_camel_case(&name.to_string());
+    if let Some(first) = name.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    name
+}
+
+fn derive_soft_delete(field: &Field, column: &TokenStream, backend: &TokenStream) -> TokenStream {
+    let ty = inner_of_option_ty(&field.ty);
+    quote! {
+        const SOFT_DELETE: bool = true;
+
+        fn apply_soft_delete<'a>(
+            query: wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx>,
+            select: &wundergraph::juniper::LookAheadSelection<'_, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> std::result::Result<
+            wundergraph::query_builder::selection::BoxedQuery<'a, Self, #backend, __Ctx>,
+            wundergraph::failure::Error,
+        > {
+            use wundergraph::diesel::{ExpressionMethods, QueryDsl};
+            use wundergraph::query_builder::selection::soft_delete::{soft_delete_mode, SoftDeleteMode};
+            std::result::Result::Ok(match soft_delete_mode(select)? {
+                SoftDeleteMode::Exclude => query.filter(#column.is_null()),
+                SoftDeleteMode::Only => query.filter(#column.is_not_null()),
+                SoftDeleteMode::Include => query,
+            })
+        }
+
+        fn set_soft_deleted(
+            ctx: &__Ctx,
+            filter: std::boxed::Box<dyn wundergraph::diesel_ext::BoxableFilter<
+                <Self as wundergraph::diesel::associations::HasTable>::Table,
+                #backend,
+                SqlType = wundergraph::diesel::sql_types::Bool,
+            >>,
+            deleted: bool,
+        ) -> std::result::Result<std::option::Option<usize>, wundergraph::failure::Error> {
+            use wundergraph::context::WundergraphContext;
+            use wundergraph::diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+            use wundergraph::instrumentation::instrument;
+            let conn = ctx.get_connection();
+            let target = <Self as wundergraph::diesel::associations::HasTable>::table().filter(filter);
+            let count = if deleted {
+                let update = wundergraph::diesel::update(target.filter(#column.is_null()))
+                    .set(#column.eq(wundergraph::diesel::dsl::now));
+                instrument(ctx, conn, <Self as wundergraph::query_builder::selection::LoadingHandler<#backend, __Ctx>>::TYPE_NAME, update, |u, conn| u.execute(conn), |n| *n)?
+            } else {
+                let update = wundergraph::diesel::update(target.filter(#column.is_not_null()))
+                    .set(#column.eq(std::option::Option::None::<#ty>));
+                instrument(ctx, conn, <Self as wundergraph::query_builder::selection::LoadingHandler<#backend, __Ctx>>::TYPE_NAME, update, |u, conn| u.execute(conn), |n| *n)?
+            };
+            std::result::Result::Ok(std::option::Option::Some(count))
+        }
+    }
+}
+
+fn derive_version(field: &Field, column: &TokenStream, backend: &TokenStream) -> TokenStream {
+    let graphql_name = field.graphql_name();
+    let ty = &field.ty;
+    quote! {
+        const VERSION_FIELD: std::option::Option<&'static str> =
+            std::option::Option::Some(stringify!(#graphql_name));
+
+        fn version_filter(
+            expected: &wundergraph::juniper::LookAheadValue<'_, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> std::result::Result<
+            std::option::Option<std::boxed::Box<dyn wundergraph::diesel_ext::BoxableFilter<
+                <Self as wundergraph::diesel::associations::HasTable>::Table,
+                #backend,
+                SqlType = wundergraph::diesel::sql_types::Bool,
+            >>>,
+            wundergraph::failure::Error,
+        > {
+            use wundergraph::diesel::ExpressionMethods;
+            let expected: #ty = wundergraph::query_builder::selection::version::parse_version(
+                expected,
+                stringify!(#graphql_name),
+            )?;
+            std::result::Result::Ok(std::option::Option::Some(std::boxed::Box::new(#column.eq(expected))))
+        }
+
+        fn version_increment() -> std::option::Option<
+            wundergraph::query_builder::selection::version::VersionIncrement<
+                <Self as wundergraph::diesel::associations::HasTable>::Table,
+                #backend,
+            >,
+        > {
+            use wundergraph::diesel::ExpressionMethods;
+            std::option::Option::Some(wundergraph::query_builder::selection::version::VersionIncrement::new(
+                #column.eq(#column + <#ty as std::convert::From<u8>>::from(1)),
+            ))
+        }
+    }
+}
+
 fn derive_belongs_to(
     model: &Model,
     item: &syn::DeriveInput,
@@ -313,7 +643,8 @@ fn derive_belongs_to(
                     use wundergraph::diesel::{ExpressionMethods, RunQueryDsl, QueryDsl, NullableExpressionMethods};
                     use wundergraph::context::WundergraphContext;
                     use wundergraph::query_builder::selection::{LoadingHandler, BoxedQuery};
-                    let conn = executor.context().get_connection();
+                    let ctx = executor.context();
+                    let conn = ctx.get_read_connection();
                     let query = <_ as QueryDsl>::filter(
                         <BoxedQuery<Self, #backend, __Ctx> as QueryDsl>::select(
                            <Self as LoadingHandler<#backend, __Ctx>>::build_query(look_ahead)?,
@@ -329,7 +660,19 @@ fn derive_belongs_to(
                     #backend,
                     __Ctx,
                     #key_column
-                    >>::build_response(query.load(conn)?, look_ahead, selection, executor)
+                    >>::build_response(
+                        wundergraph::instrumentation::instrument(
+                            ctx,
+                            conn,
+                            <Self as LoadingHandler<#backend, __Ctx>>::TYPE_NAME,
+                            query,
+                            |query, conn| query.load(conn),
+                            std::vec::Vec::len,
+                        )?,
+                        look_ahead,
+                        selection,
+                        executor,
+                    )
             }
         }
     })
@@ -390,6 +733,14 @@ fn derive_non_table_filter(
                 wundergraph::query_builder::selection::filter::filter_helper::FilterBuildHelper::<#filter, #struct_type #ty_generics, #backend, __Ctx>::from_inner_look_ahead(objs).0
             }
 
+            fn validate_inner_look_ahead(
+                path: &str,
+                objs: &[(&str, wundergraph::juniper::LookAheadValue<wundergraph::scalar::WundergraphScalarValue>)]
+            ) -> std::result::Result<(), wundergraph::failure::Error> {
+                use wundergraph::query_builder::selection::filter::inner_filter::InnerFilter;
+                wundergraph::query_builder::selection::filter::filter_helper::FilterBuildHelper::<#filter, #struct_type #ty_generics, #backend, __Ctx>::validate_inner_look_ahead(path, objs)
+            }
+
             fn from_inner_input_value(
                 obj: wundergraph::indexmap::IndexMap<&str, &wundergraph::juniper::InputValue<wundergraph::scalar::WundergraphScalarValue>>,
             ) -> std::option::Option<#filter> {
@@ -416,3 +767,334 @@ fn derive_non_table_filter(
         }
     })
 }
+
+fn derive_relation_mutations(
+    model: &Model,
+    item: &syn::DeriveInput,
+    backend: &TokenStream,
+) -> Result<TokenStream, Diagnostic> {
+    let struct_type = &model.name;
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let mut generics = item.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(__Ctx: wundergraph::context::WundergraphContext + 'static));
+    let relations = model
+        .fields()
+        .iter()
+        .filter(|f| f.relation_mutations())
+        .collect::<Vec<_>>();
+    let where_clause = generics.where_clause.get_or_insert(parse_quote!(where));
+    where_clause
+        .predicates
+        .push(parse_quote!(<__Ctx as wundergraph::context::WundergraphContext>::Connection: wundergraph::diesel::Connection<Backend = #backend>));
+    if relations.is_empty() {
+        return Ok(quote!());
+    }
+
+    let table = model.table_type()?;
+    let key_field = match *model.primary_key() {
+        [ref key] => model
+            .fields()
+            .iter()
+            .find(|f| f.sql_name() == key)
+            .ok_or_else(|| {
+                key.span()
+                    .error("The primary key needs to be a field of the entity")
+            })?,
+        _ => {
+            return Err(relations[0]
+                .span
+                .error("Relation mutations require a single column primary key"))
+        }
+    };
+    let key_column = key_field.sql_name();
+    let key_name = key_field.graphql_name();
+    let key_ty = &key_field.ty;
+    where_clause.predicates.push(parse_quote!(
+        Self: wundergraph::query_builder::mutations::relation::RelationEntity<#backend, __Ctx>
+    ));
+
+    let mut names = Vec::new();
+    let mut register = Vec::new();
+    let mut handle = Vec::new();
+    for f in relations {
+        let column = f.sql_name();
+        let field_name = pascal_case(f.graphql_name());
+        let ty = inner_of_option_ty(&f.ty);
+        if let (Some(target_key), Some(target)) =
+            (inner_ty_arg(ty, "HasOne", 0), inner_ty_arg(ty, "HasOne", 1))
+        {
+            if model.version_column().is_some() {
+                return Err(f.span.error(
+                    "Relation mutations would bypass the version check of versioned entities, \
+                     use the update mutation instead",
+                ));
+            }
+            where_clause.predicates.push(parse_quote!(
+                #target: wundergraph::query_builder::mutations::relation::RelationEntity<#backend, __Ctx>
+            ));
+            let idx = names.len();
+            let name = format!("Connect{}{}", struct_type, field_name);
+            register.push(quote! {
+                #idx => {
+                    let arg = registry.arg::<#target_key>("to", &());
+                    field.argument(arg)
+                }
+            });
+            handle.push(quote! {
+                #idx => {
+                    let to: #target_key = relation::argument(&look_ahead, "to")?;
+                    let change = relation::RelationChange::new(
+                        #name,
+                        relation::RelationOperation::Connect,
+                        "to",
+                        key.clone(),
+                        vec![to],
+                    );
+                    relation::handle_relation_mutation::<Self, Self, #backend, __Ctx, _, _, _, _, _>(
+                        selection,
+                        executor,
+                        &entity,
+                        change,
+                        wundergraph::__entity_hooks!(Self, relation::RelationChange<#key_ty, #target_key>),
+                        |ctx, select, change| {
+                            let to = change.targets()[0].clone();
+                            let target = std::boxed::Box::new(
+                                Table::primary_key(&<#target as HasTable>::table()).eq(to.clone()),
+                            );
+                            relation::check_visible::<#target, #backend, __Ctx>(select, ctx, change.mutation(), "to", target, 1)?;
+                            let query = diesel::update(FilterDsl::filter(
+                                #table::table,
+                                #table::#key_column.eq(key.clone()),
+                            ))
+                            .set(#table::#column.eq(to));
+                            instrument(ctx, ctx.get_connection(), <Self as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.execute(conn), |n| *n)?;
+                            std::result::Result::Ok(vec![key.clone()])
+                        },
+                    )
+                }
+            });
+            names.push(name);
+            if inner_ty_arg(&f.ty, "Option", 0).is_some() {
+                let idx = names.len();
+                let name = format!("Disconnect{}{}", struct_type, field_name);
+                register.push(quote!(#idx => field));
+                handle.push(quote! {
+                    #idx => {
+                        let change = relation::RelationChange::new(
+                            #name,
+                            relation::RelationOperation::Disconnect,
+                            "to",
+                            key.clone(),
+                            std::vec::Vec::<#target_key>::new(),
+                        );
+                        relation::handle_relation_mutation::<Self, Self, #backend, __Ctx, _, _, _, _, _>(
+                            selection,
+                            executor,
+                            &entity,
+                            change,
+                            wundergraph::__entity_hooks!(Self, relation::RelationChange<#key_ty, #target_key>),
+                            |ctx, _select, _change| {
+                                let query = diesel::update(FilterDsl::filter(
+                                    #table::table,
+                                    #table::#key_column.eq(key.clone()),
+                                ))
+                                .set(#table::#column.eq(std::option::Option::None::<#target_key>));
+                                instrument(ctx, ctx.get_connection(), <Self as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.execute(conn), |n| *n)?;
+                                std::result::Result::Ok(vec![key.clone()])
+                            },
+                        )
+                    }
+                });
+                names.push(name);
+            }
+        } else if let (Some(child), Some(foreign_key)) = (
+            inner_ty_arg(ty, "HasMany", 0),
+            inner_ty_arg(ty, "HasMany", 1),
+        ) {
+            where_clause.predicates.push(parse_quote!(
+                #child: wundergraph::query_builder::mutations::relation::RelationEntity<#backend, __Ctx>
+            ));
+            let child_key = quote! {
+                <<&'static #child as wundergraph::diesel::Identifiable>::Id as wundergraph::helper::primary_keys::UnRef<'static>>::UnRefed
+            };
+            let children = quote! {
+                let children = |ids: &[#child_key]| {
+                    Table::primary_key(&<#child as HasTable>::table()).eq_any(ids.to_vec())
+                };
+            };
+            let check_children = quote! {
+                relation::check_visible::<#child, #backend, __Ctx>(
+                    select,
+                    ctx,
+                    change.mutation(),
+                    "ids",
+                    std::boxed::Box::new(children(ids)),
+                    ids.len(),
+                )?;
+            };
+            // Disconnected children need a nullable foreign key
+            let assert_nullable = quote_spanned! {f.span=>
+                relation::assert_nullable_foreign_key::<#foreign_key>();
+            };
+            let disconnect = quote! {
+                let query = diesel::update(FilterDsl::filter(<#child as HasTable>::table(), children(&keys)))
+                    .set(#foreign_key.eq(diesel::dsl::sql::<diesel::dsl::SqlTypeOf<#foreign_key>>("NULL")));
+                instrument(ctx, ctx.get_connection(), <#child as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.execute(conn), |n| *n)?;
+            };
+            let ops = [
+                (
+                    "Connect",
+                    quote! {
+                        #check_children
+                        let query = diesel::update(FilterDsl::filter(<#child as HasTable>::table(), children(ids)))
+                            .set(#foreign_key.eq(key.clone()));
+                        instrument(ctx, ctx.get_connection(), <#child as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.execute(conn), |n| *n)?;
+                        std::result::Result::Ok(ids.to_vec())
+                    },
+                ),
+                (
+                    "Disconnect",
+                    quote! {
+                        #assert_nullable
+                        #check_children
+                        let query = SelectDsl::select(
+                            FilterDsl::filter(
+                                <#child as relation::RelationEntity<#backend, __Ctx>>::visible_rows(select, ctx)?,
+                                children(ids).and(#foreign_key.eq(key.clone())),
+                            ),
+                            Table::primary_key(&<#child as HasTable>::table()),
+                        );
+                        let keys: std::vec::Vec<#child_key> = instrument(ctx, ctx.get_connection(), <#child as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.load(conn), std::vec::Vec::len)?;
+                        #disconnect
+                        std::result::Result::Ok(keys)
+                    },
+                ),
+                (
+                    "Set",
+                    quote! {
+                        #assert_nullable
+                        #check_children
+                        // Only disconnect children visible to the context
+                        let query = SelectDsl::select(
+                            FilterDsl::filter(
+                                <#child as relation::RelationEntity<#backend, __Ctx>>::visible_rows(select, ctx)?,
+                                #foreign_key.eq(key.clone()).and(
+                                    Table::primary_key(&<#child as HasTable>::table()).ne_all(ids.to_vec()),
+                                ),
+                            ),
+                            Table::primary_key(&<#child as HasTable>::table()),
+                        );
+                        let mut keys: std::vec::Vec<#child_key> = instrument(ctx, ctx.get_connection(), <#child as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.load(conn), std::vec::Vec::len)?;
+                        #disconnect
+                        let query = diesel::update(FilterDsl::filter(<#child as HasTable>::table(), children(ids)))
+                            .set(#foreign_key.eq(key.clone()));
+                        instrument(ctx, ctx.get_connection(), <#child as LoadingHandler<#backend, __Ctx>>::TYPE_NAME, query, |q, conn| q.execute(conn), |n| *n)?;
+                        keys.extend(ids.iter().cloned());
+                        std::result::Result::Ok(keys)
+                    },
+                ),
+            ];
+            for (op, body) in &ops {
+                let idx = names.len();
+                let name = format!("{}{}{}", op, struct_type, field_name);
+                let operation = syn::Ident::new(op, Span::call_site());
+                register.push(quote! {
+                    #idx => {
+                        let arg = registry.arg::<std::vec::Vec<#child_key>>("ids", &());
+                        field.argument(arg)
+                    }
+                });
+                handle.push(quote! {
+                    #idx => {
+                        let ids: std::vec::Vec<#child_key> = relation::argument(&look_ahead, "ids")?;
+                        let change = relation::RelationChange::new(
+                            #name,
+                            relation::RelationOperation::#operation,
+                            "ids",
+                            key.clone(),
+                            ids,
+                        );
+                        #children
+                        relation::handle_relation_mutation::<Self, #child, #backend, __Ctx, _, _, _, _, _>(
+                            selection,
+                            executor,
+                            &entity,
+                            change,
+                            wundergraph::__entity_hooks!(#child, relation::RelationChange<#key_ty, #child_key>),
+                            |ctx, select, change| {
+                                let ids = change.targets();
+                                #body
+                            },
+                        )
+                    }
+                });
+                names.push(name);
+            }
+        } else {
+            return Err(f.span.error(
+                "`relation_mutations` could only be used on `HasOne` and `HasMany` fields",
+            ));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics wundergraph::query_builder::mutations::RelationMutations<#backend, __Ctx>
+            for #struct_type #ty_generics
+            #where_clause
+        {
+            const RELATION_MUTATIONS: &'static [&'static str] = &[#(#names,)*];
+
+            fn register_relation_mutation_arguments<'r>(
+                idx: usize,
+                registry: &mut wundergraph::juniper::Registry<'r, wundergraph::scalar::WundergraphScalarValue>,
+                field: wundergraph::juniper::meta::Field<'r, wundergraph::scalar::WundergraphScalarValue>,
+            ) -> wundergraph::juniper::meta::Field<'r, wundergraph::scalar::WundergraphScalarValue> {
+                let arg = registry.arg::<#key_ty>(stringify!(#key_name), &());
+                let field = field.argument(arg);
+                match idx {
+                    #(#register,)*
+                    _ => field,
+                }
+            }
+
+            fn handle_relation_mutation(
+                idx: usize,
+                selection: std::option::Option<&'_ [wundergraph::juniper::Selection<'_, wundergraph::scalar::WundergraphScalarValue>]>,
+                executor: &wundergraph::juniper::Executor<'_, __Ctx, wundergraph::scalar::WundergraphScalarValue>,
+            ) -> wundergraph::juniper::ExecutionResult<wundergraph::scalar::WundergraphScalarValue> {
+                use wundergraph::context::WundergraphContext;
+                use wundergraph::diesel::query_dsl::methods::FilterDsl;
+                use wundergraph::diesel::{ExpressionMethods, RunQueryDsl};
+                use wundergraph::instrumentation::instrument;
+                use wundergraph::query_builder::mutations::relation;
+                // Only used by some kinds of relations
+                #[allow(unused_imports)]
+                use wundergraph::diesel::{
+                    associations::HasTable, query_dsl::methods::SelectDsl, BoolExpressionMethods, Table,
+                };
+
+                let look_ahead = executor.look_ahead();
+                let key: #key_ty = relation::argument(&look_ahead, stringify!(#key_name))?;
+                let entity = || -> std::boxed::Box<
+                    dyn wundergraph::diesel_ext::BoxableFilter<
+                        #table::table,
+                        #backend,
+                        SqlType = wundergraph::diesel::sql_types::Bool,
+                    >,
+                > {
+                    std::boxed::Box::new(#table::#key_column.eq(key.clone()))
+                };
+                match idx {
+                    #(#handle,)*
+                    _ => std::result::Result::Err(wundergraph::juniper::FieldError::new(
+                        "Unknown relation mutation",
+                        wundergraph::juniper::Value::null(),
+                    )),
+                }
+            }
+        }
+    })
+}
@@ -79,6 +79,7 @@ fn inner_filter(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
 
     let from_inner_input_value = build_from_inner_input_value(&model)?;
     let from_inner_look_ahead = build_from_look_ahead(&model)?;
+    let validate_inner_look_ahead = build_validate_look_ahead(&model)?;
     let to_inner_input_value = build_to_inner_input_value(&model)?;
     let register_fields = build_register_fields(&model)?;
     Ok(quote! {
@@ -101,6 +102,13 @@ fn inner_filter(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
                 #from_inner_look_ahead
             }
 
+            fn validate_inner_look_ahead(
+                path: &str,
+                obj: &[(&str, LookAheadValue<WundergraphScalarValue>)]
+            ) -> Result<(), wundergraph::failure::Error> {
+                #validate_inner_look_ahead
+            }
+
             fn to_inner_input_value(
                 &self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>
             ) {
@@ -173,6 +181,25 @@ fn build_from_look_ahead(model: &Model) -> Result<TokenStream, Diagnostic> {
     })
 }
 
+fn build_validate_look_ahead(model: &Model) -> Result<TokenStream, Diagnostic> {
+    let validate_field = model.fields().iter().map(|f| {
+        let graphql_name = f.graphql_name();
+        let ty = inner_of_box_ty(inner_of_option_ty(&f.ty));
+        quote! {
+            wundergraph::query_builder::selection::filter::validate_look_ahead_filter_field::<#ty>(
+                path,
+                stringify!(#graphql_name),
+                obj,
+            )?;
+        }
+    });
+    Ok(quote! {
+        #(#validate_field)*
+
+        Ok(())
+    })
+}
+
 fn build_to_inner_input_value(model: &Model) -> Result<TokenStream, Diagnostic> {
     let to_values = model.fields().iter().map(|f| {
         let name = &f.rust_name().access();
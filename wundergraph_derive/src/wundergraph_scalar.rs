@@ -0,0 +1,148 @@
+use crate::diagnostic_shim::*;
+use crate::meta::MetaItem;
+use crate::utils::wrap_in_dummy_mod;
+use proc_macro2::TokenStream;
+use syn;
+use syn::spanned::Spanned;
+
+pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
+    let item_name = &item.ident;
+    let sql_type = MetaItem::with_name(&item.attrs, "sql_type")
+        .ok_or_else(|| {
+            item.span()
+                .error("Missing required option `sql_type`".to_string())
+        })?
+        .ty_value()?;
+    let graphql_name =
+        match MetaItem::with_name(&item.attrs, "graphql").map(|g| g.nested_item("name")) {
+            Some(Ok(n)) => n.str_value()?,
+            Some(Err(_)) | None => item_name.to_string(),
+        };
+    let description = MetaItem::get_docs(&item.attrs)
+        .map_or_else(|| quote!(meta), |d| quote!(meta.description(#d)));
+
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let mut filter_generics = item.generics.clone();
+    filter_generics.params.push(parse_quote!(__C));
+    let (filter_impl_generics, _, _) = filter_generics.split_for_impl();
+    let mut column_filter_generics = filter_generics.clone();
+    column_filter_generics.params.push(parse_quote!(__DB));
+    column_filter_generics.params.push(parse_quote!(__Ctx));
+    let (column_filter_impl_generics, _, _) = column_filter_generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(
+        "wundergraph_scalar",
+        item_name,
+        &quote! {
+            use wundergraph::query_builder::selection::filter::filter_value::FilterValue;
+            use wundergraph::query_builder::selection::filter::filter_helper::AsColumnFilter;
+            use wundergraph::query_builder::selection::filter::FilterOption;
+            use wundergraph::juniper::{self, LookAheadValue};
+            use wundergraph::juniper_ext::{FromLookAheadValue, Nameable};
+            use wundergraph::scalar::{CustomScalar, WundergraphScalarValue};
+            use wundergraph::query_builder::types::{WundergraphValue, PlaceHolder};
+            use wundergraph::diesel::sql_types::Nullable;
+
+            impl #impl_generics juniper::GraphQLType<WundergraphScalarValue> for #item_name #ty_generics
+                #where_clause
+            {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn name(_info: &Self::TypeInfo) -> Option<&str> {
+                    Some(#graphql_name)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut juniper::Registry<'r, WundergraphScalarValue>,
+                ) -> juniper::meta::MetaType<'r, WundergraphScalarValue>
+                where
+                    WundergraphScalarValue: 'r,
+                {
+                    let meta = registry.build_scalar_type::<Self>(info);
+                    #description.into_meta()
+                }
+
+                fn resolve(
+                    &self,
+                    _info: &Self::TypeInfo,
+                    _selection_set: Option<&[juniper::Selection<WundergraphScalarValue>]>,
+                    _executor: &juniper::Executor<Self::Context, WundergraphScalarValue>,
+                ) -> juniper::Value<WundergraphScalarValue> {
+                    juniper::Value::Scalar(CustomScalar::to_scalar_value(self))
+                }
+            }
+
+            impl #impl_generics juniper::FromInputValue<WundergraphScalarValue> for #item_name #ty_generics
+                #where_clause
+            {
+                fn from_input_value(v: &juniper::InputValue<WundergraphScalarValue>) -> Option<Self> {
+                    if let juniper::InputValue::Scalar(ref s) = *v {
+                        CustomScalar::from_scalar_value(s)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl #impl_generics juniper::ToInputValue<WundergraphScalarValue> for #item_name #ty_generics
+                #where_clause
+            {
+                fn to_input_value(&self) -> juniper::InputValue<WundergraphScalarValue> {
+                    juniper::InputValue::Scalar(CustomScalar::to_scalar_value(self))
+                }
+            }
+
+            impl #impl_generics juniper::ParseScalarValue<WundergraphScalarValue> for #item_name #ty_generics
+                #where_clause
+            {
+                fn from_str<'a>(
+                    value: juniper::parser::ScalarToken<'a>,
+                ) -> juniper::ParseScalarResult<'a, WundergraphScalarValue> {
+                    wundergraph::scalar::parse_scalar_token(value)
+                }
+            }
+
+            impl #impl_generics FromLookAheadValue for #item_name #ty_generics
+                #where_clause
+            {
+                fn from_look_ahead(v: &LookAheadValue<WundergraphScalarValue>) -> Option<Self> {
+                    if let LookAheadValue::Scalar(s) = *v {
+                        CustomScalar::from_scalar_value(s)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl #impl_generics Nameable for #item_name #ty_generics
+                #where_clause
+            {
+                fn name() -> String {
+                    String::from(#graphql_name)
+                }
+            }
+
+            impl #impl_generics WundergraphValue for #item_name #ty_generics
+                #where_clause
+            {
+                type PlaceHolder = PlaceHolder<Self>;
+                type SqlType = Nullable<#sql_type>;
+            }
+
+            impl #filter_impl_generics FilterValue<__C> for #item_name #ty_generics
+                #where_clause
+            {
+                type RawValue = Self;
+                type AdditionalFilter = ();
+            }
+
+            impl #column_filter_impl_generics AsColumnFilter<__C, __DB, __Ctx> for #item_name #ty_generics
+                #where_clause
+            {
+                type Filter = FilterOption<Self, __C>;
+            }
+        },
+    ))
+}
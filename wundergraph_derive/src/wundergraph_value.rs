@@ -9,8 +9,10 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
     let filter_value = filter_value(item);
     let nameable = nameable(item);
     let look_ahead = from_look_ahead(item)?;
-    let wundergraph_value = wundergraph_value(item)?;
+    let sql_type = sql_type(item)?;
+    let wundergraph_value = wundergraph_value(item, &sql_type);
     let as_filter = as_column_filter(item);
+    let sql_conversions = sql_conversions(item, &sql_type)?;
 
     Ok(wrap_in_dummy_mod(
         "wundergraph_value",
@@ -25,12 +27,12 @@ pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
             use wundergraph::query_builder::types::{WundergraphValue, PlaceHolder};
             use wundergraph::diesel::sql_types::Nullable;
 
-
             #filter_value
             #nameable
             #look_ahead
             #wundergraph_value
             #as_filter
+            #sql_conversions
         },
     ))
 }
@@ -53,24 +55,248 @@ fn as_column_filter(item: &syn::DeriveInput) -> TokenStream {
     }
 }
 
-fn wundergraph_value(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
-    let sql_type = MetaItem::with_name(&item.attrs, "sql_type")
-        .map(|m| m.expect_ident_value())
+fn sql_type(item: &syn::DeriveInput) -> Result<syn::Type, Diagnostic> {
+    MetaItem::with_name(&item.attrs, "sql_type")
         .ok_or_else(|| {
             item.span()
                 .error("Missing required option `sql_type`".to_string())
-        })?;
+        })?
+        .ty_value()
+}
+
+fn wundergraph_value(item: &syn::DeriveInput, sql_type: &syn::Type) -> TokenStream {
     let item_name = &item.ident;
     let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
 
-    Ok(quote! {
+    quote! {
         impl #impl_generics WundergraphValue for #item_name #ty_generics
             #where_clause
         {
             type PlaceHolder = PlaceHolder<Self>;
             type SqlType = Nullable<#sql_type>;
         }
-    })
+    }
+}
+
+/// Generates `ToSql` and `FromSql` implementations for the sql type
+/// given by `#[sql_type]` if requested by the enum level attribute
+///
+/// * `#[wundergraph(sql_conversions)]` supports integer types (`SmallInt`,
+///   `Integer`, `BigInt`), storing the discriminant of the variant, and
+///   text types (`Text`, `VarChar`), storing the sql name of the variant
+/// * `#[wundergraph(pg_enum)]` supports a native postgres enum type,
+///   declared via `#[derive(SqlType)]`. The sql name of the variant is used
+///   as label.
+///
+/// The sql name of a variant defaults to the snake case version of the
+/// variant name and could be changed by `#[wundergraph(sql_name = "...")]`.
+/// Decoding an unknown value returns an error.
+fn sql_conversions(
+    item: &syn::DeriveInput,
+    sql_type: &syn::Type,
+) -> Result<TokenStream, Diagnostic> {
+    let flags = MetaItem::with_name(&item.attrs, "wundergraph")
+        .unwrap_or_else(|| MetaItem::empty("wundergraph"));
+    flags.warn_if_other_options(&["sql_conversions", "pg_enum"]);
+    let has_flag = |name: &str| {
+        flags
+            .nested()
+            .map(|mut n| n.any(|f| f.name() == name))
+            .unwrap_or(false)
+    };
+    let pg_enum = has_flag("pg_enum");
+    if !pg_enum && !has_flag("sql_conversions") {
+        return Ok(quote!());
+    }
+
+    let item_name = &item.ident;
+    let variants = enum_fields(item)?
+        .map(|(_, v)| match v.fields {
+            syn::Fields::Unit => Ok((sql_name(v)?, &v.ident)),
+            _ => Err(v
+                .span()
+                .error("Only enums with unit variants are supported")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let unknown_value = quote! {
+        format!("Unrecognized value `{}` for enum `{}`", value, stringify!(#item_name)).into()
+    };
+
+    let sql_type_name = match *sql_type {
+        syn::Type::Path(ref p) => p.path.segments.last().map(|s| s.value().ident.to_string()),
+        _ => None,
+    }
+    .unwrap_or_default();
+
+    let int_type = match &*sql_type_name {
+        "SmallInt" | "Int2" => Some(quote!(i16)),
+        "Integer" | "Int4" => Some(quote!(i32)),
+        "BigInt" | "Int8" => Some(quote!(i64)),
+        _ => None,
+    };
+
+    if pg_enum {
+        Ok(pg_enum_conversions(
+            item_name,
+            sql_type,
+            &variants,
+            &unknown_value,
+        ))
+    } else if let Some(int_type) = int_type {
+        let to_sql = variants.iter().map(|(_, variant)| {
+            quote! {
+                #item_name::#variant => #item_name::#variant as #int_type
+            }
+        });
+        let from_sql = variants.iter().map(|(_, variant)| {
+            quote! {
+                if value == #item_name::#variant as #int_type {
+                    return Ok(#item_name::#variant);
+                }
+            }
+        });
+        Ok(quote! {
+            use wundergraph::diesel::deserialize::{self, FromSql};
+            use wundergraph::diesel::serialize::{self, ToSql};
+            use std::io::Write;
+
+            impl<__DB> ToSql<#sql_type, __DB> for #item_name
+            where
+                __DB: wundergraph::diesel::backend::Backend,
+                #int_type: ToSql<#sql_type, __DB>,
+            {
+                fn to_sql<__W: Write>(
+                    &self,
+                    out: &mut serialize::Output<__W, __DB>,
+                ) -> serialize::Result {
+                    let value = match *self {
+                        #(#to_sql,)*
+                    };
+                    value.to_sql(out)
+                }
+            }
+
+            impl<__DB> FromSql<#sql_type, __DB> for #item_name
+            where
+                __DB: wundergraph::diesel::backend::Backend,
+                #int_type: FromSql<#sql_type, __DB>,
+            {
+                fn from_sql(bytes: Option<&__DB::RawValue>) -> deserialize::Result<Self> {
+                    let value = <#int_type as FromSql<#sql_type, __DB>>::from_sql(bytes)?;
+                    #(#from_sql)*
+                    Err(#unknown_value)
+                }
+            }
+        })
+    } else if let "Text" | "VarChar" | "Varchar" = &*sql_type_name {
+        let to_sql = variants.iter().map(|(name, variant)| {
+            quote! {
+                #item_name::#variant => #name
+            }
+        });
+        let from_sql = variants.iter().map(|(name, variant)| {
+            quote! {
+                #name => Ok(#item_name::#variant)
+            }
+        });
+        Ok(quote! {
+            use wundergraph::diesel::deserialize::{self, FromSql};
+            use wundergraph::diesel::serialize::{self, ToSql};
+            use std::io::Write;
+
+            impl<__DB> ToSql<#sql_type, __DB> for #item_name
+            where
+                __DB: wundergraph::diesel::backend::Backend,
+                str: ToSql<#sql_type, __DB>,
+            {
+                fn to_sql<__W: Write>(
+                    &self,
+                    out: &mut serialize::Output<__W, __DB>,
+                ) -> serialize::Result {
+                    let value = match *self {
+                        #(#to_sql,)*
+                    };
+                    <str as ToSql<#sql_type, __DB>>::to_sql(value, out)
+                }
+            }
+
+            impl<__DB> FromSql<#sql_type, __DB> for #item_name
+            where
+                __DB: wundergraph::diesel::backend::Backend,
+                String: FromSql<#sql_type, __DB>,
+            {
+                fn from_sql(bytes: Option<&__DB::RawValue>) -> deserialize::Result<Self> {
+                    let value = <String as FromSql<#sql_type, __DB>>::from_sql(bytes)?;
+                    match &*value {
+                        #(#from_sql,)*
+                        _ => Err(#unknown_value),
+                    }
+                }
+            }
+        })
+    } else {
+        Err(sql_type.span().error(format!(
+            "Unsupported sql type `{}`, use `#[wundergraph(pg_enum)]` for native postgres enum types",
+            quote!(#sql_type)
+        )))
+    }
+}
+
+fn pg_enum_conversions(
+    item_name: &syn::Ident,
+    sql_type: &syn::Type,
+    variants: &[(String, &syn::Ident)],
+    unknown_value: &TokenStream,
+) -> TokenStream {
+    let to_sql = variants.iter().map(|(name, variant)| {
+        quote! {
+            #item_name::#variant => #name
+        }
+    });
+    let from_sql = variants.iter().map(|(name, variant)| {
+        let name = syn::LitByteStr::new(name.as_bytes(), Span::call_site());
+        quote! {
+            #name => Ok(#item_name::#variant)
+        }
+    });
+    quote! {
+        use wundergraph::diesel::deserialize::{self, FromSql};
+        use wundergraph::diesel::serialize::{self, ToSql};
+        use std::io::Write;
+
+        impl ToSql<#sql_type, wundergraph::diesel::pg::Pg> for #item_name {
+            fn to_sql<__W: Write>(
+                &self,
+                out: &mut serialize::Output<__W, wundergraph::diesel::pg::Pg>,
+            ) -> serialize::Result {
+                let value = match *self {
+                    #(#to_sql,)*
+                };
+                out.write_all(value.as_bytes())?;
+                Ok(serialize::IsNull::No)
+            }
+        }
+
+        impl FromSql<#sql_type, wundergraph::diesel::pg::Pg> for #item_name {
+            fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+                let bytes = bytes.ok_or("Unexpected null for non-null column")?;
+                match bytes {
+                    #(#from_sql,)*
+                    _ => {
+                        let value = std::string::String::from_utf8_lossy(bytes);
+                        Err(#unknown_value)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn sql_name(variant: &syn::Variant) -> Result<String, Diagnostic> {
+    match MetaItem::with_name(&variant.attrs, "wundergraph").map(|m| m.nested_item("sql_name")) {
+        Some(Ok(n)) => n.str_value(),
+        Some(Err(_)) | None => Ok(to_upper_snake_case(&variant.ident.to_string()).to_lowercase()),
+    }
 }
 
 fn from_look_ahead(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
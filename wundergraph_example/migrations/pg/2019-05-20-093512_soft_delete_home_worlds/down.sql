@@ -0,0 +1 @@
+ALTER TABLE home_worlds DROP COLUMN deleted_at;
@@ -0,0 +1,2 @@
+ALTER TABLE home_worlds DROP COLUMN last_visited_at;
+ALTER TABLE home_worlds DROP COLUMN view_count;
@@ -0,0 +1,2 @@
+ALTER TABLE home_worlds ADD COLUMN view_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE home_worlds ADD COLUMN last_visited_at TIMESTAMP;
@@ -0,0 +1,19 @@
+-- Full text index for the names of all heros, used by the `matches` filter
+-- and the relevance ordering of the `heroName` field of heros
+
+CREATE VIRTUAL TABLE heros_fts USING fts5(name, content='heros', content_rowid='id');
+
+INSERT INTO heros_fts(heros_fts) VALUES('rebuild');
+
+CREATE TRIGGER heros_fts_insert AFTER INSERT ON heros BEGIN
+    INSERT INTO heros_fts(rowid, name) VALUES (new.id, new.name);
+END;
+
+CREATE TRIGGER heros_fts_delete AFTER DELETE ON heros BEGIN
+    INSERT INTO heros_fts(heros_fts, rowid, name) VALUES ('delete', old.id, old.name);
+END;
+
+CREATE TRIGGER heros_fts_update AFTER UPDATE ON heros BEGIN
+    INSERT INTO heros_fts(heros_fts, rowid, name) VALUES ('delete', old.id, old.name);
+    INSERT INTO heros_fts(rowid, name) VALUES (new.id, new.name);
+END;
@@ -0,0 +1 @@
+ALTER TABLE home_worlds ADD COLUMN deleted_at TIMESTAMP;
@@ -0,0 +1 @@
+ALTER TABLE species DROP COLUMN version;
@@ -0,0 +1 @@
+ALTER TABLE species ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -36,20 +36,18 @@ extern crate juniper;
 
 use wundergraph;
 
+use chrono::NaiveDateTime;
 use diesel::backend::Backend;
-use diesel::deserialize::{self, FromSql};
 use diesel::r2d2::{ConnectionManager, PooledConnection};
-use diesel::serialize::{self, ToSql};
 use diesel::sql_types::SmallInt;
 use diesel::{Connection, Identifiable};
 use failure::Error;
 use juniper::LookAheadSelection;
-use std::io::Write;
 use wundergraph::context::WundergraphContext;
 use wundergraph::query_builder::selection::offset::ApplyOffset;
 use wundergraph::query_builder::selection::query_modifier::QueryModifier;
-use wundergraph::query_builder::selection::{LoadingHandler, BoxedQuery};
-use wundergraph::query_builder::types::{HasMany, HasOne};
+use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler};
+use wundergraph::query_builder::types::{FullText, HasMany, HasOne};
 use wundergraph::scalar::WundergraphScalarValue;
 use wundergraph::{WundergraphEntity, WundergraphValue};
 
@@ -60,38 +58,13 @@ use self::mutations::*;
     Debug, Copy, Clone, AsExpression, FromSqlRow, GraphQLEnum, WundergraphValue, Eq, PartialEq, Hash,
 )]
 #[sql_type = "SmallInt"]
+#[wundergraph(sql_conversions)]
 pub enum Episode {
     NEWHOPE = 1,
     EMPIRE = 2,
     JEDI = 3,
 }
 
-impl<DB> ToSql<SmallInt, DB> for Episode
-where
-    DB: Backend,
-    i16: ToSql<SmallInt, DB>,
-{
-    fn to_sql<W: Write>(&self, out: &mut serialize::Output<'_, W, DB>) -> serialize::Result {
-        (*self as i16).to_sql(out)
-    }
-}
-
-impl<DB> FromSql<SmallInt, DB> for Episode
-where
-    DB: Backend,
-    i16: FromSql<SmallInt, DB>,
-{
-    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
-        let value = i16::from_sql(bytes)?;
-        Ok(match value {
-            1 => Episode::NEWHOPE,
-            2 => Episode::EMPIRE,
-            3 => Episode::JEDI,
-            _ => unreachable!(),
-        })
-    }
-}
-
 table! {
     heros {
         id -> Integer,
@@ -113,6 +86,7 @@ table! {
     species {
         id -> Integer,
         name -> Text,
+        version -> Integer,
     }
 }
 
@@ -120,6 +94,9 @@ table! {
     home_worlds {
         id -> Integer,
         name -> Text,
+        deleted_at -> Nullable<Timestamp>,
+        view_count -> Integer,
+        last_visited_at -> Nullable<Timestamp>,
     }
 }
 
@@ -149,13 +126,21 @@ pub struct Friend {
 
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
 #[table_name = "home_worlds"]
+#[wundergraph(soft_delete = "deleted_at")]
 /// A world where a hero was born
 pub struct HomeWorld {
     /// Internal id of a world
     id: i32,
     /// The name of a world
     name: String,
+    /// When a world was deleted
+    deleted_at: Option<NaiveDateTime>,
+    /// How often a world was visited
+    view_count: i32,
+    /// When a world was visited the last time
+    last_visited_at: Option<NaiveDateTime>,
     /// All heros of a given world
+    #[wundergraph(relation_mutations)]
     heros: HasMany<Hero, heros::home_world>,
 }
 
@@ -171,13 +156,15 @@ mod hero {
         /// The name of a hero
         #[wundergraph(graphql_name = "heroName")]
         #[column_name = "name"]
-        something: String,
+        something: FullText,
         /// The hair color of a hero
         #[deprecated(note = "Hair color should not be used because of unsafe things")]
         hair_color: Option<String>,
         /// Which species a hero belongs to
+        #[wundergraph(relation_mutations)]
         species: HasOne<i32, Species>,
         /// On which world a hero was born
+        #[wundergraph(relation_mutations)]
         home_world: Option<HasOne<i32, HomeWorld>>,
         /// Episodes a hero appears in
         appears_in: HasMany<AppearsIn, appears_in::hero_id>,
@@ -189,12 +176,15 @@ pub use self::hero::Hero;
 
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
 #[table_name = "species"]
+#[wundergraph(version_column = "version")]
 /// A species
 pub struct Species {
     /// Internal id of a species
     id: i32,
     /// The name of a species
     name: String,
+    /// Incremented on each change of a species
+    version: i32,
     /// A list of heros for a species
     heros: HasMany<Hero, heros::species>,
 }
@@ -9,23 +9,32 @@ use super::Friend;
 use super::Hero;
 use super::HomeWorld;
 use super::Species;
+use chrono::NaiveDateTime;
+use failure::Error;
 use juniper::*;
+use wundergraph::query_builder::mutations::{
+    EntityHooks, NumericUpdate, TextUpdate, TimestampUpdate,
+};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph::{ExplicitNulls, ValidateInput};
 
-#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[derive(Insertable, GraphQLInputObject, ValidateInput, Clone, Debug)]
 #[table_name = "heros"]
 pub struct NewHero {
+    #[wundergraph(validate(non_empty))]
     name: String,
     hair_color: Option<String>,
     species: i32,
     home_world: Option<i32>,
 }
 
-#[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
+#[derive(AsChangeset, GraphQLInputObject, Identifiable, ValidateInput, ExplicitNulls, Debug)]
 #[table_name = "heros"]
 pub struct HeroChangeset {
     id: i32,
+    #[wundergraph(validate(non_empty))]
     name: Option<String>,
-    hair_color: Option<String>,
+    hair_color: Option<Option<String>>,
     species: Option<i32>,
     home_world: Option<i32>,
 }
@@ -41,6 +50,15 @@ pub struct NewSpecies {
 pub struct SpeciesChangeset {
     id: i32,
     name: Option<String>,
+    /// The version the change is based on
+    version: i32,
+}
+
+#[derive(GraphQLInputObject, Identifiable, Debug, Copy, Clone)]
+#[table_name = "species"]
+pub struct DeleteSpecies {
+    id: i32,
+    version: i32,
 }
 
 #[derive(Insertable, GraphQLInputObject, Debug)]
@@ -50,10 +68,13 @@ pub struct NewHomeWorld {
 }
 
 #[derive(AsChangeset, GraphQLInputObject, Identifiable, Debug)]
+#[graphql(scalar = "WundergraphScalarValue")]
 #[table_name = "home_worlds"]
 pub struct HomeWorldChangeset {
     id: i32,
-    name: Option<String>,
+    name: Option<TextUpdate<home_worlds::name>>,
+    view_count: Option<NumericUpdate<home_worlds::view_count, i32>>,
+    last_visited_at: Option<TimestampUpdate<home_worlds::last_visited_at, NaiveDateTime>>,
 }
 
 #[derive(Insertable, GraphQLInputObject, Debug, Copy, Clone)]
@@ -70,12 +91,29 @@ pub struct NewAppearsIn {
     episode: Episode,
 }
 
+// Trim the names of heros before they are validated
+impl<DB, Ctx> EntityHooks<DB, Ctx, NewHero> for Hero {
+    fn before_insert(_ctx: &Ctx, insertable: &mut NewHero) -> Result<(), Error> {
+        insertable.name = insertable.name.trim().to_owned();
+        Ok(())
+    }
+}
+
+impl<DB, Ctx> EntityHooks<DB, Ctx, HeroChangeset> for Hero {
+    fn before_update(_ctx: &Ctx, change_set: &mut HeroChangeset) -> Result<(), Error> {
+        if let Some(ref mut name) = change_set.name {
+            *name = name.trim().to_owned();
+        }
+        Ok(())
+    }
+}
+
 wundergraph::mutation_object! {
     /// Global mutation object for the schema
     Mutation {
-        Hero(insert = NewHero, update = HeroChangeset,),
-        Species(insert = NewSpecies, update = SpeciesChangeset,),
-        HomeWorld(insert = NewHomeWorld, update = HomeWorldChangeset,),
+        Hero(insert = NewHero, update = HeroChangeset, relations = true,),
+        Species(insert = NewSpecies, update = SpeciesChangeset, delete = DeleteSpecies),
+        HomeWorld(insert = NewHomeWorld, update = HomeWorldChangeset, relations = true,),
         Friend( insert = NewFriend,),
         AppearsIn(insert = NewAppearsIn, ),
     }